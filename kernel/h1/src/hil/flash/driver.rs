@@ -30,6 +30,7 @@ pub struct FlashImpl<'d, A: Alarm<'d> + 'd, H: Hardware + 'd> {
     write_data: TakeCell<'d, [u32]>,
     write_pos: Cell<usize>,
     write_len: Cell<usize>,
+    write_end: Cell<usize>,
     write_target: Cell<usize>,
     // Hardware interface. Uses shared references rather than mutable references
     // because the fake interface used in the unit tests is shared with the unit
@@ -54,6 +55,7 @@ impl<'d, A: Alarm<'d>, H: Hardware> FlashImpl<'d, A, H> {
             write_data: TakeCell::empty(),
             write_pos: Cell::new(0),
             write_len: Cell::new(0),
+            write_end: Cell::new(0),
             write_target: Cell::new(0),
             hw,
             smart_program_state: Cell::new(None),
@@ -77,14 +79,18 @@ impl<'d, A: Alarm<'d>, H: Hardware> super::flash::Flash<'d> for FlashImpl<'d, A,
         self.hw.read(word)
     }
 
-    fn write(&self, target: usize, data: &'d mut [u32]) -> (ReturnCode, Option<&'d mut [u32]>) {
-        let write_len = cmp::min(data.len(), MAX_WRITE_SIZE);
+    fn write_partial(&self, target: usize, data: &'d mut [u32], len: usize)
+        -> (ReturnCode, Option<&'d mut [u32]>)
+    {
+        if len > data.len() { return (ReturnCode::ESIZE, Some(data)); }
+        let write_len = cmp::min(len, MAX_WRITE_SIZE);
 
         //if data.len() > 32 { return (ReturnCode::ESIZE, Some(data)); }
         if self.program_in_progress() { return (ReturnCode::EBUSY, Some(data)); }
         self.write_pos.set(0);
         self.write_target.set(target);
         self.write_len.set(write_len);
+        self.write_end.set(len);
         self.hw.set_write_data(&data[0..write_len]);
         self.write_data.replace(data);
 
@@ -115,7 +121,7 @@ impl<'d, A: Alarm<'d>, H: Hardware> ::kernel::hil::time::AlarmClient for FlashIm
                 if let Some(client) = self.client.get() {
                     if self.opcode.get() == WRITE_OPCODE {
                         let subwrite_end = self.write_pos.get() + self.write_len.get();
-                        let fullwrite_end = self.write_data.map_or(0, |d| d.len());
+                        let fullwrite_end = self.write_end.get();
                         if subwrite_end >= fullwrite_end || code != ReturnCode::SUCCESS {
                             client.write_done(self.write_data.take().unwrap(),
                                               code);
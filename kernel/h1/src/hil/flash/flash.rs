@@ -35,7 +35,17 @@ pub trait Flash<'d> {
     /// The target location is specified as an offset from the beginning of
     /// flash in units of words. Will return EBUSY if an existing write or erase
     /// is ongoing.
-    fn write(&self, target: usize, data: &'d mut [u32]) -> (ReturnCode, Option<&'d mut [u32]>);
+    fn write(&self, target: usize, data: &'d mut [u32]) -> (ReturnCode, Option<&'d mut [u32]>) {
+        let len = data.len();
+        self.write_partial(target, data, len)
+    }
+
+    /// Writes the first `len` words of a buffer into the given location in
+    /// flash, like `write`. The whole buffer is handed back to the client, so
+    /// a buffer can be reused for writes of different lengths. Will return
+    /// ESIZE if `len` exceeds the length of the buffer.
+    fn write_partial(&self, target: usize, data: &'d mut [u32], len: usize)
+        -> (ReturnCode, Option<&'d mut [u32]>);
 
     /// Links this driver to its client.
     fn set_client(&'d self, client: &'d dyn Client<'d>);
@@ -88,12 +88,17 @@ impl<'f> Flash<'f> for FlashUser<'f> {
         self.mux.read(word)
     }
 
-    fn write(&self, target: usize, data: &'f mut [u32]) -> (ReturnCode, Option<&'f mut [u32]>) {
+    fn write_partial(&self, target: usize, data: &'f mut [u32], len: usize)
+        -> (ReturnCode, Option<&'f mut [u32]>)
+    {
         if self.operation.get() != Operation::Idle {
             return (ReturnCode::EBUSY, Some(data));
         }
+        if len > data.len() {
+            return (ReturnCode::ESIZE, Some(data));
+        }
         self.write_pos.set(target);
-        self.write_len.set(data.len());
+        self.write_len.set(len);
         self.buffer.replace(data);
         self.operation.set(Operation::Write(target));
         self.mux.do_next_op();
@@ -152,7 +157,7 @@ impl<'f> MuxFlash<'f> {
                 |buf| {
                     match node.operation.get() {
                         Operation::Write(offset) => {
-                            self.driver.write(offset, buf);
+                            self.driver.write_partial(offset, buf, node.write_len.get());
                         },
                         Operation::Erase(page_number) => {
                             self.driver.erase(page_number);
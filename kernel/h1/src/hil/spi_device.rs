@@ -1,6 +1,7 @@
 //! Interfaces for SPI device on H1
 
 use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::StatusRegister;
 
 /// Address configuration for SPI device hardware.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
@@ -61,8 +62,13 @@ pub trait SpiDevice {
     fn put_send_data(&self, write_data: &[u8]) -> kernel::ReturnCode;
 
     /// Set the contents of the SPI flash status register.
-    /// Note that this does not include the busy bit and the write enable bit.
-    fn set_status(&self, status: u8);
+    /// Note that the busy bit and the write enable bit are managed by the
+    /// hardware and are ignored.
+    fn set_status(&self, status: StatusRegister);
+
+    /// Get the contents of the SPI flash status register, including the
+    /// busy bit and the write enable bit.
+    fn get_status(&self) -> StatusRegister;
 
     /// Clear the busy bit.
     fn clear_busy(&self);
@@ -74,6 +80,9 @@ pub trait SpiDevice {
     fn clear_write_enable(&self);
 
     /// Configure JEDEC ID
+    ///
+    /// `data` must contain exactly one `spiutils::protocol::flash::JedecId`
+    /// in its wire format, including any continuation codes.
     fn set_jedec_id(&self, data: &[u8]) -> kernel::ReturnCode;
 
     /// Configure SFDP
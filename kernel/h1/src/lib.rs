@@ -38,6 +38,7 @@ pub mod nvcounter;
 pub mod personality;
 pub mod pinmux;
 pub mod pmu;
+pub mod reset;
 pub mod spi_host;
 pub mod spi_device;
 pub mod timels;
@@ -161,7 +161,7 @@ pub struct PMURegisters {
 
 const PMU_BASE: isize = 0x40000000;
 
-static mut PMU: *mut PMURegisters = PMU_BASE as *mut PMURegisters;
+pub(crate) static mut PMU: *mut PMURegisters = PMU_BASE as *mut PMURegisters;
 
 #[derive(Clone,Copy)]
 pub enum PeripheralClock0 {
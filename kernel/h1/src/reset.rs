@@ -0,0 +1,73 @@
+// Copyright 2020 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reset tracking
+//!
+//! Keeps track of the source of the last reset and of the number of resets
+//! since the last power-on reset. The counter is kept in RAM that is retained
+//! across resets other than power-on resets (the `.retained` section), since
+//! the PMU only reports the source of the last reset.
+
+use core::mem::transmute;
+use crate::pmu::{PMURegisters, PMU};
+
+/// Set in the reset source if the last reset was a power-on reset. See
+/// `PMURegisters::reset_source` for the other bits.
+pub const RESET_SOURCE_POWER_ON: u32 = 1 << 0;
+
+/// Marks the retained data as valid. Anything else means that the retained
+/// RAM has not been initialized since the last power-on.
+const RETAINED_MAGIC: u32 = 0x52535443;
+
+#[repr(C)]
+struct Retained {
+    magic: u32,
+    resets_since_power_on: u32,
+}
+
+#[link_section = ".retained"]
+static mut RETAINED: Retained = Retained {
+    magic: 0,
+    resets_since_power_on: 0,
+};
+
+static mut RESET_SOURCE: u32 = 0;
+
+/// Records the last reset.
+///
+/// Must be called once during boot, after `h1::init` has zeroed the BSS.
+pub unsafe fn init() {
+    let pmu: &mut PMURegisters = transmute(PMU);
+    let source = pmu.reset_source.get();
+    // The reset source accumulates until it is cleared.
+    pmu.clear_reset.set(1);
+    RESET_SOURCE = source;
+
+    if source & RESET_SOURCE_POWER_ON != 0 || RETAINED.magic != RETAINED_MAGIC {
+        RETAINED.magic = RETAINED_MAGIC;
+        RETAINED.resets_since_power_on = 0;
+    } else {
+        RETAINED.resets_since_power_on = RETAINED.resets_since_power_on.saturating_add(1);
+    }
+}
+
+/// Returns the number of resets since the last power-on reset.
+pub fn resets_since_power_on() -> u32 {
+    unsafe { RETAINED.resets_since_power_on }
+}
+
+/// Returns the source of the last reset, as reported by the PMU.
+pub fn reset_source() -> u32 {
+    unsafe { RESET_SOURCE }
+}
@@ -2,6 +2,7 @@ use crate::hil::spi_device::AddressConfig;
 use crate::hil::spi_device::SpiDevice;
 use crate::hil::spi_device::SpiDeviceClient;
 
+use core::cell::Cell;
 use core::cmp::min;
 
 use kernel::common::cells::OptionalCell;
@@ -14,7 +15,11 @@ use kernel::common::StaticRef;
 use kernel::ReturnCode;
 
 use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::JedecId;
+use spiutils::protocol::flash::MAX_JEDEC_ID_LEN;
 use spiutils::protocol::flash::OpCode;
+use spiutils::protocol::flash::StatusRegister;
+use spiutils::protocol::wire::FromWire;
 
 // Helper method to improve syntax for getting a data byte from a slice
 // or a default value if the specified index is out of bounds.
@@ -492,6 +497,10 @@ pub struct SpiDeviceHardware {
     registers: StaticRef<Registers>,
     client: OptionalCell<&'static dyn SpiDeviceClient>,
     config: SpiDeviceConfiguration,
+
+    /// The status register, excluding the busy and write enable bits.
+    /// The hardware only holds status register 1.
+    status: Cell<StatusRegister>,
 }
 
 impl SpiDeviceHardware {
@@ -500,6 +509,7 @@ impl SpiDeviceHardware {
             registers: base_addr,
             client: OptionalCell::empty(),
             config: config,
+            status: Cell::new(StatusRegister::from_bits(0)),
         }
     }
 
@@ -931,8 +941,22 @@ impl SpiDevice for SpiDeviceHardware {
         ReturnCode::SUCCESS
     }
 
-    fn set_status(&self, status: u8) {
-        self.registers.eeprom_status.set(status);
+    fn set_status(&self, status: StatusRegister) {
+        let status = StatusRegister {
+            write_in_progress: false,
+            write_enable_latch: false,
+            ..status
+        };
+        self.status.set(status);
+        self.registers.eeprom_status.set(status.register_1());
+    }
+
+    fn get_status(&self) -> StatusRegister {
+        StatusRegister {
+            write_in_progress: self.is_busy(),
+            write_enable_latch: self.is_write_enabled(),
+            ..self.status.get()
+        }
     }
 
     fn clear_busy(&self) {
@@ -954,6 +978,18 @@ impl SpiDevice for SpiDeviceHardware {
     /// Configure JEDEC ID
     fn set_jedec_id(&self, data: &[u8]) -> kernel::ReturnCode {
         //debug!("kernel: set_jedec_id (len={})", data.len());
+        if self.registers.jedec_id.len() * 4 < MAX_JEDEC_ID_LEN {
+            // The registers can't hold every valid JEDEC ID.
+            return ReturnCode::FAIL;
+        }
+
+        // Only accept a single, complete JEDEC ID.
+        let mut rest = data;
+        match JedecId::from_wire(&mut rest) {
+            Ok(_) if rest.is_empty() => {}
+            _ => return ReturnCode::EINVAL,
+        }
+
         self.write_register_data(&self.registers.jedec_id, data)
     }
 
@@ -120,9 +120,14 @@ impl Timeus {
 
 
     pub fn start(&self) {
+        self.start_with_divider(1);
+    }
+
+    /// Starts the counter in wrapping mode, ticking at 24Mhz / `divider`.
+    pub fn start_with_divider(&self, divider: u32) {
         let counter = self.counter();
         unsafe {counter.max_value.set(!0); // MAX_INT
-                counter.divider.set(1);
+                counter.divider.set(divider);
                 counter.wrapping.set(Enable::Enabled)};
     }
 
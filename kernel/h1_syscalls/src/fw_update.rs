@@ -0,0 +1,242 @@
+// Copyright 2020 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! System call driver for staging firmware updates in flash.
+//!
+//! Updates are written to a region of flash that is not used by the running
+//! image. All offsets are in bytes from the start of that region.
+//!
+//! The driver implements 5 commands:
+//!   0. check if the driver is present (ReturnCode::SUCCESS if so)
+//!   1. get the size of the update region in bytes.
+//!   2. erase(page), erase the page with index `page` within the region,
+//!      completion signaled by a callback.
+//!   3. write(offset, len), write `len` bytes from the user buffer at
+//!      `offset`, completion signaled by a callback. Both must be multiples
+//!      of 4 and `len` must not exceed MAX_WRITE_LEN.
+//!   4. read(offset, len), read `len` bytes at `offset` into the user
+//!      buffer. `offset` must be a multiple of 4.
+//!
+//! The driver implements 1 allow:
+//!   0. userspace buffer used for write and read (commands 3 and 4).
+//!
+//! The driver implements 1 subscribe:
+//!   0. callback for when an erase or write completes, with its ReturnCode.
+
+use h1::hil::flash::{Client, Flash};
+use h1::hil::flash::h1_hw::H1_FLASH_PAGE_SIZE;
+use kernel::{AppId, AppSlice, Callback, Driver, Grant, ReturnCode, Shared};
+use kernel::common::cells::{OptionalCell, TakeCell};
+
+pub const DRIVER_NUM: usize = 0x40007;
+
+/// The maximum number of bytes written by a single write command.
+pub const MAX_WRITE_LEN: usize = 512;
+
+const WORD_LEN: usize = 4;
+
+pub static mut WRITE_BUFFER: [u32; MAX_WRITE_LEN / WORD_LEN] = [0; MAX_WRITE_LEN / WORD_LEN];
+
+const COMMAND_CHECK: usize        = 0;
+const COMMAND_REGION_SIZE: usize  = 1;
+const COMMAND_ERASE: usize        = 2;
+const COMMAND_WRITE: usize        = 3;
+const COMMAND_READ: usize         = 4;
+const ALLOW_BUFFER: usize         = 0;
+const SUBSCRIBE_DONE: usize       = 0;
+
+#[derive(Default)]
+pub struct AppData {
+    buffer: Option<AppSlice<Shared, u8>>,
+    callback: Option<Callback>,
+}
+
+pub struct FwUpdateSyscall<'a> {
+    flash: &'a dyn Flash<'a>,
+    /// The index of the first page of the update region within flash.
+    first_page: usize,
+    /// The number of pages in the update region.
+    page_count: usize,
+    apps: Grant<AppData>,
+    write_buffer: TakeCell<'a, [u32]>,
+    current_user: OptionalCell<AppId>,
+}
+
+impl<'a> FwUpdateSyscall<'a> {
+    pub fn new(flash: &'a dyn Flash<'a>,
+               first_page: usize,
+               page_count: usize,
+               write_buffer: &'a mut [u32],
+               container: Grant<AppData>) -> FwUpdateSyscall<'a> {
+        FwUpdateSyscall {
+            flash: flash,
+            first_page: first_page,
+            page_count: page_count,
+            apps: container,
+            write_buffer: TakeCell::new(write_buffer),
+            current_user: OptionalCell::empty(),
+        }
+    }
+
+    fn region_size(&self) -> usize {
+        self.page_count * H1_FLASH_PAGE_SIZE
+    }
+
+    /// Returns the word offset within flash of the byte `offset` within the
+    /// update region.
+    fn flash_word(&self, offset: usize) -> usize {
+        (self.first_page * H1_FLASH_PAGE_SIZE + offset) / WORD_LEN
+    }
+
+    /// Whether the `len` bytes at `offset` are within the update region.
+    fn is_in_region(&self, offset: usize, len: usize) -> bool {
+        offset.checked_add(len).map_or(false, |end| end <= self.region_size())
+    }
+
+    fn erase(&self, page: usize, app_id: AppId) -> ReturnCode {
+        if self.current_user.is_some() {
+            return ReturnCode::EBUSY;
+        }
+        if page >= self.page_count {
+            return ReturnCode::EINVAL;
+        }
+        let rcode = self.flash.erase(self.first_page + page);
+        if rcode == ReturnCode::SUCCESS {
+            self.current_user.set(app_id);
+        }
+        rcode
+    }
+
+    fn write(&self, offset: usize, len: usize, app_id: AppId) -> ReturnCode {
+        if self.current_user.is_some() {
+            return ReturnCode::EBUSY;
+        }
+        if offset % WORD_LEN != 0 || len % WORD_LEN != 0 || len == 0 || len > MAX_WRITE_LEN
+            || !self.is_in_region(offset, len) {
+            return ReturnCode::EINVAL;
+        }
+        let buffer = match self.write_buffer.take() {
+            Some(buffer) => buffer,
+            None => return ReturnCode::EBUSY,
+        };
+        let rcode = self.apps.enter(app_id, |app_data, _| {
+            let data = match app_data.buffer {
+                Some(ref data) if data.len() >= len => data,
+                Some(_) => return ReturnCode::ESIZE,
+                None => return ReturnCode::ENOMEM,
+            };
+            for (word, bytes) in buffer.iter_mut().zip(data.as_ref()[..len].chunks(WORD_LEN)) {
+                *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            }
+            ReturnCode::SUCCESS
+        }).unwrap_or(ReturnCode::ENOMEM);
+        if rcode != ReturnCode::SUCCESS {
+            self.write_buffer.replace(buffer);
+            return rcode;
+        }
+
+        let (rcode, buffer) = self.flash.write_partial(self.flash_word(offset), buffer, len / WORD_LEN);
+        match buffer {
+            None => self.current_user.set(app_id),
+            Some(buffer) => { self.write_buffer.replace(buffer); },
+        }
+        rcode
+    }
+
+    fn read(&self, offset: usize, len: usize, app_id: AppId) -> ReturnCode {
+        if offset % WORD_LEN != 0 || !self.is_in_region(offset, len) {
+            return ReturnCode::EINVAL;
+        }
+        self.apps.enter(app_id, |app_data, _| {
+            let data = match app_data.buffer {
+                Some(ref mut data) if data.len() >= len => data,
+                Some(_) => return ReturnCode::ESIZE,
+                None => return ReturnCode::ENOMEM,
+            };
+            for (index, bytes) in data.as_mut()[..len].chunks_mut(WORD_LEN).enumerate() {
+                let value = match self.flash.read(self.flash_word(offset) + index) {
+                    ReturnCode::SuccessWithValue { value } => value as u32,
+                    rcode => return rcode,
+                };
+                let len = bytes.len();
+                bytes.copy_from_slice(&value.to_le_bytes()[..len]);
+            }
+            ReturnCode::SUCCESS
+        }).unwrap_or(ReturnCode::ENOMEM)
+    }
+
+    fn operation_done(&self, rcode: ReturnCode) {
+        self.current_user.take().map(|current_user| {
+            let _ = self.apps.enter(current_user, |app_data, _| {
+                app_data.callback.map(|mut cb| cb.schedule(From::from(rcode), 0, 0));
+            });
+        });
+    }
+}
+
+impl<'a> Driver for FwUpdateSyscall<'a> {
+    fn subscribe(&self,
+                 subscribe_num: usize,
+                 callback: Option<Callback>,
+                 app_id: AppId,
+    ) -> ReturnCode {
+        match subscribe_num {
+            SUBSCRIBE_DONE => {
+                self.apps.enter(app_id, |app_data, _| {
+                    app_data.callback = callback;
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            }
+            _ => ReturnCode::ENOSUPPORT
+        }
+    }
+
+    fn command(&self, command_num: usize, arg1: usize, arg2: usize, app_id: AppId) -> ReturnCode {
+        match command_num {
+            COMMAND_CHECK => ReturnCode::SUCCESS,
+            COMMAND_REGION_SIZE => ReturnCode::SuccessWithValue { value: self.region_size() },
+            COMMAND_ERASE => self.erase(arg1, app_id),
+            COMMAND_WRITE => self.write(arg1, arg2, app_id),
+            COMMAND_READ => self.read(arg1, arg2, app_id),
+            _ => ReturnCode::ENOSUPPORT
+        }
+    }
+
+    fn allow(&self,
+             app_id: AppId,
+             minor_num: usize,
+             slice: Option<AppSlice<Shared, u8>>,
+    ) -> ReturnCode {
+        match minor_num {
+            ALLOW_BUFFER => {
+                self.apps.enter(app_id, |app_data, _| {
+                    app_data.buffer = slice;
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            },
+            _ => ReturnCode::ENOSUPPORT,
+        }
+    }
+}
+
+impl<'a> Client<'a> for FwUpdateSyscall<'a> {
+    fn erase_done(&self, rcode: ReturnCode) {
+        self.operation_done(rcode);
+    }
+
+    fn write_done(&self, data: &'a mut [u32], rcode: ReturnCode) {
+        self.write_buffer.replace(data);
+        self.operation_done(rcode);
+    }
+}
@@ -22,8 +22,11 @@ pub mod digest;
 pub mod aes;
 pub mod dcrypto;
 pub mod dcrypto_test;
+pub mod fw_update;
 pub mod nvcounter_syscall;
 pub mod personality;
+pub mod reset;
+pub mod rsa;
 pub mod spi_host;
 pub mod spi_device;
 
@@ -0,0 +1,130 @@
+// Copyright 2020 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! System call driver for reset information and uptime.
+//!
+//! The driver implements 4 commands:
+//!   0. check if the driver is present (ReturnCode::SUCCESS if so)
+//!   1. get the number of resets since the last power-on reset.
+//!   2. get the source of the last reset, as reported by the PMU.
+//!   3. write the uptime in milliseconds into the user buffer, as a
+//!      little-endian u64. The uptime has a resolution of 100 ms.
+//!
+//! The driver implements 1 allow:
+//!   0. userspace buffer for the uptime (command 3).
+
+use core::cell::Cell;
+use h1::reset;
+use h1::timeus::Timeus;
+use kernel::{AppId, AppSlice, Driver, Grant, ReturnCode, Shared};
+
+pub const DRIVER_NUM: usize = 0x40006;
+
+/// The divider for the uptime counter, so that it ticks at 10 Hz. The
+/// 32-bit counter then wraps only after 13 years, rather than after 49 days
+/// at 1 kHz.
+pub const UPTIME_DIVIDER: u32 = 2_400_000;
+
+/// The duration of an uptime counter tick, in milliseconds.
+const UPTIME_TICK_MS: u64 = 100;
+
+const COMMAND_CHECK: usize                  = 0;
+const COMMAND_RESETS_SINCE_POWER_ON: usize  = 1;
+const COMMAND_RESET_SOURCE: usize           = 2;
+const COMMAND_UPTIME: usize                 = 3;
+const ALLOW_BUFFER: usize                   = 0;
+
+#[derive(Default)]
+pub struct AppData {
+    buffer: Option<AppSlice<Shared, u8>>,
+}
+
+pub struct ResetSyscall<'a> {
+    /// A counter started with `UPTIME_DIVIDER` right after boot.
+    uptime_counter: &'a Timeus,
+    apps: Grant<AppData>,
+    /// The upper 32 bits of the tick count; the counter only has the lower
+    /// 32 bits. This relies on the uptime being read at least once every 13
+    /// years.
+    uptime_high: Cell<u32>,
+    /// The last value read from the counter, to detect when it wrapped.
+    last_count: Cell<u32>,
+}
+
+impl<'a> ResetSyscall<'a> {
+    pub fn new(uptime_counter: &'a Timeus,
+               container: Grant<AppData>) -> ResetSyscall<'a> {
+        ResetSyscall {
+            uptime_counter: uptime_counter,
+            apps: container,
+            uptime_high: Cell::new(0),
+            last_count: Cell::new(0),
+        }
+    }
+
+    /// Returns the uptime in milliseconds.
+    fn uptime_ms(&self) -> u64 {
+        let count = self.uptime_counter.now();
+        if count < self.last_count.get() {
+            self.uptime_high.set(self.uptime_high.get().wrapping_add(1));
+        }
+        self.last_count.set(count);
+        ((self.uptime_high.get() as u64) << 32 | count as u64) * UPTIME_TICK_MS
+    }
+}
+
+impl<'a> Driver for ResetSyscall<'a> {
+    fn command(&self, command_num: usize, _: usize, _: usize, app_id: AppId) -> ReturnCode {
+        match command_num {
+            COMMAND_CHECK => ReturnCode::SUCCESS,
+            COMMAND_RESETS_SINCE_POWER_ON => ReturnCode::SuccessWithValue {
+                value: reset::resets_since_power_on() as usize
+            },
+            COMMAND_RESET_SOURCE => ReturnCode::SuccessWithValue {
+                value: reset::reset_source() as usize
+            },
+            COMMAND_UPTIME => {
+                self.apps.enter(app_id, |app_data, _| {
+                    let buffer = match app_data.buffer {
+                        Some(ref mut slice) => slice,
+                        None => return ReturnCode::ENOMEM,
+                    };
+                    let uptime = self.uptime_ms().to_le_bytes();
+                    if buffer.len() < uptime.len() {
+                        return ReturnCode::ESIZE;
+                    }
+                    buffer.as_mut()[..uptime.len()].copy_from_slice(&uptime);
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            },
+            _ => ReturnCode::ENOSUPPORT
+        }
+    }
+
+    fn allow(&self,
+             app_id: AppId,
+             allow_num: usize,
+             slice: Option<AppSlice<Shared, u8>>
+    ) -> ReturnCode {
+        match allow_num {
+            ALLOW_BUFFER => {
+                self.apps.enter(app_id, |app_data, _| {
+                    app_data.buffer = slice;
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            }
+            _ => ReturnCode::ENOSUPPORT
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2020 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RSA signature verification.
+//!
+//! Verifies RSASSA-PKCS1-v1_5 signatures with SHA-256 using the software
+//! implementation in `rsautils`. The application hashes the message itself,
+//! e.g. through the digest driver, and passes the digest.
+
+use core::convert::TryFrom;
+use kernel::{AppId, AppSlice, Driver, Grant, ReturnCode, Shared};
+use kernel::common::cells::TakeCell;
+use rsautils::{Error, PublicKey, SHA256_DIGEST_LEN, scratch_words};
+
+pub const DRIVER_NUM: usize = 0x40005;
+
+/// The maximum supported modulus length, in bytes. Larger keys fail with
+/// `ENOMEM`, which keeps the scratch space at 3 KiB of kernel RAM.
+pub const MAX_MODULUS_LEN: usize = 3072 / 8;
+
+/// Scratch space for the big number arithmetic.
+pub static mut RSA_SCRATCH: [u32; scratch_words(MAX_MODULUS_LEN)] =
+    [0; scratch_words(MAX_MODULUS_LEN)];
+
+/// Per-application driver data.
+#[derive(Default)]
+pub struct App {
+    /// The big-endian modulus of the public key.
+    modulus: Option<AppSlice<Shared, u8>>,
+    /// The signature to verify.
+    signature: Option<AppSlice<Shared, u8>>,
+    /// The SHA-256 digest of the signed message.
+    digest: Option<AppSlice<Shared, u8>>,
+}
+
+pub struct RsaDriver<'a> {
+    apps: Grant<App>,
+    scratch: TakeCell<'a, [u32]>,
+}
+
+impl<'a> RsaDriver<'a> {
+    pub fn new(container: Grant<App>, scratch: &'a mut [u32]) -> RsaDriver<'a> {
+        RsaDriver {
+            apps: container,
+            scratch: TakeCell::new(scratch),
+        }
+    }
+
+    fn verify(&self, app_data: &App, exponent: u32) -> ReturnCode {
+        let (modulus, signature, digest) =
+            match (&app_data.modulus, &app_data.signature, &app_data.digest) {
+                (Some(modulus), Some(signature), Some(digest)) => (modulus, signature, digest),
+                _ => return ReturnCode::ENOMEM,
+            };
+        let digest = match <&[u8; SHA256_DIGEST_LEN]>::try_from(digest.as_ref()) {
+            Ok(digest) => digest,
+            Err(_) => return ReturnCode::ESIZE,
+        };
+        let key = match PublicKey::new(modulus.as_ref(), exponent) {
+            Ok(key) => key,
+            Err(_) => return ReturnCode::EINVAL,
+        };
+        self.scratch.map_or(ReturnCode::EBUSY, |scratch| {
+            match key.verify_pkcs1_sha256(signature.as_ref(), digest, scratch) {
+                Ok(()) => ReturnCode::SUCCESS,
+                Err(Error::InvalidSignature) => ReturnCode::FAIL,
+                Err(Error::BufferTooSmall) => ReturnCode::ENOMEM,
+                Err(Error::UnsupportedModulus) | Err(Error::InvalidExponent) => ReturnCode::EINVAL,
+            }
+        })
+    }
+}
+
+const COMMAND_CHECK: usize  = 0;
+const COMMAND_VERIFY: usize = 1;
+
+const ALLOW_MODULUS: usize   = 0;
+const ALLOW_SIGNATURE: usize = 1;
+const ALLOW_DIGEST: usize    = 2;
+
+impl<'a> Driver for RsaDriver<'a> {
+    fn command(&self, minor_num: usize, r2: usize, _r3: usize, caller_id: AppId) -> ReturnCode {
+        match minor_num {
+            COMMAND_CHECK => ReturnCode::SUCCESS,
+            // Verify the signature (arg: public exponent). Returns FAIL if
+            // the signature doesn't match the digest.
+            COMMAND_VERIFY => {
+                self.apps
+                    .enter(caller_id, |app_data, _| self.verify(app_data, r2 as u32))
+                    .unwrap_or(ReturnCode::ENOMEM)
+            },
+            _ => ReturnCode::ENOSUPPORT
+        }
+    }
+
+    fn allow(&self,
+             app_id: AppId,
+             allow_num: usize,
+             slice: Option<AppSlice<Shared, u8>>
+    ) -> ReturnCode {
+        self.apps
+            .enter(app_id, |app_data, _| {
+                match allow_num {
+                    ALLOW_MODULUS => app_data.modulus = slice,
+                    ALLOW_SIGNATURE => app_data.signature = slice,
+                    ALLOW_DIGEST => app_data.digest = slice,
+                    _ => return ReturnCode::ENOSUPPORT,
+                }
+                ReturnCode::SUCCESS
+            })
+            .unwrap_or(ReturnCode::ENOMEM)
+    }
+}
@@ -1,4 +1,5 @@
 use core::cell::Cell;
+use core::cmp::min;
 use core::convert::TryFrom;
 
 use h1::hil::spi_device::AddressConfig;
@@ -16,6 +17,7 @@ use kernel::Shared;
 use spiutils::driver::HandlerMode;
 use spiutils::protocol::flash::AddressMode;
 use spiutils::protocol::flash::OpCode;
+use spiutils::protocol::wire::FieldError;
 use spiutils::protocol::wire::FromWireError;
 use spiutils::protocol::wire::WireEnum;
 
@@ -111,8 +113,15 @@ impl<'a> SpiDeviceSyscall<'a> {
         }).unwrap_or(ReturnCode::ENOMEM)
     }
 
-    fn process_spi_cmd(&self, app_data: &AppData, spi_cmd: u8, maybe_spi_data: Option<u8>) -> Result<HandlerMode, FromWireError> {
-        let op_code = OpCode::from_wire_value(spi_cmd).ok_or(FromWireError::OutOfRange)?;
+    fn get_status(&self, caller_id: AppId) -> ReturnCode {
+        self.apps.enter(caller_id, |_app_data, _| {
+            ReturnCode::SuccessWithValue { value: self.device.get_status().bits() as usize }
+        }).unwrap_or(ReturnCode::ENOMEM)
+    }
+
+    fn process_spi_cmd(&self, app_data: &AppData, spi_cmd: u8, spi_data: &[u8]) -> Result<HandlerMode, FromWireError> {
+        let op_code = OpCode::from_wire_value(spi_cmd).ok_or(
+            FromWireError::UnknownValue(FieldError::new("opcode").with_value(spi_cmd).at(0)))?;
 
         match op_code {
             OpCode::Enter4ByteAddressMode | OpCode::Exit4ByteAddressMode =>
@@ -121,7 +130,8 @@ impl<'a> SpiDeviceSyscall<'a> {
                         let address_mode = match op_code {
                             OpCode::Enter4ByteAddressMode => AddressMode::FourByte,
                             OpCode::Exit4ByteAddressMode => AddressMode::ThreeByte,
-                            _ => return Err(FromWireError::OutOfRange)
+                            _ => return Err(FromWireError::UnknownValue(
+                                FieldError::new("opcode").with_value(spi_cmd).at(0)))
                         };
                         let mut has_address_mode_changed = false;
                         if self.device.get_address_mode() != address_mode {
@@ -138,9 +148,9 @@ impl<'a> SpiDeviceSyscall<'a> {
                     handler_mode => Ok(handler_mode)
                 },
             OpCode::WriteStatusRegister =>
-                if let Some(spi_data) = maybe_spi_data {
+                if let Some(status) = self.device.get_status().written(spi_data) {
                     if self.device.is_write_enable_set() {
-                        self.device.set_status(spi_data);
+                        self.device.set_status(status);
                         self.device.clear_write_enable();
                     }
                     self.device.clear_busy();
@@ -180,32 +190,23 @@ impl<'a> SpiDeviceClient for SpiDeviceSyscall<'a> {
             let _ = self.apps.enter(current_user, move |app_data, _| {
                 let mut rx_len = 0;
                 let mut handler_mode = HandlerMode::UserSpace;
-                let mut maybe_spi_cmd : Option<u8> = None;
-                let mut maybe_spi_data : Option<u8> = None;
+                // The op code and up to two data bytes (for OpCode::WriteStatusRegister)
+                let mut spi_cmd_buf = [!0; 3];
+                let spi_cmd_buf_len;
                 if let Some(ref mut rx_buffer) = app_data.rx_buffer {
                     rx_len = self.device.get_received_data(rx_buffer.as_mut());
-                    if rx_len > 0 {
-                        maybe_spi_cmd = Some(rx_buffer.as_ref()[0]);
-                    }
-                    if rx_len > 1 {
-                        maybe_spi_data = Some(rx_buffer.as_ref()[1]);
-                    }
+                    spi_cmd_buf_len = min(rx_len, spi_cmd_buf.len());
+                    spi_cmd_buf[..spi_cmd_buf_len].copy_from_slice(&rx_buffer.as_ref()[..spi_cmd_buf_len]);
                 } else {
-                    // Just grab the first two bytes
-                    let mut spi_cmd_buf = [!0, !0];
-                    let spi_cmd_buf_len = self.device.get_received_data(&mut spi_cmd_buf);
-                    if spi_cmd_buf_len > 0 {
-                        maybe_spi_cmd = Some(spi_cmd_buf[0]);
-                    }
-                    if spi_cmd_buf_len > 1 {
-                        maybe_spi_data = Some(spi_cmd_buf[1]);
-                    }
+                    // Just grab the first few bytes
+                    spi_cmd_buf_len = self.device.get_received_data(&mut spi_cmd_buf);
                 }
 
                 // Handle some special op code straight in kernel space
-                if let Some(spi_cmd) = maybe_spi_cmd {
+                if spi_cmd_buf_len > 0 {
+                    let spi_cmd = spi_cmd_buf[0];
                     //debug!("spi_cmd: {:?}", spi_cmd);
-                    handler_mode = match self.process_spi_cmd(app_data, spi_cmd, maybe_spi_data) {
+                    handler_mode = match self.process_spi_cmd(app_data, spi_cmd, &spi_cmd_buf[1..spi_cmd_buf_len]) {
                         Ok(mode) => mode,
                         Err(_) => HandlerMode::UserSpace,
                     }
@@ -296,6 +297,10 @@ impl<'a> Driver for SpiDeviceSyscall<'a> {
             7 /* Set SFDP using data from TX buffer */ => {
                 self.set_sfdp(caller_id)
             }
+            8 /* Get status
+                 returns: StatusRegister bits */ => {
+                self.get_status(caller_id)
+            }
             _ => ReturnCode::ENOSUPPORT
         }
     }
@@ -1,26 +1,127 @@
+//! System call driver for the H1 specific functions of the SPI host and for
+//! transfers to and from the SPI flash connected to it.
+//!
+//! The driver implements 4 commands:
+//!   0. check if the driver is present (ReturnCode::SUCCESS if so)
+//!   1. enable/disable SPI device <-> SPI host passthrough.
+//!   2. enable/disable waiting for the BUSY bit to clear before completing
+//!      transactions. Transfers (command 3) override the setting for their
+//!      own transactions and restore it when they are done.
+//!   3. transfer(opcode | flags, address), execute the SPI flash command
+//!      `opcode` at `address`, completion signaled by a callback. The data
+//!      of the command is sent from the write buffer or received into the
+//!      read buffer, depending on the op code, and may be arbitrarily long.
+//!      The driver splits the command into transactions that fit into the
+//!      SPI host's FIFOs, advancing the address and splitting page programs
+//!      at page boundaries. Returns an identifier of the transfer, which
+//!      is passed to its callback. Flags:
+//!        TRANSFER_FOUR_BYTE_ADDRESS: use 4 byte addresses for op codes whose
+//!          address width depends on the address mode.
+//!        TRANSFER_WRITE_ENABLE: send a WriteEnable command before each
+//!          transaction.
+//!
+//! The driver implements 2 allows:
+//!   0. userspace buffer with the data to send (command 3).
+//!   1. userspace buffer receiving the data read (command 3).
+//!
+//! The driver implements 1 subscribe:
+//!   0. callback for when a transfer completes, with its ReturnCode and its
+//!      identifier.
+
 use core::cell::Cell;
 use h1::hil::spi_host::SpiHost;
 use kernel::{AppId, Callback, Driver, Grant, ReturnCode, Shared, AppSlice};
+use kernel::common::cells::{OptionalCell, TakeCell};
+use kernel::hil::spi::{SpiMasterClient, SpiMasterDevice};
+use spiutils::io::Cursor;
+use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::Chunk;
+use spiutils::protocol::flash::DataDirection;
+use spiutils::protocol::flash::Header;
+use spiutils::protocol::flash::OpCode;
+use spiutils::protocol::flash::Transfer;
+use spiutils::protocol::wire::WireEnum;
 
 pub const DRIVER_NUM: usize = 0x40020;
 
+/// The size of the SPI host's FIFOs, which limits the length of a single
+/// transaction.
+pub const MAX_TRANSACTION_LEN: usize = 128;
+
+pub static mut TX_BUFFER: [u8; MAX_TRANSACTION_LEN] = [0; MAX_TRANSACTION_LEN];
+pub static mut RX_BUFFER: [u8; MAX_TRANSACTION_LEN] = [0; MAX_TRANSACTION_LEN];
+
+const COMMAND_CHECK: usize               = 0;
+const COMMAND_PASSTHROUGH: usize         = 1;
+const COMMAND_WAIT_BUSY_CLEAR: usize     = 2;
+const COMMAND_TRANSFER: usize            = 3;
+const ALLOW_WRITE_BUFFER: usize          = 0;
+const ALLOW_READ_BUFFER: usize           = 1;
+const SUBSCRIBE_TRANSFER_DONE: usize     = 0;
+
+const TRANSFER_OPCODE_MASK: usize        = 0xff;
+const TRANSFER_FOUR_BYTE_ADDRESS: usize  = 1 << 8;
+const TRANSFER_WRITE_ENABLE: usize       = 1 << 9;
+
+/// Transfer identifiers wrap around before they would be returned as a
+/// negative value, which apps take for an error.
+const TRANSFER_ID_MASK: usize            = 0x7fff_ffff;
+
 #[derive(Default)]
 pub struct AppData {
+    write_buffer: Option<AppSlice<Shared, u8>>,
+    read_buffer: Option<AppSlice<Shared, u8>>,
+    callback: Option<Callback>,
+}
+
+/// The transaction of a transfer that is currently executed.
+#[derive(Clone, Copy, PartialEq)]
+enum Step {
+    WriteEnable,
+    Data,
 }
 
 pub struct SpiHostSyscall<'a> {
     device: &'a dyn SpiHost,
+    spi: &'a dyn SpiMasterDevice,
     apps: Grant<AppData>,
     current_user: Cell<Option<AppId>>,
+    tx_buffer: TakeCell<'static, [u8]>,
+    rx_buffer: TakeCell<'static, [u8]>,
+    // The state of the running transfer.
+    transfer_user: OptionalCell<AppId>,
+    transfer: Cell<Option<Transfer>>,
+    chunk: Cell<Option<Chunk>>,
+    step: Cell<Step>,
+    write_enable: Cell<bool>,
+    // The wait busy clear setting of the app, which transfers override for
+    // their transactions and restore when they are done.
+    wait_busy_clear: Cell<bool>,
+    // The identifier of the running or the last transfer. It lets the app
+    // tell the callback of a transfer it gave up on from the current one.
+    transfer_id: Cell<usize>,
 }
 
 impl<'a> SpiHostSyscall<'a> {
     pub fn new(device: &'a dyn SpiHost,
+               spi: &'a dyn SpiMasterDevice,
+               tx_buffer: &'static mut [u8],
+               rx_buffer: &'static mut [u8],
                container: Grant<AppData>) -> SpiHostSyscall<'a> {
         SpiHostSyscall {
             device: device,
+            spi: spi,
             apps: container,
             current_user: Cell::new(None),
+            tx_buffer: TakeCell::new(tx_buffer),
+            rx_buffer: TakeCell::new(rx_buffer),
+            transfer_user: OptionalCell::empty(),
+            transfer: Cell::new(None),
+            chunk: Cell::new(None),
+            step: Cell::new(Step::Data),
+            write_enable: Cell::new(false),
+            wait_busy_clear: Cell::new(false),
+            transfer_id: Cell::new(0),
         }
     }
 
@@ -33,50 +134,267 @@ impl<'a> SpiHostSyscall<'a> {
 
     fn wait_busy_clear_in_transactions(&self, caller_id: AppId, enable: bool) -> ReturnCode {
         self.apps.enter(caller_id, |_app_data, _| {
-            self.device.wait_busy_clear_in_transactions(enable);
+            self.wait_busy_clear.set(enable);
+            // A running transfer applies the setting when it is done.
+            if self.transfer_user.is_none() {
+                self.device.wait_busy_clear_in_transactions(enable);
+            }
             ReturnCode::SUCCESS
         }).unwrap_or(ReturnCode::ENOMEM)
     }
+
+    fn start_transfer(&self, caller_id: AppId, flags: usize, address: usize) -> ReturnCode {
+        if self.transfer_user.is_some() {
+            return ReturnCode::EBUSY;
+        }
+        let opcode = match OpCode::from_wire_value((flags & TRANSFER_OPCODE_MASK) as u8) {
+            Some(opcode) => opcode,
+            None => return ReturnCode::EINVAL,
+        };
+        let address_mode = if flags & TRANSFER_FOUR_BYTE_ADDRESS != 0 {
+            AddressMode::FourByte
+        } else {
+            AddressMode::ThreeByte
+        };
+        let header = Header {
+            opcode,
+            address_mode,
+            address: if opcode.address_len(address_mode) > 0 { Some(address as u32) } else { None },
+        };
+
+        let data_len = self.apps.enter(caller_id, |app_data, _| {
+            let buffer = match opcode.descriptor().data_direction {
+                DataDirection::None => return Ok(0),
+                DataDirection::HostToDevice => &app_data.write_buffer,
+                DataDirection::DeviceToHost => &app_data.read_buffer,
+            };
+            buffer.as_ref().map(|buffer| buffer.len()).ok_or(ReturnCode::ENOMEM)
+        }).unwrap_or(Err(ReturnCode::ENOMEM));
+        let data_len = match data_len {
+            Ok(data_len) => data_len,
+            Err(rcode) => return rcode,
+        };
+        let transfer = match Transfer::new(header, data_len, MAX_TRANSACTION_LEN) {
+            Ok(transfer) => transfer,
+            Err(_) => return ReturnCode::EINVAL,
+        };
+
+        let id = self.transfer_id.get().wrapping_add(1) & TRANSFER_ID_MASK;
+        self.transfer_id.set(id);
+        self.transfer_user.set(caller_id);
+        self.transfer.set(Some(transfer));
+        self.write_enable.set(flags & TRANSFER_WRITE_ENABLE != 0);
+        let rcode = self.next_chunk();
+        if rcode != ReturnCode::SUCCESS {
+            self.device.wait_busy_clear_in_transactions(self.wait_busy_clear.get());
+            self.transfer_user.clear();
+            self.transfer.set(None);
+            return rcode;
+        }
+        ReturnCode::SuccessWithValue { value: id }
+    }
+
+    /// Starts the next transaction of the running transfer, or completes the
+    /// transfer if all data has been transferred.
+    fn next_chunk(&self) -> ReturnCode {
+        let mut transfer = match self.transfer.get() {
+            Some(transfer) => transfer,
+            None => return ReturnCode::FAIL,
+        };
+        let chunk = transfer.next();
+        self.transfer.set(Some(transfer));
+        self.chunk.set(chunk);
+        match chunk {
+            None => {
+                self.transfer_done(ReturnCode::SUCCESS);
+                ReturnCode::SUCCESS
+            }
+            Some(_) if self.write_enable.get() => self.send_write_enable(),
+            Some(chunk) => self.send_chunk(&chunk),
+        }
+    }
+
+    fn send_write_enable(&self) -> ReturnCode {
+        self.step.set(Step::WriteEnable);
+        let tx_buffer = match self.tx_buffer.take() {
+            Some(tx_buffer) => tx_buffer,
+            None => return ReturnCode::EBUSY,
+        };
+        tx_buffer[0] = OpCode::WriteEnable.to_wire_value();
+        self.device.wait_busy_clear_in_transactions(false);
+        self.spi.read_write_bytes(tx_buffer, self.rx_buffer.take(), 1)
+    }
+
+    fn send_chunk(&self, chunk: &Chunk) -> ReturnCode {
+        self.step.set(Step::Data);
+        let tx_buffer = match self.tx_buffer.take() {
+            Some(tx_buffer) => tx_buffer,
+            None => return ReturnCode::EBUSY,
+        };
+        let header_len = chunk.header.wire_len();
+        if chunk.header.to_wire(Cursor::new(tx_buffer)).is_err() {
+            self.tx_buffer.replace(tx_buffer);
+            return ReturnCode::EINVAL;
+        }
+        if chunk.header.opcode.descriptor().data_direction == DataDirection::HostToDevice {
+            let rcode = self.transfer_user.map_or(ReturnCode::FAIL, |user| {
+                self.apps.enter(*user, |app_data, _| {
+                    match app_data.write_buffer {
+                        Some(ref data) if data.len() >= chunk.offset + chunk.len => {
+                            tx_buffer[header_len..header_len + chunk.len]
+                                .copy_from_slice(&data.as_ref()[chunk.offset..chunk.offset + chunk.len]);
+                            ReturnCode::SUCCESS
+                        }
+                        // The buffer was replaced during the transfer.
+                        Some(_) => ReturnCode::ESIZE,
+                        None => ReturnCode::ENOMEM,
+                    }
+                }).unwrap_or(ReturnCode::ENOMEM)
+            });
+            if rcode != ReturnCode::SUCCESS {
+                self.tx_buffer.replace(tx_buffer);
+                return rcode;
+            }
+        } else {
+            // Don't send stale data of previous transactions while reading.
+            for byte in tx_buffer[header_len..header_len + chunk.len].iter_mut() {
+                *byte = 0xff;
+            }
+        }
+        self.device.wait_busy_clear_in_transactions(chunk.header.opcode.wait_busy_clear());
+        self.spi.read_write_bytes(tx_buffer, self.rx_buffer.take(), header_len + chunk.len)
+    }
+
+    /// Copies the data received by the transaction of `chunk` into the read
+    /// buffer of the transfer user.
+    fn receive_chunk(&self, chunk: &Chunk, rx_buffer: &[u8]) -> ReturnCode {
+        let header_len = chunk.header.wire_len();
+        self.transfer_user.map_or(ReturnCode::FAIL, |user| {
+            self.apps.enter(*user, |app_data, _| {
+                match app_data.read_buffer {
+                    Some(ref mut data) if data.len() >= chunk.offset + chunk.len => {
+                        data.as_mut()[chunk.offset..chunk.offset + chunk.len]
+                            .copy_from_slice(&rx_buffer[header_len..header_len + chunk.len]);
+                        ReturnCode::SUCCESS
+                    }
+                    // The buffer was replaced during the transfer.
+                    Some(_) => ReturnCode::ESIZE,
+                    None => ReturnCode::ENOMEM,
+                }
+            }).unwrap_or(ReturnCode::ENOMEM)
+        })
+    }
+
+    fn transfer_done(&self, rcode: ReturnCode) {
+        self.device.wait_busy_clear_in_transactions(self.wait_busy_clear.get());
+        self.transfer.set(None);
+        self.chunk.set(None);
+        self.transfer_user.take().map(|user| {
+            let _ = self.apps.enter(user, |app_data, _| {
+                app_data.callback.map(|mut cb| cb.schedule(From::from(rcode), self.transfer_id.get(), 0));
+            });
+        });
+    }
 }
 
 impl<'a> Driver for SpiHostSyscall<'a> {
     fn subscribe(&self,
                  subscribe_num: usize,
-                 _callback: Option<Callback>,
-                 _app_id: AppId,
+                 callback: Option<Callback>,
+                 app_id: AppId,
     ) -> ReturnCode {
         match subscribe_num {
+            SUBSCRIBE_TRANSFER_DONE => {
+                self.apps.enter(app_id, |app_data, _| {
+                    app_data.callback = callback;
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            }
             _ => ReturnCode::ENOSUPPORT
         }
     }
 
-    fn command(&self, command_num: usize, arg1: usize, _arg2: usize, caller_id: AppId)
+    fn command(&self, command_num: usize, arg1: usize, arg2: usize, caller_id: AppId)
         -> ReturnCode {
         if self.current_user.get() == None {
             self.current_user.set(Some(caller_id));
         }
         match command_num {
-            0 /* Check if present */ => ReturnCode::SUCCESS,
-            1 /* Enable/disable SPI device <-> SPI host passthrough.
-                 arg1: 0: disable, != 0: enable) */ => {
+            COMMAND_CHECK => ReturnCode::SUCCESS,
+            /* Enable/disable SPI device <-> SPI host passthrough.
+               arg1: 0: disable, != 0: enable) */
+            COMMAND_PASSTHROUGH => {
                 self.spi_device_spi_host_passthrough(caller_id, arg1 != 0)
             },
-            2 /* Enable/disable to wait for BUSY bit to clear before completing
-                 transactions.
-                 arg1: 0: disable, != 0: enable) */ => {
+            /* Enable/disable to wait for BUSY bit to clear before completing
+               transactions.
+               arg1: 0: disable, != 0: enable) */
+            COMMAND_WAIT_BUSY_CLEAR => {
                 self.wait_busy_clear_in_transactions(caller_id, arg1 != 0)
             },
+            /* Execute a SPI flash command.
+               arg1: op code | TRANSFER_* flags
+               arg2: address */
+            COMMAND_TRANSFER => self.start_transfer(caller_id, arg1, arg2),
             _ => ReturnCode::ENOSUPPORT
         }
     }
 
     fn allow(&self,
-             _app_id: AppId,
+             app_id: AppId,
              minor_num: usize,
-             _slice: Option<AppSlice<Shared, u8>>
+             slice: Option<AppSlice<Shared, u8>>
     ) -> ReturnCode {
         match minor_num {
+            ALLOW_WRITE_BUFFER => {
+                self.apps.enter(app_id, |app_data, _| {
+                    app_data.write_buffer = slice;
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            },
+            ALLOW_READ_BUFFER => {
+                self.apps.enter(app_id, |app_data, _| {
+                    app_data.read_buffer = slice;
+                    ReturnCode::SUCCESS
+                }).unwrap_or(ReturnCode::ENOMEM)
+            },
             _ => ReturnCode::ENOSUPPORT,
         }
     }
 }
+
+impl<'a> SpiMasterClient for SpiHostSyscall<'a> {
+    fn read_write_done(&self,
+                       write_buffer: &'static mut [u8],
+                       read_buffer: Option<&'static mut [u8]>,
+                       _len: usize) {
+        self.tx_buffer.replace(write_buffer);
+        let chunk = match self.chunk.get() {
+            Some(chunk) => chunk,
+            None => {
+                read_buffer.map(|buffer| self.rx_buffer.replace(buffer));
+                return;
+            }
+        };
+
+        let mut rcode = ReturnCode::SUCCESS;
+        if self.step.get() == Step::Data
+            && chunk.header.opcode.descriptor().data_direction == DataDirection::DeviceToHost {
+            rcode = match read_buffer {
+                Some(ref buffer) => self.receive_chunk(&chunk, buffer),
+                None => ReturnCode::FAIL,
+            };
+        }
+        read_buffer.map(|buffer| self.rx_buffer.replace(buffer));
+
+        if rcode == ReturnCode::SUCCESS {
+            rcode = match self.step.get() {
+                Step::WriteEnable => self.send_chunk(&chunk),
+                Step::Data => self.next_chunk(),
+            };
+        }
+        if rcode != ReturnCode::SUCCESS {
+            self.transfer_done(rcode);
+        }
+    }
+}
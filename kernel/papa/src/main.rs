@@ -52,6 +52,15 @@ use h1::timels::Timels;
 // State for loading apps
 const NUM_PROCS: usize = 1;
 
+// The running kernel and apps are the RW image of the first flash bank (see
+// chip_layout.ld). Firmware updates are written to the RW image of the
+// inactive second bank, which follows its 16K RO image and ends before the
+// last three pages, which are used by Personality and the non-volatile counter.
+const FW_UPDATE_START: usize = h1::hil::flash::h1_hw::H1_FLASH_START + h1::hil::flash::h1_hw::H1_FLASH_BANK_SIZE
+    + 0x4000;
+const FW_UPDATE_END: usize = h1::hil::flash::h1_hw::H1_FLASH_START + h1::hil::flash::h1_hw::H1_FLASH_SIZE
+    - 3 * h1::hil::flash::h1_hw::H1_FLASH_PAGE_SIZE;
+
 // how should the kernel respond when a process faults
 const FAULT_RESPONSE: kernel::procs::FaultResponse = kernel::procs::FaultResponse::Panic;
 
@@ -89,6 +98,7 @@ pub struct Papa {
     h1_spi_device_syscalls: &'static h1_syscalls::spi_device::SpiDeviceSyscall<'static>,
     spi_host_syscalls: &'static capsules::spi::Spi<'static, VirtualSpiMasterDevice<'static, h1::spi_host::SpiHostHardware>>,
     dcrypto: &'static h1_syscalls::dcrypto::DcryptoDriver<'static>,
+    rsa: &'static h1_syscalls::rsa::RsaDriver<'static>,
     low_level_debug: &'static capsules::low_level_debug::LowLevelDebug<
         'static,
         capsules::virtual_uart::UartDevice<'static>
@@ -96,6 +106,8 @@ pub struct Papa {
     nvcounter: &'static h1_syscalls::nvcounter_syscall::NvCounterSyscall<'static,
         FlashCounter<'static, h1::hil::flash::virtual_flash::FlashUser<'static>>>,
     personality: &'static h1_syscalls::personality::PersonalitySyscall<'static>,
+    reset: &'static h1_syscalls::reset::ResetSyscall<'static>,
+    fw_update: &'static h1_syscalls::fw_update::FwUpdateSyscall<'static>,
 }
 
 #[no_mangle]
@@ -103,6 +115,7 @@ pub unsafe fn reset_handler() {
     use kernel::hil::time::Alarm;
 
     h1::init();
+    h1::reset::init();
 
     let timerhs = {
         use h1::pmu::*;
@@ -116,6 +129,9 @@ pub unsafe fn reset_handler() {
     timerhs.start();
     let start = timerhs.now();
 
+    let uptime_counter = static_init!(h1::timeus::Timeus, h1::timeus::Timeus::new(1));
+    uptime_counter.start_with_divider(h1_syscalls::reset::UPTIME_DIVIDER);
+
     {
         use h1::pmu::*;
         Clock::new(PeripheralClock::Bank0(PeripheralClock0::Gpio0)).enable();
@@ -232,6 +248,9 @@ pub unsafe fn reset_handler() {
     let nvcounter_flash = static_init!(h1::hil::flash::virtual_flash::FlashUser<'static>,
                                        h1::hil::flash::virtual_flash::FlashUser::new(flash_mux));
 
+    let fw_update_flash = static_init!(h1::hil::flash::virtual_flash::FlashUser<'static>,
+                                       h1::hil::flash::virtual_flash::FlashUser::new(flash_mux));
+
     flash.set_client(flash_mux);
 
     let timer_virtual_alarm = static_init!(VirtualMuxAlarm<'static, Timels>,
@@ -260,6 +279,10 @@ pub unsafe fn reset_handler() {
 
     h1::crypto::dcrypto::DCRYPTO.set_client(dcrypto);
 
+    let rsa = static_init!(
+        h1_syscalls::rsa::RsaDriver<'static>,
+        h1_syscalls::rsa::RsaDriver::new(kernel.create_grant(&grant_cap), &mut h1_syscalls::rsa::RSA_SCRATCH));
+
     let nvcounter_buffer = static_init!([u32; 1], [0]);
     let nvcounter = static_init!(
         FlashCounter<'static, h1::hil::flash::virtual_flash::FlashUser<'static>>,
@@ -299,13 +322,36 @@ pub unsafe fn reset_handler() {
     h1::personality::PERSONALITY.set_client(personality);
     flash_user.set_client(&h1::personality::PERSONALITY);
 
+    let reset = static_init!(
+        h1_syscalls::reset::ResetSyscall<'static>,
+        h1_syscalls::reset::ResetSyscall::new(uptime_counter, kernel.create_grant(&grant_cap)));
+
+    let fw_update = static_init!(
+        h1_syscalls::fw_update::FwUpdateSyscall<'static>,
+        h1_syscalls::fw_update::FwUpdateSyscall::new(
+            fw_update_flash,
+            (FW_UPDATE_START - h1::hil::flash::h1_hw::H1_FLASH_START) / h1::hil::flash::h1_hw::H1_FLASH_PAGE_SIZE,
+            (FW_UPDATE_END - FW_UPDATE_START) / h1::hil::flash::h1_hw::H1_FLASH_PAGE_SIZE,
+            &mut h1_syscalls::fw_update::WRITE_BUFFER,
+            kernel.create_grant(&grant_cap)));
+    fw_update_flash.set_client(fw_update);
+
     h1::spi_host::SPI_HOST0.init();
+    let spi_host_mux = components::spi::SpiMuxComponent::new(&h1::spi_host::SPI_HOST0)
+        .finalize(components::spi_mux_component_helper!(h1::spi_host::SpiHostHardware));
+    let h1_spi_host_device = static_init!(
+        VirtualSpiMasterDevice<'static, h1::spi_host::SpiHostHardware>,
+        VirtualSpiMasterDevice::new(spi_host_mux, false));
     let h1_spi_host_syscalls = static_init!(
         h1_syscalls::spi_host::SpiHostSyscall<'static>,
-        h1_syscalls::spi_host::SpiHostSyscall::new(&h1::spi_host::SPI_HOST0, kernel.create_grant(&grant_cap))
+        h1_syscalls::spi_host::SpiHostSyscall::new(
+            &h1::spi_host::SPI_HOST0,
+            h1_spi_host_device,
+            &mut h1_syscalls::spi_host::TX_BUFFER,
+            &mut h1_syscalls::spi_host::RX_BUFFER,
+            kernel.create_grant(&grant_cap))
     );
-    let spi_host_mux = components::spi::SpiMuxComponent::new(&h1::spi_host::SPI_HOST0)
-        .finalize(components::spi_mux_component_helper!(h1::spi_host::SpiHostHardware));
+    h1_spi_host_device.set_client(h1_spi_host_syscalls);
     let spi_host_syscalls = SpiSyscallComponent::new(spi_host_mux, false)
         .finalize(components::spi_syscall_component_helper!(h1::spi_host::SpiHostHardware));
 
@@ -364,16 +410,16 @@ pub unsafe fn reset_handler() {
         vs(DUSB0_REGION3_CTRL as *mut u32, !0);
 
         // Flash region initialization. We initialize a single region for the
-        // last three pages of the second flash macro, used by Personality (n-3)
-        // and the non-volatile counter implementation (n-2, n-1).
+        // firmware update region, which is followed by the last three pages of
+        // the second flash macro, used by Personality (n-3) and the
+        // non-volatile counter implementation (n-2, n-1).
         const FLASH_START: usize = 0x40000;
         const FLASH_SIZE: usize = 512 * 1024;
-        const FLASH_PAGE_SIZE: usize = 2048;
-        vs(FLASH_REGION2_BASE as *mut u32, (FLASH_START + FLASH_SIZE - 3*FLASH_PAGE_SIZE) as u32);
+        vs(FLASH_REGION2_BASE as *mut u32, FW_UPDATE_START as u32);
         // The value of the SIZE register is one less than the size of the
         // region, i.e. the last address within the region is the start address
         // + the size register.
-        vs(FLASH_REGION2_SIZE as *mut u32, (3*FLASH_PAGE_SIZE - 1) as u32);
+        vs(FLASH_REGION2_SIZE as *mut u32, (FLASH_START + FLASH_SIZE - FW_UPDATE_START - 1) as u32);
         // Enable the region for reads and writes.
         vs(FLASH_REGION2_CTRL as *mut u32, 0b111);
     }
@@ -395,6 +441,7 @@ pub unsafe fn reset_handler() {
         digest: digest,
         aes: aes,
         dcrypto: dcrypto,
+        rsa: rsa,
         low_level_debug,
         nvcounter: nvcounter_syscall,
         rng: rng,
@@ -402,6 +449,8 @@ pub unsafe fn reset_handler() {
         h1_spi_host_syscalls: h1_spi_host_syscalls,
         h1_spi_device_syscalls: h1_spi_device_syscalls,
         personality: personality,
+        reset: reset,
+        fw_update: fw_update,
     };
 
     // Uncomment to initialize NvCounter
@@ -449,9 +498,12 @@ impl Platform for Papa {
             h1_syscalls::spi_device::DRIVER_NUM        => f(Some(self.h1_spi_device_syscalls)),
             h1_syscalls::aes::DRIVER_NUM               => f(Some(self.aes)),
             h1_syscalls::dcrypto::DRIVER_NUM           => f(Some(self.dcrypto)),
+            h1_syscalls::rsa::DRIVER_NUM               => f(Some(self.rsa)),
             h1_syscalls::digest::DRIVER_NUM            => f(Some(self.digest)),
             h1_syscalls::nvcounter_syscall::DRIVER_NUM => f(Some(self.nvcounter)),
             h1_syscalls::personality::DRIVER_NUM       => f(Some(self.personality)),
+            h1_syscalls::reset::DRIVER_NUM             => f(Some(self.reset)),
+            h1_syscalls::fw_update::DRIVER_NUM         => f(Some(self.fw_update)),
             kernel::ipc::DRIVER_NUM                    => f(Some(&self.ipc)),
             _ =>  f(None),
         }
@@ -0,0 +1,222 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+
+//! Constant-time arithmetic on big numbers.
+//!
+//! Numbers are stored as little-endian slices of 32-bit limbs. All
+//! operations run in time that only depends on the number of limbs and, for
+//! `mod_exp`, on the (public) exponent.
+
+/// The maximum number of limbs in a modulus.
+pub const MAX_LIMBS: usize = 4096 / 32;
+
+/// The number of limbs of scratch space needed by `mod_exp`.
+pub const SCRATCH_LIMBS: usize = 5 * MAX_LIMBS;
+
+/// Converts the big-endian `bytes` into `limbs`.
+///
+/// `bytes` must be exactly four times as long as `limbs`.
+pub fn from_be_bytes(bytes: &[u8], limbs: &mut [u32]) {
+    debug_assert_eq!(bytes.len(), limbs.len() * 4);
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(4)) {
+        *limb = u32::from(chunk[0]) << 24 | u32::from(chunk[1]) << 16
+            | u32::from(chunk[2]) << 8 | u32::from(chunk[3]);
+    }
+}
+
+/// Returns the `index`th byte of the big-endian representation of `limbs`.
+pub fn be_byte(limbs: &[u32], index: usize) -> u8 {
+    let le_index = limbs.len() * 4 - 1 - index;
+    (limbs[le_index / 4] >> (8 * (le_index % 4))) as u8
+}
+
+/// Returns whether `a < n`.
+pub fn less_than(a: &[u32], n: &[u32]) -> bool {
+    let mut borrow = 0u64;
+    for (a, n) in a.iter().zip(n.iter()) {
+        let diff = u64::from(*a).wrapping_sub(u64::from(*n)).wrapping_sub(borrow);
+        borrow = diff >> 63;
+    }
+    borrow != 0
+}
+
+/// Subtracts `n` from `top:a` if `top:a >= n`, where `top` is an additional
+/// most significant limb.
+fn reduce_once(a: &mut [u32], top: u32, n: &[u32]) {
+    let greater_equal = (top != 0) | !less_than(a, n);
+    let mask = 0u32.wrapping_sub(greater_equal as u32);
+    let mut borrow = 0u64;
+    for (a, n) in a.iter_mut().zip(n.iter()) {
+        let diff = u64::from(*a).wrapping_sub(u64::from(*n & mask)).wrapping_sub(borrow);
+        *a = diff as u32;
+        borrow = diff >> 63;
+    }
+}
+
+/// Sets `a` to `2 * a mod n`, for `a < n`.
+fn double_mod(a: &mut [u32], n: &[u32]) {
+    let mut carry = 0;
+    for limb in a.iter_mut() {
+        let next_carry = *limb >> 31;
+        *limb = *limb << 1 | carry;
+        carry = next_carry;
+    }
+    reduce_once(a, carry, n);
+}
+
+/// Returns `-n^-1 mod 2^32` for an odd `n`.
+fn mont_inverse(n: u32) -> u32 {
+    // Each Newton step doubles the number of correct low bits, starting with
+    // 3 bits since `n * n == 1 mod 8` for any odd `n`.
+    let mut inv = n;
+    for _ in 0..4 {
+        inv = inv.wrapping_mul(2u32.wrapping_sub(n.wrapping_mul(inv)));
+    }
+    inv.wrapping_neg()
+}
+
+/// Sets `out` to the Montgomery product `a * b * 2^(-32 * n.len()) mod n`,
+/// for `a, b < n`.
+fn mont_mul(out: &mut [u32], a: &[u32], b: &[u32], n: &[u32], n_inv: u32) {
+    let len = n.len();
+    for limb in out.iter_mut() {
+        *limb = 0;
+    }
+    let mut top = 0u32;
+    for a in a.iter() {
+        // out += a * b
+        let mut carry = 0u64;
+        for (out, b) in out.iter_mut().zip(b.iter()) {
+            let sum = u64::from(*out) + u64::from(*a) * u64::from(*b) + carry;
+            *out = sum as u32;
+            carry = sum >> 32;
+        }
+        let sum = u64::from(top) + carry;
+        top = sum as u32;
+        let overflow = (sum >> 32) as u32;
+
+        // out = (out + m * n) / 2^32, with m chosen so that the division is
+        // exact.
+        let m = out[0].wrapping_mul(n_inv);
+        let mut carry = (u64::from(out[0]) + u64::from(m) * u64::from(n[0])) >> 32;
+        for i in 1..len {
+            let sum = u64::from(out[i]) + u64::from(m) * u64::from(n[i]) + carry;
+            out[i - 1] = sum as u32;
+            carry = sum >> 32;
+        }
+        let sum = u64::from(top) + carry;
+        out[len - 1] = sum as u32;
+        top = overflow + (sum >> 32) as u32;
+    }
+    reduce_once(out, top, n);
+}
+
+/// Sets `out` to `base ^ exponent mod n`.
+///
+/// `n` must be odd and `base < n`. `out`, `base` and `n` must have the same
+/// number of limbs, which must not exceed `MAX_LIMBS`, and `scratch` needs to
+/// hold at least `SCRATCH_LIMBS` limbs.
+pub fn mod_exp(out: &mut [u32], base: &[u32], exponent: u32, n: &[u32], scratch: &mut [u32]) {
+    let len = n.len();
+    let (r_squared, scratch) = scratch.split_at_mut(len);
+    let (base_mont, scratch) = scratch.split_at_mut(len);
+    let (acc, scratch) = scratch.split_at_mut(len);
+    let (tmp, scratch) = scratch.split_at_mut(len);
+    let one = &mut scratch[..len];
+    let n_inv = mont_inverse(n[0]);
+
+    // R^2 mod n, with R = 2^(32 * len), is the Montgomery form of R.
+    for limb in r_squared.iter_mut() {
+        *limb = 0;
+    }
+    r_squared[0] = 1;
+    for _ in 0..64 * len {
+        double_mod(r_squared, n);
+    }
+    for limb in one.iter_mut() {
+        *limb = 0;
+    }
+    one[0] = 1;
+
+    mont_mul(base_mont, base, r_squared, n, n_inv);
+    // Start with the Montgomery form of 1 and go through the exponent from
+    // its most significant bit. The exponent is public, so it is fine to
+    // branch on its bits.
+    mont_mul(acc, one, r_squared, n, n_inv);
+    for bit in (0..32 - exponent.leading_zeros()).rev() {
+        mont_mul(tmp, acc, acc, n, n_inv);
+        if exponent >> bit & 1 != 0 {
+            mont_mul(acc, tmp, base_mont, n, n_inv);
+        } else {
+            acc.copy_from_slice(tmp);
+        }
+    }
+    mont_mul(out, acc, one, n, n_inv);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::test_vectors;
+
+    #[test]
+    fn bytes() {
+        let mut limbs = [0u32; 2];
+        from_be_bytes(&[1, 2, 3, 4, 5, 6, 7, 8], &mut limbs);
+        assert_eq!(limbs, [0x05060708, 0x01020304]);
+        assert_eq!(be_byte(&limbs, 0), 1);
+        assert_eq!(be_byte(&limbs, 7), 8);
+    }
+
+    #[test]
+    fn inverse() {
+        for n in &[1u32, 3, 0x12345679, u32::MAX] {
+            assert_eq!(n.wrapping_mul(mont_inverse(*n)), u32::MAX);
+        }
+    }
+
+    #[test]
+    fn small_mod_exp() {
+        let n = [0xffff_fffb, 0x0000_0001];
+        let mut out = [0; 2];
+        let mut scratch = [0; 10];
+        mod_exp(&mut out, &[2, 0], 33, &n, &mut scratch);
+        // 2^33 mod 0x1fffffffb
+        assert_eq!(out, [5, 0]);
+        mod_exp(&mut out, &[7, 0], 0, &n, &mut scratch);
+        assert_eq!(out, [1, 0]);
+        mod_exp(&mut out, &[7, 0], 1, &n, &mut scratch);
+        assert_eq!(out, [7, 0]);
+    }
+
+    #[test]
+    fn large_mod_exp() {
+        let vector = test_vectors::RSA_2048;
+        let mut n = [0; 64];
+        let mut base = [0; 64];
+        let mut expected = [0; 64];
+        from_be_bytes(&test_vectors::decode(vector.modulus), &mut n);
+        from_be_bytes(&test_vectors::decode(test_vectors::MOD_EXP.base), &mut base);
+        from_be_bytes(&test_vectors::decode(test_vectors::MOD_EXP.result), &mut expected);
+
+        let mut out = [0; 64];
+        let mut scratch = [0; SCRATCH_LIMBS];
+        mod_exp(&mut out, &base, 3, &n, &mut scratch);
+        assert_eq!(out[..], expected[..]);
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+
+#![crate_type = "lib"]
+#![warn(missing_docs)]
+#![cfg_attr(not(test), no_std)]
+
+//! Software RSA signature verification
+//!
+//! This is a fallback for verifying RSASSA-PKCS1-v1_5 signatures with
+//! SHA-256 on devices without a usable RSA accelerator. Only the public key
+//! operation is implemented, so the implementation never handles secrets.
+//! It nevertheless runs in constant time with respect to the signature.
+
+pub mod bignum;
+
+#[cfg(test)]
+mod test_vectors;
+
+/// The supported modulus lengths, in bytes.
+pub const MODULUS_LENS: [usize; 3] = [2048 / 8, 3072 / 8, 4096 / 8];
+
+/// The maximum modulus length, in bytes.
+pub const MAX_MODULUS_LEN: usize = bignum::MAX_LIMBS * 4;
+
+/// Returns the number of `u32` words of scratch space needed by
+/// `PublicKey::verify_pkcs1_sha256` for a modulus of `modulus_len` bytes.
+pub const fn scratch_words(modulus_len: usize) -> usize {
+    // The modulus, signature and result, followed by the `mod_exp` scratch.
+    8 * (modulus_len / 4)
+}
+
+/// The number of `u32` words of scratch space needed by
+/// `PublicKey::verify_pkcs1_sha256` for any supported modulus.
+pub const SCRATCH_WORDS: usize = scratch_words(MAX_MODULUS_LEN);
+
+/// The length of a SHA-256 digest, in bytes.
+pub const SHA256_DIGEST_LEN: usize = 32;
+
+/// The ASN.1 DER encoded `DigestInfo` prefix for a SHA-256 digest, as defined
+/// in RFC 8017, section 9.2.
+const SHA256_DIGEST_INFO: [u8; 19] = [
+    0x30, 0x31, 0x30, 0x0d, 0x06, 0x09, 0x60, 0x86, 0x48, 0x01,
+    0x65, 0x03, 0x04, 0x02, 0x01, 0x05, 0x00, 0x04, 0x20,
+];
+
+/// An error while verifying a signature.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The modulus has an unsupported length or is not a valid modulus.
+    UnsupportedModulus,
+
+    /// The public exponent is not valid.
+    InvalidExponent,
+
+    /// The scratch space is too small.
+    BufferTooSmall,
+
+    /// The signature doesn't match the digest.
+    InvalidSignature,
+}
+
+/// An RSA public key.
+#[derive(Clone, Copy, Debug)]
+pub struct PublicKey<'a> {
+    modulus: &'a [u8],
+    exponent: u32,
+}
+
+impl<'a> PublicKey<'a> {
+    /// Creates a new `PublicKey` from the big-endian `modulus` and the public
+    /// `exponent`.
+    pub fn new(modulus: &'a [u8], exponent: u32) -> Result<Self, Error> {
+        if !MODULUS_LENS.contains(&modulus.len())
+            || modulus[0] == 0
+            || modulus[modulus.len() - 1] & 1 == 0 {
+            return Err(Error::UnsupportedModulus);
+        }
+        if exponent < 3 || exponent & 1 == 0 {
+            return Err(Error::InvalidExponent);
+        }
+        Ok(Self { modulus, exponent })
+    }
+
+    /// Returns the length of the modulus, in bytes.
+    pub fn modulus_len(&self) -> usize {
+        self.modulus.len()
+    }
+
+    /// Verifies the RSASSA-PKCS1-v1_5 `signature` of a message with the
+    /// SHA-256 `digest`.
+    ///
+    /// `scratch` needs to hold at least `scratch_words(self.modulus_len())`
+    /// words.
+    pub fn verify_pkcs1_sha256(&self, signature: &[u8], digest: &[u8; SHA256_DIGEST_LEN],
+        scratch: &mut [u32]) -> Result<(), Error> {
+        if signature.len() != self.modulus.len() {
+            return Err(Error::InvalidSignature);
+        }
+        if scratch.len() < scratch_words(self.modulus.len()) {
+            return Err(Error::BufferTooSmall);
+        }
+        let limbs = self.modulus.len() / 4;
+        let (n, scratch) = scratch.split_at_mut(limbs);
+        let (s, scratch) = scratch.split_at_mut(limbs);
+        let (em, scratch) = scratch.split_at_mut(limbs);
+        bignum::from_be_bytes(self.modulus, n);
+        bignum::from_be_bytes(signature, s);
+        if !bignum::less_than(s, n) {
+            return Err(Error::InvalidSignature);
+        }
+        bignum::mod_exp(em, s, self.exponent, n, scratch);
+
+        // EM = 0x00 || 0x01 || PS || 0x00 || DigestInfo || digest, where PS
+        // is filled with 0xff.
+        let len = self.modulus.len();
+        let info_start = len - SHA256_DIGEST_INFO.len() - SHA256_DIGEST_LEN;
+        let digest_start = len - SHA256_DIGEST_LEN;
+        let mut diff = 0u8;
+        for i in 0..len {
+            let expected = if i == 0 || i == info_start - 1 {
+                0x00
+            } else if i == 1 {
+                0x01
+            } else if i < info_start {
+                0xff
+            } else if i < digest_start {
+                SHA256_DIGEST_INFO[i - info_start]
+            } else {
+                digest[i - digest_start]
+            };
+            diff |= bignum::be_byte(em, i) ^ expected;
+        }
+        if diff != 0 {
+            return Err(Error::InvalidSignature);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use core::convert::TryFrom;
+
+    use test_vectors::decode;
+
+    fn verify(vector: &test_vectors::Vector, signature: &[u8]) -> Result<(), Error> {
+        let modulus = decode(vector.modulus);
+        let digest = <[u8; SHA256_DIGEST_LEN]>::try_from(&decode(vector.digest)[..]).unwrap();
+        let mut scratch = vec![0; scratch_words(modulus.len())];
+        PublicKey::new(&modulus, 65537)?.verify_pkcs1_sha256(signature, &digest, &mut scratch)
+    }
+
+    #[test]
+    fn known_vectors() {
+        for vector in &[test_vectors::RSA_2048, test_vectors::RSA_3072, test_vectors::RSA_4096] {
+            let signature = decode(vector.signature);
+            assert_eq!(verify(vector, &signature), Ok(()));
+
+            // Flipping any bit invalidates the signature.
+            for i in &[0, signature.len() / 2, signature.len() - 1] {
+                let mut corrupted = signature.clone();
+                corrupted[*i] ^= 0x10;
+                assert_eq!(verify(vector, &corrupted), Err(Error::InvalidSignature));
+            }
+            assert_eq!(verify(vector, &signature[1..]), Err(Error::InvalidSignature));
+        }
+    }
+
+    #[test]
+    fn wrong_digest() {
+        let vector = &test_vectors::RSA_2048;
+        let modulus = decode(vector.modulus);
+        let signature = decode(vector.signature);
+        let mut digest = <[u8; SHA256_DIGEST_LEN]>::try_from(&decode(vector.digest)[..]).unwrap();
+        digest[31] ^= 1;
+        let mut scratch = [0; scratch_words(256)];
+        let key = PublicKey::new(&modulus, 65537).unwrap();
+        assert_eq!(key.verify_pkcs1_sha256(&signature, &digest, &mut scratch),
+            Err(Error::InvalidSignature));
+        assert_eq!(key.verify_pkcs1_sha256(&signature, &digest, &mut scratch[1..]),
+            Err(Error::BufferTooSmall));
+
+        // The signature must be smaller than the modulus.
+        assert_eq!(key.verify_pkcs1_sha256(&modulus, &digest, &mut scratch),
+            Err(Error::InvalidSignature));
+    }
+
+    #[test]
+    fn invalid_keys() {
+        let modulus = decode(test_vectors::RSA_3072.modulus);
+        assert_eq!(PublicKey::new(&modulus, 65537).unwrap().modulus_len(), 384);
+        assert_eq!(PublicKey::new(&modulus[1..], 65537).err(), Some(Error::UnsupportedModulus));
+        assert_eq!(PublicKey::new(&modulus, 65536).err(), Some(Error::InvalidExponent));
+        assert_eq!(PublicKey::new(&modulus, 1).err(), Some(Error::InvalidExponent));
+
+        let mut even = modulus.clone();
+        even[383] &= !1;
+        assert_eq!(PublicKey::new(&even, 65537).err(), Some(Error::UnsupportedModulus));
+        let mut short = modulus;
+        short[0] = 0;
+        assert_eq!(PublicKey::new(&short, 65537).err(), Some(Error::UnsupportedModulus));
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+
+//! Known answer test vectors.
+//!
+//! The keys and signatures were generated with OpenSSL. Each signature is an
+//! RSASSA-PKCS1-v1_5 signature with SHA-256 and the public exponent 65537.
+
+/// A public key and a signature made with the corresponding private key.
+pub struct Vector {
+    /// The hex-encoded big-endian modulus.
+    pub modulus: &'static str,
+
+    /// The hex-encoded SHA-256 digest of the signed message.
+    pub digest: &'static str,
+
+    /// The hex-encoded signature.
+    pub signature: &'static str,
+}
+
+/// A modular exponentiation with the `RSA_2048` modulus and exponent 3.
+pub struct ModExp {
+    /// The hex-encoded big-endian base.
+    pub base: &'static str,
+
+    /// The hex-encoded big-endian result.
+    pub result: &'static str,
+}
+
+/// Decodes a hex string.
+pub fn decode(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+pub const MOD_EXP: ModExp = ModExp {
+    base: concat!(
+        "09c1c5edc3e50b7b83a58a1b10e515716bfb0efb2527ca3fab084417e49f7765",
+        "6dfdd2cbef23f605076008e5a2f3d5bed8e2f77bf726d3f785739b02ae9b3ae0",
+        "363f06ee0abb843a780c21d2f56b6d9b52c328d55ee51dc6a40f2c5b06e69247",
+        "c4a903559432220d2cb6443b7aaacaabffb6c7b38f6820b6dc0c9442b01d9465",
+        "a5a37f8f807495ccab89031571d212ac6a6cf2128046f0fcf988427a99d5e722",
+        "59bc174c82846546bedda2a2a367c811fd8ae54fbb87ae0198b3a3ca9edde6e8",
+        "bbb330e8d202fac4e778e039901f73e6b3caae0629bd3e99e112acd309bfa79c",
+        "8544606e383f1139d92c6f875a69cbfee5f38c1ff0c7b016d4ceb06f1492976b",
+    ),
+    result: concat!(
+        "44667047b3d85ecfa8603ef2628c68bc2dde1ca163b68261f3e6de690a0ac9a3",
+        "f8c5f02d11021dec2310b81bf94f494e0fc40e216336cd19375157a6b25ccc4c",
+        "1c78499e71153e027e87525dc569851f05b1603dc190ccf36e3950f13f64d6b0",
+        "4f27bf639d2e167408fc169f9a2e9cbe2322064d474ac0c5df4beb3f37862e5b",
+        "6f598bd09611d28074460a68330dd10dc893e8efc83b4b85482e9208f58d3adf",
+        "913a564bf7c1a6cb33774fd462141aa233b9a62f4c3e8c218ef4699a32f34ac7",
+        "76d82ff98efa9db16ea1bc2d50950d26ec3530c4351d6de04cc4949fc8fcfbf2",
+        "841acaab9588b6f46d7a9910b37b9ae4e027f2e4529b362fa86947566379f139",
+    ),
+};
+
+/// A 2048-bit key and a signature over "tock-on-titan RSA-2048 test vector".
+pub const RSA_2048: Vector = Vector {
+    modulus: concat!(
+        "c1249c296bef453f8931868c5822869453cac9e839d29b3742bf8c1f965e5d3d",
+        "5ce88f4b40b05ab6057707c1c613c646e6e2e16767d3917f68543534cc6299c2",
+        "94a75b292518cc8094caeed4739c2e6a6d02b00e001547b049b8a3dc7417425b",
+        "063d5ec19ba22eade020cc6bee5cd159c00f112fcf9244c011bb3bf4cae0403d",
+        "2542e287af5fbaee614e0d91f73589595558e6d0deb37479f43f8dbce127ed80",
+        "712a4acaad4feb744df96e04c59fd3f3c23af393a372b77555142c6cdc1fedba",
+        "0f33312e5dd155f6255e306dd8e8281f0bfb2add353d26dd0cb52364713e2d06",
+        "45a201a8f7953f8133aaa1200e9dd006d9d24cc36e32b56018f91fc8666b3d37",
+    ),
+    digest: "6ca4e9221e6e77bf8eebd5bc9b8952700bcf3e78ae29cf0176cdbeea476faf08",
+    signature: concat!(
+        "8d7ff95dfdb256a320fd1280901cd549784af27812c2e5f31102cb67b6ac731e",
+        "8e89a261af0bc864669d243b10140a2ce7c6184c68f2266b03919de05d978434",
+        "adaa03007d39cffdb33ac1ff883c69a50f74b0d4c294db3392585664b3f57f98",
+        "c6ceda4510aa5e5f7d257ec8f76a3175a53321a86276ee4cafe13c31abc284e0",
+        "a80f23d6f129c7054600c6e7da5d0850f7d84920964d60ef4e9a1ceb2cc6a1b5",
+        "50dca34d6146857cabe259d97231e299c239cdef57d88fcef6af7af69b284982",
+        "5bc65b3f46216feefb2e49260221042a8e3e74c4719fd07d7a2e231288447b66",
+        "e7bf238e7079b9e8f1031ffaba9d5f34e0c051691e876886f82019f719474655",
+    ),
+};
+
+/// A 3072-bit key and a signature over "tock-on-titan RSA-3072 test vector".
+pub const RSA_3072: Vector = Vector {
+    modulus: concat!(
+        "cd0c6943a642d5244ede81f673da4050984c3a24ad2889c69a8eba92e2c3543c",
+        "d691814697fc7fe6be8bc69124389aeec40cc08afc15186259fc3a9075afbb87",
+        "3b6baf4b90caa61c0ce729f553f57d796cc9e7c974228f0fe540b4656ca9da1e",
+        "1daa794dccc74a8d7412b8bfc2640c57f8699801a4d81c628cda32364b572888",
+        "ed591ce3d2ab8682a0a866b49faf338088d2f58801aadf58b3a97fcbfe360e62",
+        "4550b04667c009f39086fb374330218861790d18468de1c8c59d88861f8aedc5",
+        "c29333220608a7ff3ff532c24a565c59f865b1d16e35b418ff84f54f8b43fb66",
+        "65ea5ca0ff64fe1c54cb147552ebf9439326c59d96baaad4399052329674ce7d",
+        "bc77cdfaf96dba73825016f446cc425f38d6e92d8d997ca482f28d7fd184b458",
+        "fd0e375644aef25f7397d0b1c0e503081f5d56db73bceeaaa67493e83aa0a461",
+        "bf29b3d0e5f2cd600eb65ebd4bb68ea05902fa05f216110e7e1da0e84a86be29",
+        "3986641dd0d5173dae10855343a1b2e60e07405dc7948995d2ed04977f756683",
+    ),
+    digest: "eb931cb3df81bc35066c3056103704dd7d1e5e98df8ab5947d261d196c40ceb0",
+    signature: concat!(
+        "116a7c089ca45571b67e37f18242e6b45ff4b88847681b696a58a46257869a3f",
+        "79994c00676fd7f18a78a45a219714d2d7bb44db50268929a85377707d0a25ca",
+        "6f19dbc2bf2d884053ef5ab537049a368b4b01d154cd806a9af3622ef6135022",
+        "5c167021921de646be5109df6f0c04b2aa107bdacdb68761f0cddddeba2657fd",
+        "a698c7453df881e2241f67902cd366c1fd47d395cbe9b668466d86071c4277d5",
+        "bced5015f30c808c643959008f331342ed7d3b1caf52c7996e88e74ed088c538",
+        "c048bcde8e1a3e751e9e98a4e04f514a87ec540fdf495a05200a6113bcd2d9d0",
+        "9a58e830c9a1b1e9e44f9a795412449eac64e085bb2ea5d6f449a41bf0cebebf",
+        "674cb8a75ae03bfaa00351f0af72f8d5c61fcd8ab59be0ff411565f26f58697b",
+        "e00f8fb874c6dae85f4b1a702e80c4c9f0bf879e8314288e1b89ad6961dc72ff",
+        "11f3e20af17e3ef6aa30c820944f0aa1810416622f8878830ace94f41f5be1f8",
+        "db02c64a6b840b6acaf8e090dbbc2463df4eb020e78375c38c10c4ecd3de4789",
+    ),
+};
+
+/// A 4096-bit key and a signature over "tock-on-titan RSA-4096 test vector".
+pub const RSA_4096: Vector = Vector {
+    modulus: concat!(
+        "b6cd90b56ff050839a3c4a8d859fb476f136f0268d4d4b4556ea35df558462b8",
+        "00ef4f8d08cedd54a52c657998de9e2a29bef93f8bae1daff0d70789b0a0ca8f",
+        "eab3255169b517921c02b68dfa1a77da93d82d52d5cd3ba12b8bb2e1ff760b2d",
+        "64080fbb9a526a30fdedf2d7e2b16455fb92bfdcf535e11ed97b479fb24a59c6",
+        "a9a64ea3ad333140b594b9b10f5afc08416df17e46379d5901f9c8c2ac390fe0",
+        "5ef29e31c860b8a486ed3528dfea8bc89d985688cc47c78705f4a76b15417c67",
+        "90d4e1328c3c2d6bb9a5fa31f16b3016928723a5b2867b00eae027484670491d",
+        "0f36e83d6e3073d5e5603c5835d66297d33484c63ac6d15dcb1ee9b94430c7b5",
+        "76544debdeeac92c827e75ed82eb5cf8ef35776951774c0ff0c3f75d0afdf73a",
+        "e5875624101af838137492413c21d2e80a43d042b5ca9df2bbc5e5b38c0da43c",
+        "305230aa91060376af28cd8ed7ab26c149a6666435426dd93ab76bb47d91d4a9",
+        "267d18be32ce7618472fa2d9147c3e68fd565a8c0390821c61206dc250b8793b",
+        "288f90637345d23c929031b5de943d78e825442ff3e1b98fc306ba655128dffb",
+        "c96a1f181124aa9c7b675710cc9db9e4a73e806730af1a14cf01ae1628d77273",
+        "4e2b5f3000cfdb6c254e5c6dc808aa58fad65b39fc8573a6be83a258fde20032",
+        "52faba178e82527a1e0142ba6d5d8103db489438de0d270d9c9c4d252c92507d",
+    ),
+    digest: "dac66bd3463f264a1c93133da704f201d92d38d720a6d35817bfabeeadf0546d",
+    signature: concat!(
+        "344be63c55b3eb219898a5719bd0deba8072c9d5883a2262122199aa434a1095",
+        "d5d6a2489f69463497cb013c50171857d8bbe8f390bfcf5268cae25c7c1a9a30",
+        "c95349256bb881c471efafd61aadab485ead91a6fce7cd3458a195b2fce4688f",
+        "340b30ba02317e6bc90bb6e2e6df43048a088d6558141d821c9da3c4da9ed4e5",
+        "f832608f745be577290f2e149e268475e9ceef953a125855bc1a025f943747c4",
+        "93c79187da7c710bd856df8c8afa20a40995e23c47983e6263d2390c154e49f7",
+        "62f0f50f302244c0cf03a27b327fc3aa99ee13acb68472598b58955e02d5cef3",
+        "eac6dea101436be5929e4f9b65ed191155ff6c5ab8b5a68aed10725adaaf159c",
+        "18bcc9afd2006c843848ce3ecf009d922d228766fdcb65107864b0009dc6b9fe",
+        "2961ca83404ede97a1efdf299ebd774a7f8c28f6617088fb1df3be5efd51d9e8",
+        "a63c8721488424b891824f4afcd389bbbe0c18aca7606716cb8d949592adacc7",
+        "e1935a7b1327e7d2198f8179f2169a362a2816a8a1ffd1820e4facb70492040e",
+        "7c0d9cd3e1035763680aaf371a84df62d31029bc6ee7455231f4489aacd37390",
+        "ce9ab25c6ddd46fd332d502a69e409c59bc68617191cf7d793bc126d9bb7c075",
+        "b3bd8b3f93f826dc629849feb6370a8b1964f54e288b657a48d7b0c5c5c2c61a",
+        "c4d73da4129b594ff98da6187f69eafa50bd1f39c4331fe673f3e1d2f06a1947",
+    ),
+};
@@ -0,0 +1,510 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Derive macros for `spiutils::protocol::wire::FromWire` and
+//! `spiutils::protocol::wire::ToWire`.
+//!
+//! The macros support structs with named fields. Fields are read and written
+//! in declaration order. How a field is encoded depends on its type and its
+//! `#[wire(...)]` attribute:
+//!
+//! - Integers (`u8`, `u16`, `u32`, `u64`, `i8`, `i16`, `i32`, `i64` and
+//!   `u24`) are big-endian by default. Use `#[wire(le)]` for little-endian
+//!   or `#[wire(varint)]` for LEB128.
+//! - `[u8; N]` is copied verbatim.
+//! - `&'a [u8]` takes up the remaining input by default. Use
+//!   `#[wire(len_prefix = "u16")]` for a length prefix of the given integer
+//!   type, or `#[wire(len = "field")]` to take the length from a previous
+//!   field.
+//! - `#[wire(bits = N)]` packs consecutive fields into a byte using
+//!   `spiutils::io::BitBuf`, starting at the most significant bit. Each
+//!   group of consecutive bit fields must add up to 8 bits. Fields must be
+//!   `bool` (for `bits = 1`) or an unsigned integer.
+//! - Any other type is encoded using its own `FromWire`/`ToWire` impl.
+//!
+//! ```text
+//! #[derive(FromWire, ToWire)]
+//! struct Message<'a> {
+//!     #[wire(bits = 1)]
+//!     flag: bool,
+//!     #[wire(bits = 7)]
+//!     kind: u8,
+//!     #[wire(le)]
+//!     id: u32,
+//!     #[wire(len_prefix = "u16")]
+//!     data: &'a [u8],
+//! }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+
+use quote::quote;
+
+use syn::parse_macro_input;
+use syn::spanned::Spanned;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Error;
+use syn::Fields;
+use syn::GenericParam;
+use syn::Ident;
+use syn::Lifetime;
+use syn::Lit;
+use syn::Meta;
+use syn::NestedMeta;
+use syn::Type;
+
+/// Derives `spiutils::protocol::wire::FromWire`.
+#[proc_macro_derive(FromWire, attributes(wire))]
+pub fn derive_from_wire(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_wire(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Derives `spiutils::protocol::wire::ToWire`.
+#[proc_macro_derive(ToWire, attributes(wire))]
+pub fn derive_to_wire(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_to_wire(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Where the length of a slice field comes from.
+enum SliceLen {
+    /// The slice takes up the remaining input.
+    Remaining,
+
+    /// The slice is prefixed with its length of the given integer type.
+    Prefix(Type),
+
+    /// The length is stored in a previous field.
+    Field(Ident),
+}
+
+/// How a field is encoded.
+enum Encoding {
+    /// A big-endian or little-endian integer.
+    Int { le: bool },
+
+    /// A LEB128-encoded integer.
+    VarInt,
+
+    /// A fixed-size byte array.
+    Bytes,
+
+    /// A byte slice.
+    Slice(SliceLen),
+
+    /// A bit field of the given width.
+    Bits(u8),
+
+    /// A type implementing `FromWire`/`ToWire`.
+    Nested,
+}
+
+/// A field of the struct.
+struct Field {
+    ident: Ident,
+    ty: Type,
+    encoding: Encoding,
+}
+
+/// A group of fields that are encoded together.
+enum Group {
+    /// A single field.
+    Single(Box<Field>),
+
+    /// Fields that are packed into one byte.
+    Bits(Vec<Field>),
+}
+
+const INT_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "u24"];
+
+fn is_int(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.segments.last()
+            .map(|segment| INT_TYPES.iter().any(|name| segment.ident == name))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+fn is_bool(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path.path.is_ident("bool"),
+        _ => false,
+    }
+}
+
+fn is_byte_array(ty: &Type) -> bool {
+    match ty {
+        Type::Array(array) => match &*array.elem {
+            Type::Path(path) => path.path.is_ident("u8"),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_byte_slice(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_none() => match &*reference.elem {
+            Type::Slice(slice) => match &*slice.elem {
+                Type::Path(path) => path.path.is_ident("u8"),
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn lit_str(lit: &Lit) -> Result<String, Error> {
+    match lit {
+        Lit::Str(lit) => Ok(lit.value()),
+        _ => Err(Error::new(lit.span(), "expected a string literal")),
+    }
+}
+
+/// Parses the `#[wire(...)]` attributes and type of a field.
+fn parse_field(field: &syn::Field) -> Result<Field, Error> {
+    let ident = field.ident.clone()
+        .ok_or_else(|| Error::new(field.span(), "only named fields are supported"))?;
+    let ty = field.ty.clone();
+
+    let mut le = false;
+    let mut varint = false;
+    let mut bits = None;
+    let mut slice_len = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("wire")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new(meta.span(), "expected #[wire(...)]")),
+        };
+        for nested in list.nested.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("le") => le = true,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("be") => le = false,
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("varint") => varint = true,
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("bits") => {
+                    bits = Some(match &nv.lit {
+                        Lit::Int(lit) => lit.base10_parse::<u8>()?,
+                        lit => return Err(Error::new(lit.span(), "expected an integer")),
+                    });
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("len_prefix") => {
+                    slice_len = Some(SliceLen::Prefix(syn::parse_str(&lit_str(&nv.lit)?)?));
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("len") => {
+                    slice_len = Some(SliceLen::Field(Ident::new(&lit_str(&nv.lit)?, nv.lit.span())));
+                }
+                nested => return Err(Error::new(nested.span(), "unknown wire attribute")),
+            }
+        }
+    }
+
+    let encoding = if let Some(bits) = bits {
+        if bits == 0 || bits > 8 || (is_bool(&ty) && bits != 1) {
+            return Err(Error::new(ty.span(), "invalid bit field width"));
+        }
+        if !is_bool(&ty) && !is_int(&ty) {
+            return Err(Error::new(ty.span(), "bit fields must be bool or integers"));
+        }
+        Encoding::Bits(bits)
+    } else if is_byte_slice(&ty) {
+        Encoding::Slice(slice_len.unwrap_or(SliceLen::Remaining))
+    } else if slice_len.is_some() {
+        return Err(Error::new(ty.span(), "length attributes are only supported for &[u8]"));
+    } else if is_int(&ty) && varint {
+        Encoding::VarInt
+    } else if is_int(&ty) {
+        Encoding::Int { le }
+    } else if is_byte_array(&ty) {
+        Encoding::Bytes
+    } else {
+        Encoding::Nested
+    };
+
+    Ok(Field { ident, ty, encoding })
+}
+
+/// Parses the fields of `input` into groups.
+fn parse_groups(input: &DeriveInput) -> Result<Vec<Group>, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unit => return Ok(Vec::new()),
+            _ => return Err(Error::new(input.span(), "only named fields are supported")),
+        },
+        _ => return Err(Error::new(input.span(), "only structs are supported")),
+    };
+
+    let mut groups = Vec::new();
+    let mut bit_fields: Vec<Field> = Vec::new();
+    let mut bit_count = 0;
+    for field in fields.iter() {
+        let field = parse_field(field)?;
+        if let Encoding::Bits(bits) = field.encoding {
+            bit_count += bits as usize;
+            bit_fields.push(field);
+            if bit_count > 8 {
+                return Err(Error::new(input.span(), "bit fields must add up to 8 bits"));
+            }
+            if bit_count == 8 {
+                groups.push(Group::Bits(bit_fields.split_off(0)));
+                bit_count = 0;
+            }
+        } else {
+            if bit_count != 0 {
+                return Err(Error::new(field.ident.span(), "bit fields must add up to 8 bits"));
+            }
+            groups.push(Group::Single(Box::new(field)));
+        }
+    }
+    if bit_count != 0 {
+        return Err(Error::new(input.span(), "bit fields must add up to 8 bits"));
+    }
+    Ok(groups)
+}
+
+/// Returns the lifetime to use for `'wire`.
+///
+/// This is the struct's lifetime parameter, if any.
+fn wire_lifetime(input: &DeriveInput) -> Result<(Lifetime, bool), Error> {
+    let mut lifetimes = input.generics.params.iter().filter_map(|param| match param {
+        GenericParam::Lifetime(lifetime) => Some(lifetime.lifetime.clone()),
+        _ => None,
+    });
+    if input.generics.type_params().next().is_some() || input.generics.const_params().next().is_some() {
+        return Err(Error::new(input.generics.span(), "only lifetime parameters are supported"));
+    }
+    match (lifetimes.next(), lifetimes.next()) {
+        (None, _) => Ok((Lifetime::new("'wire", Span::call_site()), false)),
+        (Some(lifetime), None) => Ok((lifetime, true)),
+        (Some(_), Some(lifetime)) =>
+            Err(Error::new(lifetime.span(), "at most one lifetime parameter is supported")),
+    }
+}
+
+fn expand_from_wire(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let groups = parse_groups(input)?;
+    let (wire, has_lifetime) = wire_lifetime(input)?;
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    let mut reads = Vec::new();
+    let mut idents = Vec::new();
+    let mut has_nested = false;
+    for group in groups.iter() {
+        match group {
+            Group::Single(field) => {
+                let ident = &field.ident;
+                let ty = &field.ty;
+                let read = match &field.encoding {
+                    Encoding::Int { le: false } => quote! {
+                        <#ty as ::spiutils::io::BeInt>::read_from(&mut r)?
+                    },
+                    Encoding::Int { le: true } => quote! {
+                        <#ty as ::spiutils::io::LeInt>::read_from(&mut r)?
+                    },
+                    Encoding::VarInt => quote! {
+                        <#ty as ::spiutils::io::VarInt>::read_from(&mut r)?
+                    },
+                    Encoding::Bytes => quote! {
+                        {
+                            let mut array: #ty = [0u8; ::core::mem::size_of::<#ty>()];
+                            array.copy_from_slice(::spiutils::io::Read::read_bytes(
+                                &mut r, ::core::mem::size_of::<#ty>())?);
+                            array
+                        }
+                    },
+                    Encoding::Slice(SliceLen::Remaining) => quote! {
+                        {
+                            let len = ::spiutils::io::Read::remaining_data(&r);
+                            ::spiutils::io::Read::read_bytes(&mut r, len)?
+                        }
+                    },
+                    Encoding::Slice(SliceLen::Prefix(len_ty)) => quote! {
+                        {
+                            let len = <#len_ty as ::spiutils::io::BeInt>::read_from(&mut r)?;
+                            ::spiutils::io::Read::read_bytes(&mut r, len as usize)?
+                        }
+                    },
+                    Encoding::Slice(SliceLen::Field(len_field)) => quote! {
+                        ::spiutils::io::Read::read_bytes(&mut r, #len_field as usize)?
+                    },
+                    Encoding::Nested => {
+                        has_nested = true;
+                        quote! {
+                            {
+                                let offset = __wire_start - ::spiutils::io::Read::remaining_data(&r);
+                                <#ty as ::spiutils::protocol::wire::FromWire<#wire>>::from_wire(&mut r)
+                                    .map_err(|e| e.shift(offset))?
+                            }
+                        }
+                    }
+                    Encoding::Bits(_) => unreachable!(),
+                };
+                reads.push(quote! { let #ident = #read; });
+                idents.push(ident.clone());
+            }
+            Group::Bits(fields) => {
+                let mut bit_reads = Vec::new();
+                for field in fields.iter() {
+                    let ident = &field.ident;
+                    let ty = &field.ty;
+                    let bits = match field.encoding {
+                        Encoding::Bits(bits) => bits as usize,
+                        _ => unreachable!(),
+                    };
+                    let read = if is_bool(ty) {
+                        quote! { bits.read_bit()? }
+                    } else {
+                        quote! { <#ty as ::core::convert::From<u8>>::from(bits.read_bits(#bits)?) }
+                    };
+                    bit_reads.push(quote! { let #ident = #read; });
+                    idents.push(ident.clone());
+                }
+                reads.push(quote! {
+                    let mut bits = ::spiutils::io::BitBuf::from_bits(
+                        <u8 as ::spiutils::io::BeInt>::read_from(&mut r)?);
+                    #(#bit_reads)*
+                });
+            }
+        }
+    }
+
+    // Errors from nested values are made relative to the start of `Self`.
+    let start = if has_nested {
+        quote! { let __wire_start = ::spiutils::io::Read::remaining_data(&r); }
+    } else {
+        quote! {}
+    };
+
+    let impl_generics = if has_lifetime {
+        quote! { <#wire> }
+    } else {
+        quote! { <'wire> }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::spiutils::protocol::wire::FromWire<#wire> for #name #ty_generics {
+            fn from_wire<R: ::spiutils::io::Read<#wire>>(mut r: R)
+                -> ::core::result::Result<Self, ::spiutils::protocol::wire::FromWireError> {
+                #start
+                #(#reads)*
+                Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+fn expand_to_wire(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let groups = parse_groups(input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut writes = Vec::new();
+    for group in groups.iter() {
+        match group {
+            Group::Single(field) => {
+                let ident = &field.ident;
+                let field_name = ident.to_string();
+                writes.push(match &field.encoding {
+                    Encoding::Int { le: false } => quote! {
+                        ::spiutils::io::BeInt::write_to(self.#ident, &mut w)?;
+                    },
+                    Encoding::Int { le: true } => quote! {
+                        ::spiutils::io::LeInt::write_to(self.#ident, &mut w)?;
+                    },
+                    Encoding::VarInt => quote! {
+                        ::spiutils::io::VarInt::write_to(self.#ident, &mut w)?;
+                    },
+                    Encoding::Bytes => quote! {
+                        ::spiutils::io::Write::write_bytes(&mut w, &self.#ident)?;
+                    },
+                    Encoding::Slice(SliceLen::Remaining) => quote! {
+                        ::spiutils::io::Write::write_bytes(&mut w, self.#ident)?;
+                    },
+                    Encoding::Slice(SliceLen::Prefix(len_ty)) => quote! {
+                        let len = <#len_ty as ::core::convert::TryFrom<usize>>::try_from(self.#ident.len())
+                            .map_err(|_| ::spiutils::protocol::wire::ToWireError::InvalidData(
+                                ::spiutils::protocol::wire::FieldError::new(#field_name)
+                                    .with_value(self.#ident.len() as u64)))?;
+                        ::spiutils::io::BeInt::write_to(len, &mut w)?;
+                        ::spiutils::io::Write::write_bytes(&mut w, self.#ident)?;
+                    },
+                    Encoding::Slice(SliceLen::Field(len_field)) => quote! {
+                        if self.#ident.len() != self.#len_field as usize {
+                            return Err(::spiutils::protocol::wire::ToWireError::InvalidData(
+                                ::spiutils::protocol::wire::FieldError::new(#field_name)
+                                    .with_value(self.#ident.len() as u64)));
+                        }
+                        ::spiutils::io::Write::write_bytes(&mut w, self.#ident)?;
+                    },
+                    Encoding::Nested => quote! {
+                        ::spiutils::protocol::wire::ToWire::to_wire(&self.#ident, &mut w)?;
+                    },
+                    Encoding::Bits(_) => unreachable!(),
+                });
+            }
+            Group::Bits(fields) => {
+                let mut bit_writes = Vec::new();
+                for field in fields.iter() {
+                    let ident = &field.ident;
+                    let field_name = ident.to_string();
+                    let bits = match field.encoding {
+                        Encoding::Bits(bits) => bits as usize,
+                        _ => unreachable!(),
+                    };
+                    bit_writes.push(if is_bool(&field.ty) {
+                        quote! { bits.write_bit(self.#ident)?; }
+                    } else {
+                        quote! {
+                            if (self.#ident as u64) >> #bits != 0 {
+                                return Err(::spiutils::protocol::wire::ToWireError::InvalidData(
+                                    ::spiutils::protocol::wire::FieldError::new(#field_name)
+                                        .with_value(self.#ident as u64)));
+                            }
+                            bits.write_bits(#bits, self.#ident as u8)?;
+                        }
+                    });
+                }
+                writes.push(quote! {
+                    let mut bits = ::spiutils::io::BitBuf::new();
+                    #(#bit_writes)*
+                    ::spiutils::io::BeInt::write_to(bits.bits(), &mut w)?;
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::spiutils::protocol::wire::ToWire for #name #ty_generics #where_clause {
+            fn to_wire<W: ::spiutils::io::Write>(&self, mut w: W)
+                -> ::core::result::Result<(), ::spiutils::protocol::wire::ToWireError> {
+                #(#writes)*
+                Ok(())
+            }
+        }
+    })
+}
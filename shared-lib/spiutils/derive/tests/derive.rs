@@ -0,0 +1,150 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use spiutils::io::Cursor;
+use spiutils::protocol::payload;
+use spiutils::protocol::wire::FieldError;
+use spiutils::protocol::wire::FromWire;
+use spiutils::protocol::wire::FromWireError;
+use spiutils::protocol::wire::ToWire;
+use spiutils::protocol::wire::ToWireError;
+
+#[derive(Debug, PartialEq, Eq, FromWire, ToWire)]
+struct Inner {
+    value: u16,
+}
+
+#[derive(Debug, PartialEq, Eq, FromWire, ToWire)]
+struct Message<'a> {
+    #[wire(bits = 1)]
+    flag: bool,
+    #[wire(bits = 3)]
+    kind: u8,
+    #[wire(bits = 4)]
+    count: u8,
+    id: u32,
+    #[wire(le)]
+    le_id: u32,
+    tag: [u8; 2],
+    #[wire(varint)]
+    var: u32,
+    inner: Inner,
+    #[wire(len_prefix = "u16")]
+    prefixed: &'a [u8],
+    len: u8,
+    #[wire(len = "len")]
+    sized: &'a [u8],
+    rest: &'a [u8],
+}
+
+const MESSAGE: &[u8] = &[
+    0b1011_0110,
+    0x01, 0x02, 0x03, 0x04,
+    0x04, 0x03, 0x02, 0x01,
+    0xaa, 0xbb,
+    0xe5, 0x8e, 0x26,
+    0x12, 0x34,
+    0x00, 0x02, 0x55, 0x66,
+    0x01, 0x77,
+    0x88, 0x99,
+];
+
+fn message() -> Message<'static> {
+    Message {
+        flag: true,
+        kind: 0b011,
+        count: 0b0110,
+        id: 0x01020304,
+        le_id: 0x01020304,
+        tag: [0xaa, 0xbb],
+        var: 624485,
+        inner: Inner { value: 0x1234 },
+        prefixed: &[0x55, 0x66],
+        len: 1,
+        sized: &[0x77],
+        rest: &[0x88, 0x99],
+    }
+}
+
+#[test]
+fn from_wire() {
+    let mut data = MESSAGE;
+    assert_eq!(Message::from_wire(&mut data).expect("from_wire failed"), message());
+    assert!(data.is_empty());
+}
+
+#[test]
+fn to_wire() {
+    let mut buf = [0u8; 64];
+    let mut cursor = Cursor::new(&mut buf);
+    message().to_wire(&mut cursor).expect("to_wire failed");
+    assert_eq!(cursor.consumed_bytes(), MESSAGE);
+}
+
+#[test]
+fn from_wire_short() {
+    let mut data = &MESSAGE[..10];
+    assert!(Message::from_wire(&mut data).is_err());
+}
+
+#[test]
+fn to_wire_invalid() {
+    let mut buf = [0u8; 64];
+
+    let mut msg = message();
+    msg.kind = 0b1000;
+    assert_eq!(msg.to_wire(Cursor::new(&mut buf)),
+        Err(ToWireError::InvalidData(FieldError::new("kind").with_value(0b1000u8))));
+
+    let mut msg = message();
+    msg.len = 2;
+    assert_eq!(msg.to_wire(Cursor::new(&mut buf)),
+        Err(ToWireError::InvalidData(FieldError::new("sized").with_value(1u8))));
+}
+
+#[derive(Debug, PartialEq, Eq, FromWire, ToWire)]
+struct Envelope {
+    id: u16,
+    header: payload::Header,
+}
+
+#[test]
+fn round_trip_nested() {
+    let envelope = Envelope {
+        id: 0x1234,
+        header: payload::Header {
+            content: payload::ContentType::Manticore,
+            content_len: 0x0203,
+        },
+    };
+    let mut buf = [0u8; 5];
+    envelope.to_wire(Cursor::new(&mut buf)).unwrap();
+    assert_eq!(buf, [0x12, 0x34, 0x01, 0x02, 0x03]);
+
+    let mut data: &[u8] = &buf;
+    let decoded = Envelope::from_wire(&mut data).unwrap();
+    assert_eq!(decoded.id, 0x1234);
+    assert_eq!(decoded.header, envelope.header);
+}
+
+#[test]
+fn from_wire_nested_offset() {
+    let mut data: &[u8] = &[0x00, 0x01, 0x9e, 0x00, 0x00];
+    let err = Envelope::from_wire(&mut data).unwrap_err();
+    assert_eq!(err, FromWireError::UnknownValue(
+        FieldError::new("ContentType").with_value(0x9eu8).at(2)));
+    assert_eq!(format!("{}", err), "unknown ContentType 0x9e at offset 2");
+}
@@ -0,0 +1,47 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parses arbitrary bytes as a SPI flash header and checks that everything
+//! but the dummy bytes survives a round trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use spiutils::io::Cursor;
+use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::Header;
+
+fuzz_target!(|input: &[u8]| {
+    for address_mode in &[AddressMode::ThreeByte, AddressMode::FourByte] {
+        let mut data = input;
+        let header = match Header::from_wire(&mut data, *address_mode) {
+            Ok(header) => header,
+            Err(_) => continue,
+        };
+        let consumed = input.len() - data.len();
+
+        let mut buf = [0u8; 16];
+        let mut cursor = Cursor::new(&mut buf);
+        header.to_wire(&mut cursor).expect("to_wire failed");
+        assert_eq!(cursor.consumed_len(), consumed);
+        let len = 1 + header.address_len();
+        assert_eq!(&cursor.consumed_bytes()[..len], &input[..len]);
+
+        let mut data = cursor.consumed_bytes();
+        assert_eq!(Header::from_wire(&mut data, *address_mode), Ok(header));
+    }
+});
@@ -0,0 +1,42 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Parses arbitrary bytes as a JEDEC ID and checks the round trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+use spiutils::io::Cursor;
+use spiutils::protocol::flash::JedecId;
+use spiutils::protocol::flash::MAX_JEDEC_ID_LEN;
+use spiutils::protocol::wire::FromWire;
+use spiutils::protocol::wire::ToWire;
+
+fuzz_target!(|input: &[u8]| {
+    let mut data = input;
+    let id = match JedecId::from_wire(&mut data) {
+        Ok(id) => id,
+        Err(_) => return,
+    };
+    let consumed = input.len() - data.len();
+    assert_eq!(id.wire_len(), consumed);
+
+    let mut buf = [0u8; MAX_JEDEC_ID_LEN];
+    let mut cursor = Cursor::new(&mut buf);
+    id.to_wire(&mut cursor).expect("to_wire failed");
+    assert_eq!(cursor.consumed_bytes(), &input[..consumed]);
+});
@@ -0,0 +1,36 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Converts arbitrary `u32`s into 24-bit addresses.
+
+#![no_main]
+
+use core::convert::TryInto;
+
+use libfuzzer_sys::fuzz_target;
+
+use spiutils::protocol::flash::Address;
+
+fuzz_target!(|input: &[u8]| {
+    let val = match input.try_into() {
+        Ok(bytes) => u32::from_be_bytes(bytes),
+        Err(_) => return,
+    };
+    match <ux::u24 as Address>::try_from(val) {
+        Ok(address) => assert_eq!(u32::from(address), val),
+        Err(_) => assert!(val > 0x00ff_ffff),
+    }
+});
@@ -0,0 +1,474 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-memory SPI NOR flash emulator.
+//!
+//! This module models a SPI flash chip as seen from the SPI bus: each
+//! transaction consists of the bytes sent by the SPI host (op code, address,
+//! dummy bytes and data) and the number of bytes the host clocks in
+//! afterwards. Transactions are decoded with [`flash::Header`].
+//!
+//! Program and erase operations complete immediately. To exercise polling
+//! loops, the emulator can optionally report the BUSY bit for a number of
+//! status register reads after each operation.
+//!
+//! Program and erase operations on regions protected by the block protect
+//! bits of the status register are ignored, like on a real flash chip.
+//!
+//! [`flash::Header`]: ../protocol/flash/struct.Header.html
+
+use crate::protocol::flash;
+use crate::protocol::flash::AddressMode;
+use crate::protocol::flash::OpCode;
+use crate::protocol::flash::StatusRegister;
+use crate::protocol::wire::FromWireError;
+
+/// Status register bit indicating that a write operation is in progress.
+pub const STATUS_BUSY: u8 = 0x01;
+
+/// Status register bit indicating that writes are enabled.
+pub const STATUS_WEL: u8 = 0x02;
+
+/// The size of a page for PageProgram.
+pub const PAGE_SIZE: usize = 256;
+
+/// The size of a sector for SectorErase.
+pub const SECTOR_SIZE: usize = 4 * 1024;
+
+/// The size of a block for BlockErase32KB.
+pub const BLOCK_32K_SIZE: usize = 32 * 1024;
+
+/// The size of a block for BlockErase64KB.
+pub const BLOCK_64K_SIZE: usize = 64 * 1024;
+
+/// The value of erased flash memory.
+const ERASED_VALUE: u8 = 0xff;
+
+/// An error while processing a transaction.
+#[derive(Clone, Copy, Debug)]
+pub enum Error {
+    /// The transaction could not be decoded.
+    FromWire(FromWireError),
+
+    /// The op code is not supported by the emulator.
+    UnsupportedOpCode(OpCode),
+}
+
+impl From<FromWireError> for Error {
+    fn from(err: FromWireError) -> Self {
+        Error::FromWire(err)
+    }
+}
+
+/// An emulated SPI NOR flash chip.
+pub struct FlashEmulator {
+    /// The contents of the flash.
+    memory: Vec<u8>,
+
+    /// The status register, including the WEL bit.
+    ///
+    /// BUSY is reported based on `remaining_busy_polls` instead.
+    status: StatusRegister,
+
+    /// Whether the WP# pin is asserted.
+    write_protect: bool,
+
+    /// The current address mode.
+    address_mode: AddressMode,
+
+    /// The data returned for OpCode::ReadJedec.
+    jedec_id: Vec<u8>,
+
+    /// The data returned for OpCode::ReadSfdp.
+    sfdp: Vec<u8>,
+
+    /// The number of status register reads that report BUSY after a program
+    /// or erase operation.
+    busy_polls: usize,
+
+    /// The number of remaining status register reads that report BUSY.
+    remaining_busy_polls: usize,
+}
+
+impl FlashEmulator {
+    /// Creates a new erased flash of `size` bytes.
+    ///
+    /// `size` must be a power of two. Addresses beyond `size` wrap around.
+    pub fn new(size: usize) -> Self {
+        assert!(size.is_power_of_two(), "flash size must be a power of two");
+        Self {
+            memory: vec![ERASED_VALUE; size],
+            status: StatusRegister::default(),
+            write_protect: false,
+            address_mode: AddressMode::ThreeByte,
+            jedec_id: Vec::new(),
+            sfdp: Vec::new(),
+            busy_polls: 0,
+            remaining_busy_polls: 0,
+        }
+    }
+
+    /// Sets the data returned for OpCode::ReadJedec.
+    pub fn set_jedec_id(&mut self, data: &[u8]) {
+        self.jedec_id = data.to_vec();
+    }
+
+    /// Sets the data returned for OpCode::ReadSfdp.
+    pub fn set_sfdp(&mut self, data: &[u8]) {
+        self.sfdp = data.to_vec();
+    }
+
+    /// Sets the number of status register reads that report BUSY after each
+    /// program or erase operation.
+    pub fn set_busy_polls(&mut self, busy_polls: usize) {
+        self.busy_polls = busy_polls;
+    }
+
+    /// Sets the address mode.
+    pub fn set_address_mode(&mut self, address_mode: AddressMode) {
+        self.address_mode = address_mode;
+    }
+
+    /// Returns the current address mode.
+    pub fn address_mode(&self) -> AddressMode {
+        self.address_mode
+    }
+
+    /// Sets whether the WP# pin is asserted.
+    pub fn set_write_protect(&mut self, write_protect: bool) {
+        self.write_protect = write_protect;
+    }
+
+    /// Returns the current contents of the status register.
+    pub fn status_register(&self) -> StatusRegister {
+        StatusRegister {
+            write_in_progress: self.remaining_busy_polls > 0,
+            ..self.status
+        }
+    }
+
+    /// Returns the current contents of status register 1.
+    pub fn status(&self) -> u8 {
+        self.status_register().register_1()
+    }
+
+    /// Returns true iff the WEL bit is set.
+    pub fn is_write_enable_set(&self) -> bool {
+        self.status.write_enable_latch
+    }
+
+    /// Returns the contents of the flash.
+    pub fn memory(&self) -> &[u8] {
+        &self.memory
+    }
+
+    /// Returns the contents of the flash for modification.
+    pub fn memory_mut(&mut self) -> &mut [u8] {
+        &mut self.memory
+    }
+
+    /// Executes a single SPI transaction.
+    ///
+    /// `tx` contains all bytes sent by the SPI host, i.e. the op code,
+    /// address, dummy byte and data. `rx_len` is the number of bytes the SPI
+    /// host clocks in after `tx` has been sent.
+    ///
+    /// Returns the `rx_len` bytes sent by the flash.
+    pub fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, Error> {
+        let mut data = tx;
+        let header = flash::Header::from_wire(&mut data, self.address_mode)?;
+        let opcode = header.opcode;
+        let address = header.get_address().unwrap_or(0) as usize;
+
+        let mut rx = vec![ERASED_VALUE; rx_len];
+        match opcode {
+            OpCode::Nop | OpCode::WriteSuspend | OpCode::WriteResume => {}
+            OpCode::WriteEnable => self.status.write_enable_latch = true,
+            OpCode::WriteDisable => self.status.write_enable_latch = false,
+            OpCode::ReadStatusRegister => {
+                for val in rx.iter_mut() {
+                    *val = self.status();
+                }
+                self.remaining_busy_polls = self.remaining_busy_polls.saturating_sub(1);
+            }
+            OpCode::WriteStatusRegister => {
+                if let Some(status) = self.status.written(data) {
+                    if self.start_write() && !self.status.is_locked(self.write_protect) {
+                        self.status = StatusRegister {
+                            write_enable_latch: false,
+                            ..status
+                        };
+                    }
+                }
+            }
+            OpCode::Enter4ByteAddressMode => self.address_mode = AddressMode::FourByte,
+            OpCode::Exit4ByteAddressMode => self.address_mode = AddressMode::ThreeByte,
+            OpCode::ReadJedec => copy_from(&self.jedec_id, 0, &mut rx),
+            OpCode::ReadSfdp => copy_from(&self.sfdp, address, &mut rx),
+            OpCode::NormalRead | OpCode::NormalRead4B | OpCode::FastRead | OpCode::FastRead4B
+                | OpCode::FastReadDualOutput | OpCode::FastReadDualOutput4B
+                | OpCode::FastReadDualIo | OpCode::FastReadDualIo4B
+                | OpCode::FastReadQuadOutput | OpCode::FastReadQuadOutput4B
+                | OpCode::FastReadQuadIo | OpCode::FastReadQuadIo4B => {
+                // The emulator operates on bytes, so the number of data lines
+                // doesn't matter.
+                for (idx, val) in rx.iter_mut().enumerate() {
+                    *val = self.memory[self.wrap(address + idx)];
+                }
+            }
+            OpCode::PageProgram | OpCode::PageProgram4B | OpCode::QuadPageProgram
+                | OpCode::QuadPageProgram4B | OpCode::QuadIoPageProgram
+                | OpCode::QuadIoPageProgram4B => {
+                let page_base = address & !(PAGE_SIZE - 1);
+                // Protected regions are multiples of the page size, so a page
+                // is either fully protected or not at all.
+                if self.start_write() && !self.is_protected(page_base, PAGE_SIZE) {
+                    for (idx, val) in data.iter().enumerate() {
+                        // Data beyond the end of the page wraps around to
+                        // the start of the page.
+                        let offset = (address + idx) % PAGE_SIZE;
+                        let target = self.wrap(page_base + offset);
+                        self.memory[target] &= *val;
+                    }
+                }
+            }
+            OpCode::SectorErase | OpCode::SectorErase4B => self.erase(address, SECTOR_SIZE),
+            OpCode::BlockErase32KB | OpCode::BlockErase32KB4B => self.erase(address, BLOCK_32K_SIZE),
+            OpCode::BlockErase64KB | OpCode::BlockErase64KB4B => self.erase(address, BLOCK_64K_SIZE),
+            OpCode::ChipErase | OpCode::ChipErase2 => self.erase(0, self.memory.len()),
+        }
+
+        Ok(rx)
+    }
+
+    /// Wraps `address` to the size of the flash.
+    fn wrap(&self, address: usize) -> usize {
+        address & (self.memory.len() - 1)
+    }
+
+    /// Returns whether any of the `len` bytes starting at `address` are
+    /// protected by the block protect bits.
+    fn is_protected(&self, address: usize, len: usize) -> bool {
+        self.status.is_protected(self.memory.len() as u32, self.wrap(address) as u32, len as u32)
+    }
+
+    /// Checks whether a write operation may start and updates the status
+    /// register accordingly.
+    ///
+    /// Returns true iff the WEL bit was set.
+    fn start_write(&mut self) -> bool {
+        if !self.is_write_enable_set() {
+            return false;
+        }
+        self.status.write_enable_latch = false;
+        self.remaining_busy_polls = self.busy_polls;
+        true
+    }
+
+    /// Erases the `size` bytes long region containing `address`.
+    ///
+    /// Nothing is erased if any part of the region is protected.
+    fn erase(&mut self, address: usize, size: usize) {
+        let size = size.min(self.memory.len());
+        let base = self.wrap(address) & !(size - 1);
+        if !self.start_write() || self.is_protected(base, size) {
+            return;
+        }
+        for val in &mut self.memory[base..base + size] {
+            *val = ERASED_VALUE;
+        }
+    }
+}
+
+/// Copies bytes from `src` starting at `offset` into `dest`.
+///
+/// Bytes beyond the end of `src` are left untouched.
+fn copy_from(src: &[u8], offset: usize, dest: &mut [u8]) {
+    let src = src.get(offset..).unwrap_or(&[]);
+    for (dest, src) in dest.iter_mut().zip(src) {
+        *dest = *src;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const FLASH_SIZE: usize = 1024 * 1024;
+
+    #[test]
+    fn read_erased() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        let rx = flash.transaction(&[0x03, 0x00, 0x10, 0x00], 4).unwrap();
+        assert_eq!(rx, [0xff; 4]);
+    }
+
+    #[test]
+    fn page_program_requires_write_enable() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.transaction(&[0x02, 0x00, 0x00, 0x00, 0x12, 0x34], 0).unwrap();
+        assert_eq!(&flash.memory()[..2], &[0xff, 0xff]);
+
+        flash.transaction(&[0x06], 0).unwrap();
+        assert_eq!(flash.status(), STATUS_WEL);
+        flash.transaction(&[0x02, 0x00, 0x00, 0x00, 0x12, 0x34], 0).unwrap();
+        assert_eq!(&flash.memory()[..2], &[0x12, 0x34]);
+        assert_eq!(flash.status(), 0);
+    }
+
+    #[test]
+    fn page_program_wraps_within_page() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x02, 0x00, 0x01, 0xff, 0xaa, 0xbb], 0).unwrap();
+        assert_eq!(flash.memory()[0x1ff], 0xaa);
+        assert_eq!(flash.memory()[0x100], 0xbb);
+        assert_eq!(flash.memory()[0x200], 0xff);
+    }
+
+    #[test]
+    fn page_program_only_clears_bits() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x02, 0x00, 0x00, 0x00, 0xf0], 0).unwrap();
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x02, 0x00, 0x00, 0x00, 0x3c], 0).unwrap();
+        assert_eq!(flash.memory()[0], 0x30);
+    }
+
+    #[test]
+    fn erase() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        for val in flash.memory_mut().iter_mut() {
+            *val = 0;
+        }
+
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x20, 0x00, 0x12, 0x34], 0).unwrap();
+        assert_eq!(flash.memory()[0x0fff], 0x00);
+        assert!(flash.memory()[0x1000..0x2000].iter().all(|&val| val == 0xff));
+        assert_eq!(flash.memory()[0x2000], 0x00);
+
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0xd8, 0x01, 0x23, 0x45], 0).unwrap();
+        assert!(flash.memory()[0x10000..0x20000].iter().all(|&val| val == 0xff));
+        assert_eq!(flash.memory()[0x20000], 0x00);
+
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0xc7], 0).unwrap();
+        assert!(flash.memory().iter().all(|&val| val == 0xff));
+    }
+
+    #[test]
+    fn address_mode() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.memory_mut()[0x12345] = 0x42;
+
+        flash.transaction(&[0xb7], 0).unwrap();
+        assert_eq!(flash.address_mode(), AddressMode::FourByte);
+        let rx = flash.transaction(&[0x0b, 0x00, 0x01, 0x23, 0x45, 0x00], 1).unwrap();
+        assert_eq!(rx, [0x42]);
+
+        flash.transaction(&[0xe9], 0).unwrap();
+        assert_eq!(flash.address_mode(), AddressMode::ThreeByte);
+        let rx = flash.transaction(&[0x03, 0x01, 0x23, 0x45], 1).unwrap();
+        assert_eq!(rx, [0x42]);
+    }
+
+    #[test]
+    fn four_byte_opcodes() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x12, 0x00, 0x01, 0x00, 0x00, 0x42], 0).unwrap();
+        assert_eq!(flash.address_mode(), AddressMode::ThreeByte);
+        assert_eq!(flash.transaction(&[0x13, 0x00, 0x01, 0x00, 0x00], 1).unwrap(), [0x42]);
+        assert_eq!(flash.transaction(&[0x0c, 0x00, 0x01, 0x00, 0x00, 0x00], 1).unwrap(), [0x42]);
+
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x21, 0x00, 0x01, 0x00, 0x00], 0).unwrap();
+        assert_eq!(flash.memory()[0x10000], 0xff);
+    }
+
+    #[test]
+    fn quad_io_read() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.memory_mut()[0x100] = 0x42;
+        // Op code, 3 address bytes, mode byte and 2 dummy bytes.
+        let rx = flash.transaction(&[0xeb, 0x00, 0x01, 0x00, 0xff, 0x00, 0x00], 2).unwrap();
+        assert_eq!(rx, [0x42, 0xff]);
+    }
+
+    #[test]
+    fn busy_polls() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.set_busy_polls(2);
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x20, 0x00, 0x00, 0x00], 0).unwrap();
+        assert_eq!(flash.transaction(&[0x05], 1).unwrap(), [STATUS_BUSY]);
+        assert_eq!(flash.transaction(&[0x05], 1).unwrap(), [STATUS_BUSY]);
+        assert_eq!(flash.transaction(&[0x05], 1).unwrap(), [0]);
+    }
+
+    #[test]
+    fn block_protect() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        // BP3-BP0 = 1 protects the top 64 KiB.
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x01, 0x04], 0).unwrap();
+        assert_eq!(flash.status(), 0x04);
+        assert_eq!(flash.status_register().protected_range(FLASH_SIZE as u32), 0xf0000..0x100000);
+
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x02, 0x0f, 0xff, 0x00, 0x00], 0).unwrap();
+        assert_eq!(flash.memory()[0xfff00], 0xff);
+        assert!(!flash.is_write_enable_set());
+
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x02, 0x0e, 0xff, 0x00, 0x00], 0).unwrap();
+        assert_eq!(flash.memory()[0xeff00], 0x00);
+
+        // Chip erase is ignored if any block is protected.
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0xc7], 0).unwrap();
+        assert_eq!(flash.memory()[0xeff00], 0x00);
+
+        // SRP locks the status register while WP# is asserted.
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x01, 0x04, 0x01], 0).unwrap();
+        flash.set_write_protect(true);
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x01, 0x00, 0x00], 0).unwrap();
+        assert_eq!(flash.status_register().block_protect, 1);
+        assert!(!flash.is_write_enable_set());
+
+        flash.set_write_protect(false);
+        flash.transaction(&[0x06], 0).unwrap();
+        flash.transaction(&[0x01, 0x00], 0).unwrap();
+        assert_eq!(flash.status_register().block_protect, 0);
+        assert!(flash.status_register().status_register_protect);
+    }
+
+    #[test]
+    fn jedec_and_sfdp() {
+        let mut flash = FlashEmulator::new(FLASH_SIZE);
+        flash.set_jedec_id(&[0x26, 0x02, 0x17]);
+        flash.set_sfdp(b"SFDP");
+
+        assert_eq!(flash.transaction(&[0x9f], 4).unwrap(), [0x26, 0x02, 0x17, 0xff]);
+        assert_eq!(flash.transaction(&[0x5a, 0x00, 0x00, 0x01, 0x00], 4).unwrap(),
+            [b'F', b'D', b'P', 0xff]);
+    }
+}
@@ -12,6 +12,7 @@
 //!
 //! [`std::io`]: https://doc.rust-lang.org/std/io/index.html
 
+use core::fmt;
 use core::mem;
 
 use static_assertions::assert_obj_safe;
@@ -125,7 +126,7 @@ impl BitBuf {
 }
 
 /// A generic, low-level I/O error.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[non_exhaustive]
 pub enum Error {
     /// Indicates that some underlying buffer has been completely used up,
@@ -137,6 +138,20 @@ pub enum Error {
 
     /// Indicates that an unspecified, internal failure occurred.
     Internal,
+
+    /// Indicates that a value did not fit into the requested integer type,
+    /// such as an overlong variable-length integer.
+    Overflow,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::BufferExhausted => write!(f, "buffer exhausted"),
+            Self::Internal => write!(f, "internal I/O error"),
+            Self::Overflow => write!(f, "integer overflow"),
+        }
+    }
 }
 
 /// A big-endian integer, which can be read and written.
@@ -243,6 +258,234 @@ impl BeInt for u64 {
     }
 }
 
+/// Implements an integer trait in terms of a `byteorder::ByteOrder`.
+macro_rules! byteorder_int {
+    ($trait:ident, $order:ty, $ty:ty, $read:ident, $write:ident) => {
+        impl $trait for $ty {
+            #[inline]
+            fn read_from<'a, R: Read<'a>>(mut r: R) -> Result<Self, Error> {
+                use byteorder::ByteOrder as _;
+
+                Ok(<$order>::$read(r.read_bytes(mem::size_of::<Self>())?))
+            }
+
+            #[inline]
+            fn write_to<W: Write>(self, mut w: W) -> Result<(), Error> {
+                use byteorder::ByteOrder as _;
+
+                let mut bytes = [0; mem::size_of::<Self>()];
+                <$order>::$write(&mut bytes, self);
+                w.write_bytes(&bytes)
+            }
+        }
+    };
+}
+
+impl BeInt for i8 {
+    #[inline]
+    fn read_from<'a, R: Read<'a>>(r: R) -> Result<Self, Error> {
+        Ok(<u8 as BeInt>::read_from(r)? as i8)
+    }
+
+    #[inline]
+    fn write_to<W: Write>(self, w: W) -> Result<(), Error> {
+        BeInt::write_to(self as u8, w)
+    }
+}
+
+byteorder_int!(BeInt, byteorder::BE, i16, read_i16, write_i16);
+byteorder_int!(BeInt, byteorder::BE, i32, read_i32, write_i32);
+byteorder_int!(BeInt, byteorder::BE, i64, read_i64, write_i64);
+
+/// A little-endian integer, which can be read and written.
+///
+/// This trait can be used for operating generically over little-endian
+/// integer I/O.
+pub trait LeInt: Sized + Copy {
+    /// Reads a value of type `Self`, in little-endian order.
+    fn read_from<'a, R: Read<'a>>(r: R) -> Result<Self, Error>;
+
+    /// Writes a value of type `Self`, in little-endian order.
+    fn write_to<W: Write>(self, w: W) -> Result<(), Error>;
+}
+
+impl LeInt for u8 {
+    #[inline]
+    fn read_from<'a, R: Read<'a>>(r: R) -> Result<Self, Error> {
+        <u8 as BeInt>::read_from(r)
+    }
+
+    #[inline]
+    fn write_to<W: Write>(self, w: W) -> Result<(), Error> {
+        <u8 as BeInt>::write_to(self, w)
+    }
+}
+
+impl LeInt for i8 {
+    #[inline]
+    fn read_from<'a, R: Read<'a>>(r: R) -> Result<Self, Error> {
+        <i8 as BeInt>::read_from(r)
+    }
+
+    #[inline]
+    fn write_to<W: Write>(self, w: W) -> Result<(), Error> {
+        <i8 as BeInt>::write_to(self, w)
+    }
+}
+
+impl LeInt for ux::u24 {
+    #[inline]
+    fn read_from<'a, R: Read<'a>>(mut r: R) -> Result<Self, Error> {
+        use byteorder::ByteOrder as _;
+
+        Ok(ux::u24::new(byteorder::LE::read_u24(
+            r.read_bytes(3)?,
+        )))
+    }
+
+    #[inline]
+    fn write_to<W: Write>(self, mut w: W) -> Result<(), Error> {
+        use byteorder::ByteOrder as _;
+
+        let mut bytes = [0; 3];
+        byteorder::LE::write_u24(&mut bytes, u32::from(self));
+        w.write_bytes(&bytes)
+    }
+}
+
+byteorder_int!(LeInt, byteorder::LE, u16, read_u16, write_u16);
+byteorder_int!(LeInt, byteorder::LE, u32, read_u32, write_u32);
+byteorder_int!(LeInt, byteorder::LE, u64, read_u64, write_u64);
+byteorder_int!(LeInt, byteorder::LE, i16, read_i16, write_i16);
+byteorder_int!(LeInt, byteorder::LE, i32, read_i32, write_i32);
+byteorder_int!(LeInt, byteorder::LE, i64, read_i64, write_i64);
+
+/// The maximum length of a LEB128-encoded 64-bit integer.
+pub const MAX_VARINT_LEN: usize = 10;
+
+/// A variable-length integer, which can be read and written.
+///
+/// Unsigned integers use the unsigned LEB128 encoding and signed integers use
+/// the signed LEB128 encoding: the value is split into groups of seven bits,
+/// least significant group first, and the high bit of each byte is set if
+/// more bytes follow.
+pub trait VarInt: Sized + Copy {
+    /// Reads a LEB128-encoded value of type `Self`.
+    fn read_from<'a, R: Read<'a>>(r: R) -> Result<Self, Error>;
+
+    /// Writes `self` LEB128-encoded.
+    fn write_to<W: Write>(self, w: W) -> Result<(), Error>;
+}
+
+/// Reads an unsigned LEB128 value of at most 64 bits.
+fn read_uleb128<'a, R: Read<'a>>(mut r: R) -> Result<u64, Error> {
+    let mut value = 0u64;
+    for i in 0..MAX_VARINT_LEN {
+        let byte = <u8 as BeInt>::read_from(&mut r)?;
+        let shift = 7 * i as u32;
+        let bits = (byte & 0x7f) as u64;
+        if shift == 63 && bits > 1 {
+            return Err(Error::Overflow);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(Error::Overflow)
+}
+
+/// Writes an unsigned LEB128 value.
+fn write_uleb128<W: Write>(mut value: u64, mut w: W) -> Result<(), Error> {
+    let mut bytes = [0; MAX_VARINT_LEN];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes[len] = byte;
+            len += 1;
+            break;
+        }
+        bytes[len] = byte | 0x80;
+        len += 1;
+    }
+    w.write_bytes(&bytes[..len])
+}
+
+/// Reads a signed LEB128 value of at most 64 bits.
+fn read_sleb128<'a, R: Read<'a>>(mut r: R) -> Result<i64, Error> {
+    let mut value = 0i64;
+    for i in 0..MAX_VARINT_LEN {
+        let byte = <u8 as BeInt>::read_from(&mut r)?;
+        let shift = 7 * i as u32;
+        if shift == 63 {
+            // Only the sign bit remains, so the byte must be a pure sign
+            // extension.
+            if byte != 0x00 && byte != 0x7f {
+                return Err(Error::Overflow);
+            }
+            return Ok(value | ((byte as i64) << 63));
+        }
+        value |= ((byte & 0x7f) as i64) << shift;
+        if byte & 0x80 == 0 {
+            if shift + 7 < 64 && byte & 0x40 != 0 {
+                value |= -1i64 << (shift + 7);
+            }
+            return Ok(value);
+        }
+    }
+    Err(Error::Overflow)
+}
+
+/// Writes a signed LEB128 value.
+fn write_sleb128<W: Write>(mut value: i64, mut w: W) -> Result<(), Error> {
+    let mut bytes = [0; MAX_VARINT_LEN];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            bytes[len] = byte;
+            len += 1;
+            break;
+        }
+        bytes[len] = byte | 0x80;
+        len += 1;
+    }
+    w.write_bytes(&bytes[..len])
+}
+
+/// Implements `VarInt` for an integer type in terms of a 64-bit LEB128
+/// encoding.
+macro_rules! varint {
+    ($ty:ty, $wide:ty, $read:ident, $write:ident) => {
+        impl VarInt for $ty {
+            #[inline]
+            fn read_from<'a, R: Read<'a>>(r: R) -> Result<Self, Error> {
+                use core::convert::TryFrom as _;
+
+                Self::try_from($read(r)?).map_err(|_| Error::Overflow)
+            }
+
+            #[inline]
+            fn write_to<W: Write>(self, w: W) -> Result<(), Error> {
+                $write(<$wide>::from(self), w)
+            }
+        }
+    };
+}
+
+varint!(u8, u64, read_uleb128, write_uleb128);
+varint!(u16, u64, read_uleb128, write_uleb128);
+varint!(u32, u64, read_uleb128, write_uleb128);
+varint!(u64, u64, read_uleb128, write_uleb128);
+varint!(i8, i64, read_sleb128, write_sleb128);
+varint!(i16, i64, read_sleb128, write_sleb128);
+varint!(i32, i64, read_sleb128, write_sleb128);
+varint!(i64, i64, read_sleb128, write_sleb128);
+
 /// Represents a place that bytes can be read from, such as a `&[u8]`.
 ///
 /// Types which implement this trait enable *zero copy reads*, that is,
@@ -283,6 +526,34 @@ pub trait Read<'a> {
     {
         I::read_from(self)
     }
+
+    /// Reads a little-endian integer.
+    ///
+    /// # Note
+    /// Do not implement this function yourself. Callers are not required to
+    /// call it in order to actually perform a read, so whether or not it is
+    /// called is an implementation detail.
+    #[inline]
+    fn read_le<I: LeInt>(&mut self) -> Result<I, Error>
+    where
+        Self: Sized,
+    {
+        I::read_from(self)
+    }
+
+    /// Reads a LEB128-encoded variable-length integer.
+    ///
+    /// # Note
+    /// Do not implement this function yourself. Callers are not required to
+    /// call it in order to actually perform a read, so whether or not it is
+    /// called is an implementation detail.
+    #[inline]
+    fn read_varint<I: VarInt>(&mut self) -> Result<I, Error>
+    where
+        Self: Sized,
+    {
+        I::read_from(self)
+    }
 }
 
 assert_obj_safe!(Read<'static>);
@@ -358,7 +629,35 @@ pub trait Write {
     where
         Self: Sized,
     {
-        val.write_to(self)
+        BeInt::write_to(val, self)
+    }
+
+    /// Writes a little-endian integer.
+    ///
+    /// # Note
+    /// Do not implement this function yourself. Callers are not required to
+    /// call it in order to actually perform a write, so whether or not it is
+    /// called is an implementation detail.
+    #[inline]
+    fn write_le<I: LeInt>(&mut self, val: I) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        LeInt::write_to(val, self)
+    }
+
+    /// Writes a LEB128-encoded variable-length integer.
+    ///
+    /// # Note
+    /// Do not implement this function yourself. Callers are not required to
+    /// call it in order to actually perform a write, so whether or not it is
+    /// called is an implementation detail.
+    #[inline]
+    fn write_varint<I: VarInt>(&mut self, val: I) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        VarInt::write_to(val, self)
     }
 }
 
@@ -516,6 +815,206 @@ impl Write for Cursor<'_> {
     }
 }
 
+/// A reader or writer that continues with `second` once `first` is exhausted.
+///
+/// Reads that lie entirely within `first` or `second` are zero copy. Reads
+/// that straddle the boundary between the two are copied into a scratch
+/// buffer, which must be provided with [`with_scratch()`] for such reads to
+/// succeed. Each straddling read consumes as many bytes of the scratch buffer
+/// as it returns.
+///
+/// For writes, `first` must be a writer whose capacity is known, i.e. a
+/// `&mut [u8]` or a [`Limit`].
+///
+/// [`with_scratch()`]: struct.Chain.html#method.with_scratch
+/// [`Limit`]: struct.Limit.html
+pub struct Chain<'s, A, B> {
+    first: A,
+    second: B,
+    scratch: &'s mut [u8],
+}
+
+impl<'s, A, B> Chain<'s, A, B> {
+    /// Creates a new `Chain` without a scratch buffer.
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            scratch: &mut [],
+        }
+    }
+
+    /// Sets the scratch buffer used for reads that straddle the boundary
+    /// between `first` and `second`.
+    pub fn with_scratch(mut self, scratch: &'s mut [u8]) -> Self {
+        self.scratch = scratch;
+        self
+    }
+
+    /// Returns the underlying readers or writers.
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<'a, A: Read<'a>, B: Read<'a>> Read<'a> for Chain<'a, A, B> {
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        let first_len = self.first.remaining_data();
+        if n <= first_len {
+            return self.first.read_bytes(n);
+        }
+        if first_len == 0 {
+            return self.second.read_bytes(n);
+        }
+        if self.remaining_data() < n || self.scratch.len() < n {
+            return Err(Error::BufferExhausted);
+        }
+
+        let (dest, rest) = mem::take(&mut self.scratch).split_at_mut(n);
+        self.scratch = rest;
+        let (dest_first, dest_second) = dest.split_at_mut(first_len);
+        dest_first.copy_from_slice(self.first.read_bytes(first_len)?);
+        dest_second.copy_from_slice(self.second.read_bytes(n - first_len)?);
+        Ok(dest)
+    }
+
+    fn remaining_data(&self) -> usize {
+        self.first.remaining_data() + self.second.remaining_data()
+    }
+}
+
+impl<B: Write> Write for Chain<'_, &'_ mut [u8], B> {
+    fn write_bytes(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let first_len = buf.len().min(self.first.len());
+        let (buf_first, buf_second) = buf.split_at(first_len);
+        self.first.write_bytes(buf_first)?;
+        self.second.write_bytes(buf_second)
+    }
+}
+
+impl<A: Write, B: Write> Write for Chain<'_, Limit<A>, B> {
+    fn write_bytes(&mut self, buf: &[u8]) -> Result<(), Error> {
+        let first_len = buf.len().min(self.first.remaining_capacity());
+        let (buf_first, buf_second) = buf.split_at(first_len);
+        self.first.write_bytes(buf_first)?;
+        self.second.write_bytes(buf_second)
+    }
+}
+
+/// A reader over the contents of a ring buffer.
+///
+/// The data starts at `start` and is `len` bytes long, wrapping around to
+/// the beginning of `buf` at its end. This is a [`Chain`] of the two
+/// contiguous parts of the data.
+///
+/// [`Chain`]: struct.Chain.html
+pub struct RingReader<'a> {
+    chain: Chain<'a, &'a [u8], &'a [u8]>,
+}
+
+impl<'a> RingReader<'a> {
+    /// Creates a new `RingReader` for the `len` bytes starting at `start` in
+    /// `buf`.
+    ///
+    /// `scratch` is used for reads that wrap around the end of `buf`.
+    ///
+    /// Returns `None` if `start` or `len` exceed the length of `buf`.
+    pub fn new(buf: &'a [u8], start: usize, len: usize, scratch: &'a mut [u8]) -> Option<Self> {
+        if start > buf.len() || len > buf.len() {
+            return None;
+        }
+        let (head, tail) = buf.split_at(start);
+        let first_len = len.min(tail.len());
+        let chain = Chain::new(&tail[..first_len], &head[..len - first_len])
+            .with_scratch(scratch);
+        Some(Self { chain })
+    }
+}
+
+impl<'a> Read<'a> for RingReader<'a> {
+    #[inline]
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        self.chain.read_bytes(n)
+    }
+
+    #[inline]
+    fn remaining_data(&self) -> usize {
+        self.chain.remaining_data()
+    }
+}
+
+/// A reader that yields at most `limit` bytes from the underlying reader.
+pub struct Take<R> {
+    inner: R,
+    limit: usize,
+}
+
+impl<R> Take<R> {
+    /// Creates a new `Take` reading at most `limit` bytes from `inner`.
+    pub fn new(inner: R, limit: usize) -> Self {
+        Self { inner, limit }
+    }
+
+    /// Returns the number of bytes that may still be read.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<'a, R: Read<'a>> Read<'a> for Take<R> {
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if n > self.limit {
+            return Err(Error::BufferExhausted);
+        }
+        let result = self.inner.read_bytes(n)?;
+        self.limit -= n;
+        Ok(result)
+    }
+
+    fn remaining_data(&self) -> usize {
+        self.inner.remaining_data().min(self.limit)
+    }
+}
+
+/// A writer that accepts at most `limit` bytes for the underlying writer.
+pub struct Limit<W> {
+    inner: W,
+    limit: usize,
+}
+
+impl<W> Limit<W> {
+    /// Creates a new `Limit` writing at most `limit` bytes to `inner`.
+    pub fn new(inner: W, limit: usize) -> Self {
+        Self { inner, limit }
+    }
+
+    /// Returns the number of bytes that may still be written.
+    pub fn remaining_capacity(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for Limit<W> {
+    fn write_bytes(&mut self, buf: &[u8]) -> Result<(), Error> {
+        if buf.len() > self.limit {
+            return Err(Error::BufferExhausted);
+        }
+        self.inner.write_bytes(buf)?;
+        self.limit -= buf.len();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -615,4 +1114,163 @@ mod test {
 
         assert!(cursor.write_bytes(&[0x55; 7]).is_err());
     }
+
+    #[test]
+    fn be_and_le_ints() {
+        let mut buf = [0; 16];
+        let mut cursor = Cursor::new(&mut buf);
+        cursor.write_be::<i16>(-2).unwrap();
+        cursor.write_le::<i16>(-2).unwrap();
+        cursor.write_le(ux::u24::new(0x030201)).unwrap();
+        cursor.write_le::<i32>(-0x01020304).unwrap();
+        cursor.write_be::<i8>(-1).unwrap();
+        assert_eq!(cursor.consumed_bytes(), &[
+            0xff, 0xfe,
+            0xfe, 0xff,
+            0x01, 0x02, 0x03,
+            0xfc, 0xfc, 0xfd, 0xfe,
+            0xff,
+        ]);
+
+        let mut bytes = cursor.consumed_bytes();
+        assert_eq!(bytes.read_be::<i16>().unwrap(), -2);
+        assert_eq!(bytes.read_le::<i16>().unwrap(), -2);
+        assert_eq!(bytes.read_le::<ux::u24>().unwrap(), ux::u24::new(0x030201));
+        assert_eq!(bytes.read_le::<i32>().unwrap(), -0x01020304);
+        assert_eq!(bytes.read_be::<i8>().unwrap(), -1);
+        assert!(bytes.read_le::<i64>().is_err());
+    }
+
+    #[test]
+    fn varints() {
+        fn round_trip<I: VarInt + PartialEq + core::fmt::Debug>(val: I, expected: &[u8]) {
+            let mut buf = [0; MAX_VARINT_LEN];
+            let mut cursor = Cursor::new(&mut buf);
+            cursor.write_varint(val).unwrap();
+            assert_eq!(cursor.consumed_bytes(), expected);
+
+            let mut bytes = expected;
+            assert_eq!(bytes.read_varint::<I>().unwrap(), val);
+            assert!(bytes.is_empty());
+        }
+
+        round_trip(0u8, &[0x00]);
+        round_trip(127u8, &[0x7f]);
+        round_trip(128u16, &[0x80, 0x01]);
+        round_trip(624485u32, &[0xe5, 0x8e, 0x26]);
+        round_trip(u64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]);
+        round_trip(0i8, &[0x00]);
+        round_trip(-1i8, &[0x7f]);
+        round_trip(63i16, &[0x3f]);
+        round_trip(64i16, &[0xc0, 0x00]);
+        round_trip(-123456i32, &[0xc0, 0xbb, 0x78]);
+        round_trip(i64::MIN, &[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f]);
+        round_trip(i64::MAX, &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00]);
+    }
+
+    #[test]
+    fn varint_errors() {
+        // Doesn't fit into a u8.
+        let mut bytes: &[u8] = &[0x80, 0x02];
+        assert!(matches!(bytes.read_varint::<u8>(), Err(Error::Overflow)));
+
+        // Doesn't fit into an i8.
+        let mut bytes: &[u8] = &[0x80, 0x7e];
+        assert!(matches!(bytes.read_varint::<i8>(), Err(Error::Overflow)));
+
+        // More than 64 bits.
+        let mut bytes: &[u8] = &[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02];
+        assert!(matches!(bytes.read_varint::<u64>(), Err(Error::Overflow)));
+        let mut bytes: &[u8] = &[0x80; MAX_VARINT_LEN + 1];
+        assert!(matches!(bytes.read_varint::<u64>(), Err(Error::Overflow)));
+
+        // Truncated.
+        let mut bytes: &[u8] = &[0x80, 0x80];
+        assert!(matches!(bytes.read_varint::<u32>(), Err(Error::BufferExhausted)));
+    }
+
+    #[test]
+    fn chain_read() {
+        let mut chain = Chain::new(&b"Hel"[..], &b"lo!"[..]);
+        assert_eq!(chain.remaining_data(), 6);
+        assert_eq!(chain.read_bytes(2).unwrap(), b"He");
+        // Straddling reads need a scratch buffer.
+        assert!(chain.read_bytes(2).is_err());
+
+        let mut scratch = [0; 4];
+        let mut chain = Chain::new(&b"Hel"[..], &b"lo!"[..]).with_scratch(&mut scratch);
+        assert_eq!(chain.read_bytes(2).unwrap(), b"He");
+        assert_eq!(chain.read_be::<u16>().unwrap(), 0x6c6c);
+        assert_eq!(chain.remaining_data(), 2);
+        assert!(chain.read_bytes(3).is_err());
+        assert_eq!(chain.read_bytes(2).unwrap(), b"o!");
+        assert_eq!(chain.remaining_data(), 0);
+
+        // The scratch buffer is used up by straddling reads.
+        let mut scratch = [0; 2];
+        let mut chain = Chain::new(&b"ab"[..], Chain::new(&b"cd"[..], &b"ef"[..]))
+            .with_scratch(&mut scratch);
+        assert_eq!(chain.read_bytes(1).unwrap(), b"a");
+        assert_eq!(chain.read_bytes(2).unwrap(), b"bc");
+        assert!(chain.read_bytes(2).is_err());
+        assert_eq!(chain.read_bytes(1).unwrap(), b"d");
+    }
+
+    #[test]
+    fn chain_write() {
+        let mut first = [0; 3];
+        let mut second = [0; 3];
+        let mut chain = Chain::new(&mut first[..], &mut second[..]);
+        chain.write_bytes(b"He").unwrap();
+        chain.write_be(0x6c6cu16).unwrap();
+        chain.write_bytes(b"o!").unwrap();
+        assert!(chain.write_bytes(b"?").is_err());
+        assert_eq!(&first, b"Hel");
+        assert_eq!(&second, b"lo!");
+
+        let mut first = [0; 4];
+        let mut second = Vec::new();
+        let mut chain = Chain::new(Limit::new(&mut first[..], 2), StdWrite(&mut second));
+        chain.write_bytes(b"Hello!").unwrap();
+        assert_eq!(&first, b"He\0\0");
+        assert_eq!(second, b"llo!");
+    }
+
+    #[test]
+    fn ring_reader() {
+        let buf = *b"o!Hell";
+        let mut scratch = [0; 4];
+        let mut ring = RingReader::new(&buf, 2, 6, &mut scratch).unwrap();
+        assert_eq!(ring.remaining_data(), 6);
+        assert_eq!(ring.read_bytes(3).unwrap(), b"Hel");
+        assert_eq!(ring.read_bytes(3).unwrap(), b"lo!");
+        assert_eq!(ring.remaining_data(), 0);
+
+        let mut scratch = [];
+        let mut ring = RingReader::new(&buf, 4, 2, &mut scratch).unwrap();
+        assert_eq!(ring.read_bytes(2).unwrap(), b"ll");
+        assert!(ring.read_bytes(1).is_err());
+
+        assert!(RingReader::new(&buf, 7, 0, &mut []).is_none());
+        assert!(RingReader::new(&buf, 0, 7, &mut []).is_none());
+    }
+
+    #[test]
+    fn take_and_limit() {
+        let mut take = Take::new(&b"Hello!"[..], 4);
+        assert_eq!(take.remaining_data(), 4);
+        assert_eq!(take.read_bytes(3).unwrap(), b"Hel");
+        assert!(take.read_bytes(2).is_err());
+        assert_eq!(take.read_bytes(1).unwrap(), b"l");
+        assert_eq!(take.limit(), 0);
+        assert_eq!(take.into_inner(), b"o!");
+
+        let mut buf = [0; 6];
+        let mut limit = Limit::new(&mut buf[..], 4);
+        limit.write_bytes(b"Hel").unwrap();
+        assert!(limit.write_bytes(b"lo").is_err());
+        assert_eq!(limit.remaining_capacity(), 1);
+        limit.write_bytes(b"l").unwrap();
+        assert_eq!(&buf, b"Hell\0\0");
+    }
 }
@@ -20,9 +20,19 @@
 
 //! Utilities for SPI
 
+// The derive macros refer to this crate as `spiutils`.
+extern crate self as spiutils;
+
 pub mod driver;
 
+#[cfg(feature = "std")]
+pub mod emulator;
+
 pub mod io;
 
 #[macro_use]
 pub mod protocol;
+
+pub mod manifest;
+
+pub mod policy;
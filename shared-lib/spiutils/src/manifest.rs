@@ -0,0 +1,390 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signed host firmware manifests.
+//!
+//! A manifest in the host flash lists the SHA-256 digests of the host flash
+//! regions of a firmware image. It is signed with an RSA key using
+//! RSASSA-PKCS1-v1_5 with SHA-256. The device verifies the manifest before it
+//! lets the host boot from the flash.
+//!
+//! The manifest layout is, with all integers in big-endian byte order:
+//!
+//! | Offset | Length        | Field                           |
+//! |--------|---------------|---------------------------------|
+//! | 0      | 4             | `MAGIC`                         |
+//! | 4      | 2             | modulus length in bytes (`n`)   |
+//! | 6      | 1             | region count (`r`)              |
+//! | 7      | 1             | reserved                        |
+//! | 8      | 4             | public exponent                 |
+//! | 12     | `n`           | modulus                         |
+//! | 12 + n | `r` * 40      | regions, as `Measurement`s      |
+//! | ...    | `n`           | signature of all of the above   |
+
+use crate::io::Read;
+use crate::protocol::measurement::Measurement;
+use crate::protocol::measurement::Region;
+use crate::protocol::measurement::DIGEST_LEN;
+use crate::protocol::measurement::MEASUREMENT_LEN;
+use crate::protocol::wire::FieldError;
+use crate::protocol::wire::FromWire;
+use crate::protocol::wire::FromWireError;
+use crate::protocol::wire::WireEnum;
+
+/// The magic number at the start of a manifest.
+pub const MAGIC: u32 = 0x4f54_4d46;
+
+/// The length of the fixed size part of a manifest, in bytes.
+pub const HEADER_LEN: usize = 12;
+
+/// The offset of the public key (exponent followed by modulus).
+const KEY_OFFSET: usize = 8;
+
+/// The maximum number of regions in a manifest.
+pub const MAX_REGIONS: usize = 8;
+
+/// The maximum RSA modulus length, in bytes.
+pub const MAX_MODULUS_LEN: usize = 384;
+
+/// The maximum length of a manifest, in bytes.
+pub const MAX_MANIFEST_LEN: usize = HEADER_LEN + 2 * MAX_MODULUS_LEN + MAX_REGIONS * MEASUREMENT_LEN;
+
+/// A parser for a manifest.
+#[derive(Clone, Copy, Debug)]
+pub struct ManifestReader<'a> {
+    exponent: u32,
+    key: &'a [u8],
+    modulus: &'a [u8],
+    regions: &'a [u8],
+    signed: &'a [u8],
+    signature: &'a [u8],
+}
+
+impl<'a> ManifestReader<'a> {
+    /// Parses the manifest at the start of `data`.
+    pub fn new(data: &'a [u8]) -> Result<Self, FromWireError> {
+        let mut r = data;
+        let magic = r.read_be::<u32>()?;
+        if magic != MAGIC {
+            return Err(FromWireError::UnknownValue(FieldError::new("magic").with_value(magic).at(0)));
+        }
+        let modulus_len = r.read_be::<u16>()?;
+        if !matches!(modulus_len, 256 | 384) {
+            return Err(FromWireError::UnknownValue(
+                FieldError::new("modulus length").with_value(modulus_len).at(4)));
+        }
+        let region_count = r.read_be::<u8>()?;
+        if region_count as usize > MAX_REGIONS {
+            return Err(FromWireError::OutOfRange(
+                FieldError::new("region count").with_value(region_count).at(6)));
+        }
+        let _reserved = r.read_be::<u8>()?;
+        let exponent = r.read_be::<u32>()?;
+        let modulus = r.read_bytes(modulus_len as usize)?;
+        let regions = r.read_bytes(region_count as usize * MEASUREMENT_LEN)?;
+        let signed_len = HEADER_LEN + modulus.len() + regions.len();
+        let signature = r.read_bytes(modulus_len as usize)?;
+        Ok(Self {
+            exponent,
+            key: &data[KEY_OFFSET..HEADER_LEN + modulus.len()],
+            modulus,
+            regions,
+            signed: &data[..signed_len],
+            signature,
+        })
+    }
+
+    /// Returns the public exponent of the signing key.
+    pub fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    /// Returns the big-endian modulus of the signing key.
+    pub fn modulus(&self) -> &'a [u8] {
+        self.modulus
+    }
+
+    /// Returns the signing key as it appears in the manifest, i.e. the
+    /// exponent followed by the modulus. Its SHA-256 digest identifies the
+    /// key.
+    pub fn key(&self) -> &'a [u8] {
+        self.key
+    }
+
+    /// Returns the signed part of the manifest.
+    pub fn signed_data(&self) -> &'a [u8] {
+        self.signed
+    }
+
+    /// Returns the signature.
+    pub fn signature(&self) -> &'a [u8] {
+        self.signature
+    }
+
+    /// Returns an iterator over the regions and their expected digests.
+    pub fn regions(&self) -> impl Iterator<Item = Measurement> + 'a {
+        // The length of the regions was checked by `new`, so parsing cannot fail.
+        self.regions.chunks(MEASUREMENT_LEN).filter_map(|chunk| Measurement::from_wire(chunk).ok())
+    }
+}
+
+wire_enum! {
+    /// The reason a manifest was rejected.
+    pub enum Failure: u8 {
+        /// There is no valid manifest.
+        Invalid = 0x01,
+
+        /// The manifest is not signed with the trusted key.
+        UntrustedKey = 0x02,
+
+        /// The signature of the manifest is invalid.
+        BadSignature = 0x03,
+
+        /// The digest of a region doesn't match the manifest.
+        DigestMismatch = 0x04,
+
+        /// Reading or hashing the host flash failed.
+        Internal = 0x05,
+    }
+}
+
+/// Checks that the manifest at the start of `data` is signed with the trusted
+/// key, without measuring its regions.
+///
+/// * `sha256(data)` returns the SHA-256 digest of `data`.
+/// * `verify_signature(manifest)` returns whether the signature of
+///   `manifest` is valid.
+///
+/// The manifest is authentic if the digest of its key is
+/// `trusted_key_digest` and its signature is valid. This lets callers that
+/// can't measure regions synchronously compare the digests of
+/// `ManifestReader::regions` themselves.
+pub fn authenticate<'a, E, Sh, Ve>(data: &'a [u8], trusted_key_digest: &[u8; DIGEST_LEN],
+    sha256: Sh, verify_signature: Ve) -> Result<ManifestReader<'a>, Failure>
+where
+    Sh: FnOnce(&[u8]) -> Result<[u8; DIGEST_LEN], E>,
+    Ve: FnOnce(&ManifestReader<'a>) -> bool,
+{
+    let manifest = ManifestReader::new(data).map_err(|_| Failure::Invalid)?;
+    let key_digest = sha256(manifest.key()).map_err(|_| Failure::Internal)?;
+    if key_digest != *trusted_key_digest {
+        return Err(Failure::UntrustedKey);
+    }
+    if !verify_signature(&manifest) {
+        return Err(Failure::BadSignature);
+    }
+    Ok(manifest)
+}
+
+/// Verifies the manifest at the start of `data`.
+///
+/// `sha256` and `verify_signature` are as for `authenticate`, and
+/// `measure(region)` returns the SHA-256 digest of `region` of the host
+/// flash.
+///
+/// The manifest is accepted if it is authentic and all region digests match.
+pub fn verify<'a, E, Sh, Ve, Me>(data: &'a [u8], trusted_key_digest: &[u8; DIGEST_LEN],
+    sha256: Sh, verify_signature: Ve, mut measure: Me) -> Result<ManifestReader<'a>, Failure>
+where
+    Sh: FnOnce(&[u8]) -> Result<[u8; DIGEST_LEN], E>,
+    Ve: FnOnce(&ManifestReader<'a>) -> bool,
+    Me: FnMut(&Region) -> Result<[u8; DIGEST_LEN], E>,
+{
+    let manifest = authenticate(data, trusted_key_digest, sha256, verify_signature)?;
+    for expected in manifest.regions() {
+        if measure(&expected.region).map_err(|_| Failure::Internal)? != expected.digest {
+            return Err(Failure::DigestMismatch);
+        }
+    }
+    Ok(manifest)
+}
+
+wire_enum! {
+    /// What the device decided to boot.
+    pub enum Decision: u8 {
+        /// The primary image was verified.
+        Verified = 0x01,
+
+        /// The primary image was rejected and the recovery image was
+        /// verified. The host is expected to boot the recovery image by
+        /// itself.
+        Recovery = 0x02,
+
+        /// Both images were rejected. The host flash is not released.
+        Halted = 0x03,
+    }
+}
+
+/// The outcome of verified boot, as reported in the mailbox status.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BootStatus {
+    /// What the device decided to boot.
+    pub decision: Decision,
+
+    /// Why the primary image was rejected, if it was.
+    pub failure: Option<Failure>,
+}
+
+impl BootStatus {
+    /// Returns the status as a single byte, with the decision in the upper
+    /// and the failure in the lower nibble. The byte is never zero.
+    pub fn to_wire_value(&self) -> u8 {
+        self.decision.to_wire_value() << 4 | self.failure.map_or(0, |failure| failure.to_wire_value())
+    }
+
+    /// Parses a status returned by `to_wire_value`.
+    pub fn from_wire_value(value: u8) -> Option<Self> {
+        let decision = Decision::from_wire_value(value >> 4)?;
+        let failure = match value & 0x0f {
+            0 => None,
+            failure => Some(Failure::from_wire_value(failure)?),
+        };
+        Some(Self {
+            decision,
+            failure,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::io::Cursor;
+    use crate::io::Write as _;
+    use crate::protocol::wire::ToWire;
+
+    const MODULUS_LEN: usize = 256;
+
+    // A fake digest that is the XOR of all 32 byte blocks of `data`.
+    fn sha256(data: &[u8]) -> Result<[u8; DIGEST_LEN], ()> {
+        let mut digest = [0u8; DIGEST_LEN];
+        for (index, byte) in data.iter().enumerate() {
+            digest[index % DIGEST_LEN] ^= *byte;
+        }
+        Ok(digest)
+    }
+
+    fn region(start: u32) -> Region {
+        Region { start, len: 0x1000 }
+    }
+
+    // The fake digest of a region is its start address repeated.
+    fn measure(region: &Region) -> Result<[u8; DIGEST_LEN], ()> {
+        Ok([region.start as u8; DIGEST_LEN])
+    }
+
+    // Writes a manifest with two regions and returns its length.
+    fn manifest(buf: &mut [u8]) -> usize {
+        let mut cursor = Cursor::new(buf);
+        cursor.write_be(MAGIC).unwrap();
+        cursor.write_be(MODULUS_LEN as u16).unwrap();
+        cursor.write_be(2u8).unwrap();
+        cursor.write_be(0u8).unwrap();
+        cursor.write_be(0x10001u32).unwrap();
+        cursor.write_bytes(&[0xa5; MODULUS_LEN]).unwrap();
+        for start in [0x10, 0x20].iter() {
+            Measurement { region: region(*start), digest: measure(&region(*start)).unwrap() }
+                .to_wire(&mut cursor).unwrap();
+        }
+        cursor.write_bytes(&[0x5a; MODULUS_LEN]).unwrap();
+        cursor.consumed_len()
+    }
+
+    fn trusted_key_digest() -> [u8; DIGEST_LEN] {
+        let mut key = [0xa5; 4 + MODULUS_LEN];
+        key[..4].copy_from_slice(&0x10001u32.to_be_bytes());
+        sha256(&key).unwrap()
+    }
+
+    #[test]
+    fn reader() {
+        let mut buf = [0xff; MAX_MANIFEST_LEN];
+        let len = manifest(&mut buf);
+        assert_eq!(len, HEADER_LEN + 2 * MODULUS_LEN + 2 * MEASUREMENT_LEN);
+
+        let manifest = ManifestReader::new(&buf).unwrap();
+        assert_eq!(manifest.exponent(), 0x10001);
+        assert_eq!(manifest.modulus(), &[0xa5; MODULUS_LEN][..]);
+        assert_eq!(manifest.key().len(), 4 + MODULUS_LEN);
+        assert_eq!(manifest.signed_data(), &buf[..len - MODULUS_LEN]);
+        assert_eq!(manifest.signature(), &[0x5a; MODULUS_LEN][..]);
+        let starts = manifest.regions().map(|measurement| measurement.region.start);
+        assert!(starts.eq([0x10, 0x20].iter().cloned()));
+
+        // An erased flash doesn't contain a manifest.
+        assert!(ManifestReader::new(&[0xff; MAX_MANIFEST_LEN]).is_err());
+        // The signature is missing.
+        assert!(ManifestReader::new(&buf[..len - 1]).is_err());
+        buf[5] = 0x01;
+        assert!(ManifestReader::new(&buf).is_err());
+        // 4096-bit keys don't fit in the kernel's RSA scratch space.
+        buf[4..6].copy_from_slice(&512u16.to_be_bytes());
+        assert!(ManifestReader::new(&buf).is_err());
+    }
+
+    #[test]
+    fn verify_manifest() {
+        let mut buf = [0xff; MAX_MANIFEST_LEN];
+        manifest(&mut buf);
+        let trusted_key_digest = trusted_key_digest();
+
+        assert!(verify(&buf, &trusted_key_digest, sha256, |_| true, measure).is_ok());
+        assert_eq!(verify(&buf, &[0; DIGEST_LEN], sha256, |_| true, measure).err(),
+            Some(Failure::UntrustedKey));
+        assert_eq!(verify(&buf, &trusted_key_digest, sha256, |_| false, measure).err(),
+            Some(Failure::BadSignature));
+        assert_eq!(verify(&buf, &trusted_key_digest, sha256, |_| true, |_| Ok([0; DIGEST_LEN])).err(),
+            Some(Failure::DigestMismatch));
+        assert_eq!(verify(&buf, &trusted_key_digest, sha256, |_| true, |_| Err(())).err(),
+            Some(Failure::Internal));
+        assert_eq!(verify(&[0xff; 16], &trusted_key_digest, sha256, |_| true, measure).err(),
+            Some(Failure::Invalid));
+    }
+
+    #[test]
+    fn authenticate_manifest() {
+        let mut buf = [0xff; MAX_MANIFEST_LEN];
+        manifest(&mut buf);
+        let trusted_key_digest = trusted_key_digest();
+
+        // Regions are not measured.
+        let manifest = authenticate(&buf, &trusted_key_digest, sha256, |_| true).unwrap();
+        assert_eq!(manifest.regions().count(), 2);
+        assert_eq!(authenticate(&buf, &[0; DIGEST_LEN], sha256, |_| true).err(),
+            Some(Failure::UntrustedKey));
+        assert_eq!(authenticate(&buf, &trusted_key_digest, sha256, |_| false).err(),
+            Some(Failure::BadSignature));
+        assert_eq!(authenticate(&buf, &trusted_key_digest, |_| Err(()), |_| true).err(),
+            Some(Failure::Internal));
+        assert_eq!(authenticate(&[0xff; 16], &trusted_key_digest, sha256, |_| true).err(),
+            Some(Failure::Invalid));
+    }
+
+    #[test]
+    fn boot_status() {
+        let status = BootStatus { decision: Decision::Recovery, failure: Some(Failure::BadSignature) };
+        assert_eq!(status.to_wire_value(), 0x23);
+        assert_eq!(BootStatus::from_wire_value(0x23), Some(status));
+
+        let status = BootStatus { decision: Decision::Verified, failure: None };
+        assert_eq!(status.to_wire_value(), 0x10);
+        assert_eq!(BootStatus::from_wire_value(0x10), Some(status));
+
+        assert_eq!(BootStatus::from_wire_value(0x00), None);
+        assert_eq!(BootStatus::from_wire_value(0x1f), None);
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host flash write policy.
+//!
+//! A `WritePolicy` is a table of protected host flash regions that is
+//! consulted before a "write" type command (program or erase) of the SPI
+//! host is passed through to the host flash.
+
+use crate::protocol::flash::Header;
+use crate::protocol::flash::OpCode;
+use crate::protocol::flash::PAGE_SIZE;
+
+/// The size of the smallest erasable unit of the host flash.
+///
+/// Region bounds must be aligned to it, so that every erase and every page
+/// lies either completely inside or completely outside of a region.
+pub const SECTOR_SIZE: u32 = 4 * 1024;
+
+wire_enum! {
+    /// The protection of a host flash region.
+    pub enum Protection: u8 {
+        /// The region cannot be programmed or erased.
+        ReadOnly = 0x01,
+
+        /// Erased bytes of the region can be programmed once. The region
+        /// cannot be erased.
+        WriteOnce = 0x02,
+
+        /// The region can only be programmed or erased in recovery mode.
+        RecoveryOnly = 0x03,
+    }
+}
+
+/// A protected host flash region.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Region {
+    /// The address of the first byte of the region.
+    pub start: u32,
+
+    /// The length of the region, in bytes.
+    pub len: u32,
+
+    /// The protection of the region.
+    pub protection: Protection,
+}
+
+impl Region {
+    /// Returns whether the start and the end of the region are aligned to
+    /// `SECTOR_SIZE`.
+    pub fn is_aligned(&self) -> bool {
+        (self.start | self.len) & (SECTOR_SIZE - 1) == 0
+    }
+
+    /// Returns the part of the `len` bytes starting at `address` that lies
+    /// within the region, as an `(address, len)` pair.
+    pub fn intersection(&self, address: u32, len: u32) -> Option<(u32, u32)> {
+        let start = core::cmp::max(self.start as u64, address as u64);
+        let end = core::cmp::min(
+            self.start as u64 + self.len as u64,
+            address as u64 + len as u64);
+        if start < end {
+            Some((start as u32, (end - start) as u32))
+        } else {
+            None
+        }
+    }
+}
+
+/// A "write" type command.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Write {
+    /// Program `len` bytes starting at `address`.
+    ///
+    /// Like on the flash, a program that reaches the end of its page wraps
+    /// around to the start of the page.
+    Program {
+        /// The address of the first programmed byte.
+        address: u32,
+
+        /// The number of programmed bytes, at most `PAGE_SIZE`.
+        len: u32,
+    },
+
+    /// Erase `len` bytes starting at `address`.
+    Erase {
+        /// The address of the first erased byte.
+        address: u32,
+
+        /// The number of erased bytes.
+        len: u32,
+    },
+}
+
+impl Write {
+    /// Returns the write executed by the command with `header` and
+    /// `data_len` bytes of data on a flash of `flash_size` bytes.
+    ///
+    /// Returns `None` if the command doesn't modify the flash.
+    pub fn from_header(header: &Header, data_len: usize, flash_size: u32) -> Option<Write> {
+        let address = header.get_address().unwrap_or(0);
+        match header.opcode {
+            // If more than a page of data is sent, the flash keeps
+            // wrapping around and programs the whole page.
+            opcode if opcode.is_page_program() => Some(Write::Program {
+                address,
+                len: core::cmp::min(data_len, PAGE_SIZE as usize) as u32,
+            }),
+            OpCode::ChipErase | OpCode::ChipErase2 => Some(Write::Erase {
+                address: 0,
+                len: flash_size,
+            }),
+            opcode => opcode.erase_len().map(|len| Write::Erase {
+                // Erases ignore the low address bits.
+                address: address & !(len - 1),
+                len,
+            }),
+        }
+    }
+
+    /// Returns the modified ranges as `(address, len)` pairs.
+    ///
+    /// The second range is only non-empty for a program that wraps around
+    /// to the start of its page, in which case it starts at the page start.
+    pub fn ranges(&self) -> [(u32, u32); 2] {
+        match *self {
+            Write::Program { address, len } => {
+                let offset = address % PAGE_SIZE;
+                let first_len = core::cmp::min(len, PAGE_SIZE - offset);
+                let wrapped_len = core::cmp::min(len - first_len, offset);
+                [(address, first_len), (address - offset, wrapped_len)]
+            }
+            Write::Erase { address, len } => [(address, len), (address, 0)],
+        }
+    }
+}
+
+/// An error returned by `WritePolicy::check`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error<E> {
+    /// The write was rejected because it modifies a region with the given
+    /// protection.
+    Rejected(Protection),
+
+    /// Checking whether the flash is erased failed.
+    Flash(E),
+}
+
+/// A table of protected host flash regions.
+///
+/// Bytes that are not within any region are unprotected.
+#[derive(Clone, Copy, Debug)]
+pub struct WritePolicy<'a> {
+    regions: &'a [Region],
+}
+
+impl<'a> WritePolicy<'a> {
+    /// Creates a new `WritePolicy` with the protected `regions`.
+    pub const fn new(regions: &'a [Region]) -> Self {
+        Self { regions }
+    }
+
+    /// Returns the protected regions.
+    pub fn regions(&self) -> &'a [Region] {
+        self.regions
+    }
+
+    /// Returns whether all regions are aligned to `SECTOR_SIZE`.
+    pub fn is_aligned(&self) -> bool {
+        self.regions.iter().all(Region::is_aligned)
+    }
+
+    /// Returns whether `check` needs to know whether the flash is erased for
+    /// `write`, i.e. whether `write` is a program that intersects a
+    /// `Protection::WriteOnce` region.
+    pub fn needs_erased_check(&self, write: &Write) -> bool {
+        if let Write::Erase { .. } = write {
+            return false;
+        }
+        write.ranges().iter().any(|&(address, len)| {
+            self.regions.iter().any(|region| region.protection == Protection::WriteOnce
+                && region.intersection(address, len).is_some())
+        })
+    }
+
+    /// Checks whether `write` may be passed through to the flash.
+    ///
+    /// `is_erased(address, len)` returns whether the `len` bytes starting at
+    /// `address` are erased. It is only called for programs that intersect
+    /// a `Protection::WriteOnce` region, and only for bytes within the page
+    /// of the program.
+    pub fn check<E, F>(&self, write: &Write, recovery_mode: bool, mut is_erased: F) -> Result<(), Error<E>>
+    where F: FnMut(u32, u32) -> Result<bool, E>
+    {
+        for &(address, len) in write.ranges().iter() {
+            for region in self.regions {
+                let (start, len) = match region.intersection(address, len) {
+                    Some(intersection) => intersection,
+                    None => continue,
+                };
+                let allowed = match (region.protection, write) {
+                    (Protection::ReadOnly, _) => false,
+                    (Protection::WriteOnce, Write::Erase { .. }) => false,
+                    (Protection::WriteOnce, Write::Program { .. }) =>
+                        is_erased(start, len).map_err(Error::Flash)?,
+                    (Protection::RecoveryOnly, _) => recovery_mode,
+                };
+                if !allowed {
+                    return Err(Error::Rejected(region.protection));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::protocol::flash::AddressMode;
+
+    const REGIONS: &[Region] = &[
+        Region { start: 0x0000, len: 0x1000, protection: Protection::ReadOnly },
+        Region { start: 0x1000, len: 0x1000, protection: Protection::WriteOnce },
+        Region { start: 0x8000, len: 0x8000, protection: Protection::RecoveryOnly },
+    ];
+
+    fn header(opcode: OpCode, address: Option<u32>) -> Header {
+        Header {
+            opcode,
+            address_mode: AddressMode::FourByte,
+            address,
+        }
+    }
+
+    fn never_called(_: u32, _: u32) -> Result<bool, ()> {
+        panic!("is_erased called");
+    }
+
+    #[test]
+    fn from_header() {
+        assert_eq!(Write::from_header(&header(OpCode::PageProgram, Some(0x1234)), 16, 0x10000),
+            Some(Write::Program { address: 0x1234, len: 16 }));
+        assert_eq!(Write::from_header(&header(OpCode::PageProgram, Some(0x1234)), 300, 0x10000),
+            Some(Write::Program { address: 0x1234, len: 0x100 }));
+        assert_eq!(Write::from_header(&header(OpCode::SectorErase, Some(0x1234)), 0, 0x10000),
+            Some(Write::Erase { address: 0x1000, len: 0x1000 }));
+        assert_eq!(Write::from_header(&header(OpCode::BlockErase64KB4B, Some(0x12345)), 0, 0x100000),
+            Some(Write::Erase { address: 0x10000, len: 0x10000 }));
+        assert_eq!(Write::from_header(&header(OpCode::ChipErase, None), 0, 0x10000),
+            Some(Write::Erase { address: 0, len: 0x10000 }));
+        assert_eq!(Write::from_header(&header(OpCode::NormalRead, Some(0)), 0, 0x10000), None);
+    }
+
+    #[test]
+    fn ranges() {
+        let write = Write::Program { address: 0x1210, len: 0x20 };
+        assert_eq!(write.ranges(), [(0x1210, 0x20), (0x1200, 0)]);
+        let write = Write::Program { address: 0x12f0, len: 0x20 };
+        assert_eq!(write.ranges(), [(0x12f0, 0x10), (0x1200, 0x10)]);
+        let write = Write::Program { address: 0x12f0, len: 0x100 };
+        assert_eq!(write.ranges(), [(0x12f0, 0x10), (0x1200, 0xf0)]);
+        let write = Write::Erase { address: 0x1000, len: 0x1000 };
+        assert_eq!(write.ranges(), [(0x1000, 0x1000), (0x1000, 0)]);
+    }
+
+    #[test]
+    fn unprotected() {
+        let policy = WritePolicy::new(REGIONS);
+        let write = Write::Program { address: 0x2000, len: 0x100 };
+        assert_eq!(policy.check(&write, false, never_called), Ok(()));
+        let write = Write::Erase { address: 0x2000, len: 0x6000 };
+        assert_eq!(policy.check(&write, false, never_called), Ok(()));
+    }
+
+    #[test]
+    fn read_only() {
+        let policy = WritePolicy::new(REGIONS);
+        let write = Write::Program { address: 0xff0, len: 0x20 };
+        assert_eq!(policy.check(&write, true, |_, _| Ok::<_, ()>(true)),
+            Err(Error::Rejected(Protection::ReadOnly)));
+        let write = Write::Erase { address: 0, len: 0x10000 };
+        assert_eq!(policy.check(&write, true, never_called),
+            Err(Error::Rejected(Protection::ReadOnly)));
+    }
+
+    #[test]
+    fn write_once() {
+        let policy = WritePolicy::new(REGIONS);
+        let write = Write::Program { address: 0x1ff0, len: 0x10 };
+        let mut checked = None;
+        assert_eq!(policy.check(&write, false, |address, len| {
+            checked = Some((address, len));
+            Ok::<_, ()>(true)
+        }), Ok(()));
+        assert_eq!(checked, Some((0x1ff0, 0x10)));
+
+        assert_eq!(policy.check(&write, false, |_, _| Ok::<_, ()>(false)),
+            Err(Error::Rejected(Protection::WriteOnce)));
+        assert_eq!(policy.check(&write, false, |_, _| Err(7)), Err(Error::Flash(7)));
+
+        let write = Write::Erase { address: 0x1000, len: 0x1000 };
+        assert_eq!(policy.check(&write, true, never_called),
+            Err(Error::Rejected(Protection::WriteOnce)));
+    }
+
+    #[test]
+    fn needs_erased_check() {
+        let policy = WritePolicy::new(REGIONS);
+        assert!(policy.needs_erased_check(&Write::Program { address: 0x1ff0, len: 0x10 }));
+        assert!(policy.needs_erased_check(&Write::Program { address: 0x10f0, len: 0x20 }));
+        assert!(!policy.needs_erased_check(&Write::Program { address: 0x2000, len: 0x100 }));
+        assert!(!policy.needs_erased_check(&Write::Program { address: 0xff0, len: 0x10 }));
+        assert!(!policy.needs_erased_check(&Write::Erase { address: 0x1000, len: 0x1000 }));
+    }
+
+    #[test]
+    fn wrapping_program() {
+        let policy = WritePolicy::new(REGIONS);
+
+        // The end of the page lies outside the WriteOnce region, the
+        // wrapped part at its start doesn't.
+        let write = Write::Program { address: 0x10f0, len: 0x20 };
+        let mut checked = None;
+        assert_eq!(policy.check(&write, false, |address, len| {
+            checked = Some((address, len));
+            Ok::<_, ()>(true)
+        }), Ok(()));
+        assert_eq!(checked, Some((0x1000, 0x10)));
+
+        // A program at the end of the last unprotected page before the
+        // ReadOnly region can't wrap into it, but one in its last page does.
+        let write = Write::Program { address: 0x7ff0, len: 0x20 };
+        assert_eq!(policy.check(&write, false, never_called), Ok(()));
+        let write = Write::Program { address: 0xff0, len: 0x20 };
+        assert_eq!(policy.check(&write, true, never_called),
+            Err(Error::Rejected(Protection::ReadOnly)));
+    }
+
+    #[test]
+    fn recovery_only() {
+        let policy = WritePolicy::new(REGIONS);
+        let write = Write::Erase { address: 0x8000, len: 0x8000 };
+        assert_eq!(policy.check(&write, false, never_called),
+            Err(Error::Rejected(Protection::RecoveryOnly)));
+        assert_eq!(policy.check(&write, true, never_called), Ok(()));
+    }
+
+    #[test]
+    fn alignment() {
+        assert!(WritePolicy::new(REGIONS).is_aligned());
+        let regions = &[
+            Region { start: 0x0000, len: 0x1000, protection: Protection::ReadOnly },
+            Region { start: 0x1000, len: 0x0100, protection: Protection::WriteOnce },
+        ];
+        assert!(!WritePolicy::new(regions).is_aligned());
+        let region = Region { start: 0x0100, len: 0x1000, protection: Protection::ReadOnly };
+        assert!(!region.is_aligned());
+    }
+
+    #[test]
+    fn intersection() {
+        let region = Region { start: 0xffff_f000, len: 0x1000, protection: Protection::ReadOnly };
+        assert_eq!(region.intersection(0xffff_fff0, 0x100), Some((0xffff_fff0, 0x10)));
+        assert_eq!(region.intersection(0, 0xffff_f000), None);
+        assert_eq!(region.intersection(0xffff_f800, 0), None);
+    }
+}
@@ -0,0 +1,688 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Firmware update messages.
+//!
+//! The SPI host updates the device firmware with `FirmwareUpdate` payloads
+//! sent through the mailbox. It begins an update with the length of the
+//! image, writes the image in chunks to the inactive flash bank, has the
+//! device verify the written image against a signed manifest and finally
+//! commits it. The device answers every request with its `UpdateStatus`.
+//!
+//! The manifest has the format described in `crate::manifest`. Its regions
+//! are offsets within the image, and must cover the image from start to end
+//! without gaps.
+
+use crate::io::Read;
+use crate::io::Write;
+use crate::manifest;
+use crate::manifest::ManifestReader;
+use crate::protocol::wire::FieldError;
+use crate::protocol::wire::FromWire;
+use crate::protocol::wire::FromWireError;
+use crate::protocol::wire::ToWire;
+use crate::protocol::wire::ToWireError;
+use crate::protocol::wire::WireEnum;
+
+/// The alignment of the chunks written to flash, in bytes.
+///
+/// Every chunk except the last one must be a multiple of this long. It is a
+/// power of two.
+pub const WRITE_ALIGNMENT: u32 = 4;
+
+wire_enum! {
+    /// A firmware update command.
+    pub enum Command: u8 {
+        /// Begin a new update.
+        Begin = 0x01,
+
+        /// Write a chunk of the image.
+        Write = 0x02,
+
+        /// Verify the written image against a signed manifest.
+        Verify = 0x03,
+
+        /// Commit the verified image.
+        Commit = 0x04,
+
+        /// Report the status of the update.
+        Status = 0x05,
+    }
+}
+
+/// A firmware update request.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Request<'a> {
+    /// Begin a new update, discarding any previous one.
+    Begin {
+        /// The length of the image, in bytes.
+        image_len: u32,
+    },
+
+    /// Write `data` at `offset` within the image.
+    Write {
+        /// The offset of the chunk within the image.
+        offset: u32,
+
+        /// The chunk.
+        data: &'a [u8],
+    },
+
+    /// Verify the written image against `manifest`.
+    Verify {
+        /// The signed manifest of the image.
+        manifest: &'a [u8],
+    },
+
+    /// Commit the verified image.
+    Commit,
+
+    /// Report the status of the update.
+    Status,
+}
+
+impl<'a> FromWire<'a> for Request<'a> {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let command_u8 = r.read_be::<u8>()?;
+        let command = Command::from_wire_value(command_u8)
+            .ok_or_else(|| FromWireError::UnknownValue(FieldError::new("command").with_value(command_u8).at(0)))?;
+        match command {
+            Command::Begin => {
+                let image_len = r.read_be::<u32>()?;
+                Ok(Request::Begin { image_len })
+            }
+            Command::Write => {
+                let offset = r.read_be::<u32>()?;
+                let data = r.read_bytes(r.remaining_data())?;
+                Ok(Request::Write { offset, data })
+            }
+            Command::Verify => {
+                let manifest = r.read_bytes(r.remaining_data())?;
+                Ok(Request::Verify { manifest })
+            }
+            Command::Commit => Ok(Request::Commit),
+            Command::Status => Ok(Request::Status),
+        }
+    }
+}
+
+impl<'a> ToWire for Request<'a> {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        match self {
+            Request::Begin { image_len } => {
+                w.write_be(Command::Begin.to_wire_value())?;
+                w.write_be(*image_len)?;
+            }
+            Request::Write { offset, data } => {
+                w.write_be(Command::Write.to_wire_value())?;
+                w.write_be(*offset)?;
+                w.write_bytes(data)?;
+            }
+            Request::Verify { manifest } => {
+                w.write_be(Command::Verify.to_wire_value())?;
+                w.write_bytes(manifest)?;
+            }
+            Request::Commit => w.write_be(Command::Commit.to_wire_value())?,
+            Request::Status => w.write_be(Command::Status.to_wire_value())?,
+        }
+        Ok(())
+    }
+}
+
+wire_enum! {
+    /// The state of a firmware update.
+    pub enum UpdateState: u8 {
+        /// No update is in progress.
+        Idle = 0x00,
+
+        /// The image is being written.
+        Receiving = 0x01,
+
+        /// The written image matches its signed manifest.
+        Verified = 0x02,
+
+        /// The image was committed.
+        Committed = 0x03,
+    }
+}
+
+wire_enum! {
+    /// The reason a firmware update request failed.
+    pub enum UpdateError: u8 {
+        /// The request is not valid in the current state.
+        InvalidState = 0x01,
+
+        /// The image doesn't fit into the update region.
+        ImageTooLarge = 0x02,
+
+        /// The chunk doesn't start where the previous one ended.
+        UnexpectedOffset = 0x03,
+
+        /// The chunk extends past the end of the image or is not a multiple
+        /// of `WRITE_ALIGNMENT` long.
+        InvalidLength = 0x04,
+
+        /// The digest of a region of the written image doesn't match the
+        /// manifest.
+        DigestMismatch = 0x05,
+
+        /// Accessing the flash failed.
+        Flash = 0x06,
+
+        /// The manifest is malformed or doesn't cover the whole image.
+        InvalidManifest = 0x07,
+
+        /// The manifest is not signed with the trusted key.
+        UntrustedKey = 0x08,
+
+        /// The signature of the manifest is invalid.
+        BadSignature = 0x09,
+    }
+}
+
+impl From<manifest::Failure> for UpdateError {
+    fn from(failure: manifest::Failure) -> Self {
+        match failure {
+            manifest::Failure::Invalid => UpdateError::InvalidManifest,
+            manifest::Failure::UntrustedKey => UpdateError::UntrustedKey,
+            manifest::Failure::BadSignature => UpdateError::BadSignature,
+            manifest::Failure::DigestMismatch => UpdateError::DigestMismatch,
+            manifest::Failure::Internal => UpdateError::Flash,
+        }
+    }
+}
+
+/// The status of a firmware update, which is the response to every request.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UpdateStatus {
+    /// The state of the update.
+    pub state: UpdateState,
+
+    /// The reason the last request failed, if it did.
+    pub error: Option<UpdateError>,
+
+    /// The length of the image, in bytes.
+    pub image_len: u32,
+
+    /// The number of bytes of the image written so far.
+    ///
+    /// This is the offset of the next chunk.
+    pub written: u32,
+}
+
+impl<'a> FromWire<'a> for UpdateStatus {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let state_u8 = r.read_be::<u8>()?;
+        let state = UpdateState::from_wire_value(state_u8)
+            .ok_or_else(|| FromWireError::UnknownValue(FieldError::new("state").with_value(state_u8).at(0)))?;
+        let error = match r.read_be::<u8>()? {
+            0 => None,
+            error_u8 => Some(UpdateError::from_wire_value(error_u8)
+                .ok_or_else(|| FromWireError::UnknownValue(FieldError::new("error").with_value(error_u8).at(1)))?),
+        };
+        let image_len = r.read_be::<u32>()?;
+        let written = r.read_be::<u32>()?;
+        Ok(Self {
+            state,
+            error,
+            image_len,
+            written,
+        })
+    }
+}
+
+impl ToWire for UpdateStatus {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        w.write_be(self.state.to_wire_value())?;
+        w.write_be(self.error.map_or(0, |error| error.to_wire_value()))?;
+        w.write_be(self.image_len)?;
+        w.write_be(self.written)?;
+        Ok(())
+    }
+}
+
+/// The device side of the firmware update state machine.
+///
+/// The flash is accessed through the functions passed to the transitions.
+/// If one of them fails, the update is aborted with `UpdateError::Flash`.
+pub struct FirmwareUpdate {
+    max_image_len: u32,
+    state: UpdateState,
+    error: Option<UpdateError>,
+    image_len: u32,
+    written: u32,
+}
+
+impl FirmwareUpdate {
+    /// Creates a new idle `FirmwareUpdate` for images of up to
+    /// `max_image_len` bytes.
+    pub fn new(max_image_len: u32) -> Self {
+        Self {
+            max_image_len,
+            state: UpdateState::Idle,
+            error: None,
+            image_len: 0,
+            written: 0,
+        }
+    }
+
+    /// Returns the current status.
+    pub fn status(&self) -> UpdateStatus {
+        UpdateStatus {
+            state: self.state,
+            error: self.error,
+            image_len: self.image_len,
+            written: self.written,
+        }
+    }
+
+    /// Processes `request` and returns the new status.
+    ///
+    /// * `erase(len)` erases the first `len` bytes of the update region.
+    /// * `write(offset, data)` writes `data` at `offset` within the update
+    ///   region.
+    /// * `verify(manifest)` verifies `manifest` with `manifest::verify`,
+    ///   measuring regions of the update region.
+    /// * `commit()` makes the verified image bootable.
+    pub fn process<E, Er, Wr, Ve, Co>(&mut self, request: &Request, erase: Er, write: Wr, verify: Ve, commit: Co)
+        -> UpdateStatus
+    where
+        Er: FnOnce(u32) -> Result<(), E>,
+        Wr: FnOnce(u32, &[u8]) -> Result<(), E>,
+        Ve: FnOnce(&[u8]) -> Result<(), manifest::Failure>,
+        Co: FnOnce() -> Result<(), E>,
+    {
+        let result = match *request {
+            Request::Begin { image_len } => self.begin(image_len, erase),
+            Request::Write { offset, data } => self.write(offset, data, write),
+            Request::Verify { manifest } => self.verify(manifest, verify),
+            Request::Commit => self.commit(commit),
+            Request::Status => Ok(()),
+        };
+        self.error = result.err();
+        self.status()
+    }
+
+    fn begin<E, Er>(&mut self, image_len: u32, erase: Er) -> Result<(), UpdateError>
+    where Er: FnOnce(u32) -> Result<(), E>
+    {
+        self.state = UpdateState::Idle;
+        self.image_len = 0;
+        self.written = 0;
+        if image_len > self.max_image_len {
+            return Err(UpdateError::ImageTooLarge);
+        }
+        erase(image_len).map_err(|_| UpdateError::Flash)?;
+        self.state = UpdateState::Receiving;
+        self.image_len = image_len;
+        Ok(())
+    }
+
+    fn write<E, Wr>(&mut self, offset: u32, data: &[u8], write: Wr) -> Result<(), UpdateError>
+    where Wr: FnOnce(u32, &[u8]) -> Result<(), E>
+    {
+        if self.state != UpdateState::Receiving {
+            return Err(UpdateError::InvalidState);
+        }
+        if offset != self.written {
+            return Err(UpdateError::UnexpectedOffset);
+        }
+        let end = offset as u64 + data.len() as u64;
+        if end > self.image_len as u64
+            || (end < self.image_len as u64 && data.len() as u32 & (WRITE_ALIGNMENT - 1) != 0) {
+            return Err(UpdateError::InvalidLength);
+        }
+        if write(offset, data).is_err() {
+            return self.abort(UpdateError::Flash);
+        }
+        self.written = end as u32;
+        Ok(())
+    }
+
+    fn verify<Ve>(&mut self, manifest: &[u8], verify: Ve) -> Result<(), UpdateError>
+    where Ve: FnOnce(&[u8]) -> Result<(), manifest::Failure>
+    {
+        if self.state != UpdateState::Receiving || self.written != self.image_len {
+            return Err(UpdateError::InvalidState);
+        }
+        let covered = match ManifestReader::new(manifest) {
+            Ok(reader) => covers(&reader, self.image_len),
+            Err(_) => false,
+        };
+        if !covered {
+            return self.abort(UpdateError::InvalidManifest);
+        }
+        match verify(manifest) {
+            Ok(()) => {
+                self.state = UpdateState::Verified;
+                Ok(())
+            }
+            Err(failure) => self.abort(failure.into()),
+        }
+    }
+
+    fn commit<E, Co>(&mut self, commit: Co) -> Result<(), UpdateError>
+    where Co: FnOnce() -> Result<(), E>
+    {
+        if self.state != UpdateState::Verified {
+            return Err(UpdateError::InvalidState);
+        }
+        if commit().is_err() {
+            return self.abort(UpdateError::Flash);
+        }
+        self.state = UpdateState::Committed;
+        Ok(())
+    }
+
+    fn abort(&mut self, error: UpdateError) -> Result<(), UpdateError> {
+        self.state = UpdateState::Idle;
+        Err(error)
+    }
+}
+
+/// Splits `data` written at `offset` of an image into the part within the
+/// first `header_len` bytes of the image and the rest.
+///
+/// Returns the part within the header, which starts at `offset`, followed by
+/// the offset and the data of the rest. The part within the header is empty
+/// if `offset` lies beyond the header.
+pub fn split_header(offset: u32, data: &[u8], header_len: u32) -> (&[u8], u32, &[u8]) {
+    let in_header = if offset < header_len {
+        core::cmp::min((header_len - offset) as usize, data.len())
+    } else {
+        0
+    };
+    let (header, rest) = data.split_at(in_header);
+    (header, offset + in_header as u32, rest)
+}
+
+/// Returns whether the regions of `manifest` follow each other without gaps
+/// and cover exactly the first `len` bytes.
+fn covers(manifest: &ManifestReader, len: u32) -> bool {
+    let mut end = 0u32;
+    for measurement in manifest.regions() {
+        if measurement.region.start != end {
+            return false;
+        }
+        end = match end.checked_add(measurement.region.len) {
+            Some(end) => end,
+            None => return false,
+        };
+    }
+    end == len
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::io::Cursor;
+    use crate::protocol::measurement::Measurement;
+    use crate::protocol::measurement::Region as ManifestRegion;
+
+    const MODULUS_LEN: usize = 256;
+
+    // Writes a manifest with the regions `(start, len)` and returns its
+    // length.
+    fn manifest(buf: &mut [u8], regions: &[(u32, u32)]) -> usize {
+        let mut cursor = Cursor::new(buf);
+        cursor.write_be(manifest::MAGIC).unwrap();
+        cursor.write_be(MODULUS_LEN as u16).unwrap();
+        cursor.write_be(regions.len() as u8).unwrap();
+        cursor.write_be(0u8).unwrap();
+        cursor.write_be(0x10001u32).unwrap();
+        cursor.write_bytes(&[0xa5; MODULUS_LEN]).unwrap();
+        for &(start, len) in regions {
+            Measurement { region: ManifestRegion { start, len }, digest: [0; 32] }
+                .to_wire(&mut cursor).unwrap();
+        }
+        cursor.write_bytes(&[0x5a; MODULUS_LEN]).unwrap();
+        cursor.consumed_len()
+    }
+
+    // A fake update region that records the flash operations.
+    struct Region {
+        data: [u8; 64],
+        committed: bool,
+    }
+
+    impl Region {
+        // Processes `request`, with `verify` standing in for
+        // `manifest::verify`.
+        fn process(&mut self, update: &mut FirmwareUpdate, request: &Request,
+            verify: Result<(), manifest::Failure>) -> UpdateStatus {
+            let Region { data, committed } = self;
+            let (data, committed) = (core::cell::RefCell::new(data), core::cell::RefCell::new(committed));
+            update.process(
+                request,
+                |len| {
+                    for byte in data.borrow_mut()[..len as usize].iter_mut() {
+                        *byte = 0xff;
+                    }
+                    Ok::<_, ()>(())
+                },
+                |offset, chunk| {
+                    let offset = offset as usize;
+                    data.borrow_mut()[offset..offset + chunk.len()].copy_from_slice(chunk);
+                    Ok(())
+                },
+                |_| verify,
+                || {
+                    **committed.borrow_mut() = true;
+                    Ok(())
+                })
+        }
+    }
+
+    #[test]
+    fn request_round_trip() {
+        let requests = [
+            Request::Begin { image_len: 0x1234 },
+            Request::Write { offset: 0x20, data: &[1, 2, 3] },
+            Request::Verify { manifest: &[4, 5] },
+            Request::Commit,
+            Request::Status,
+        ];
+        for request in requests.iter() {
+            let mut buf = [0u8; 64];
+            let mut cursor = Cursor::new(&mut buf);
+            request.to_wire(&mut cursor).unwrap();
+            let len = cursor.consumed_len();
+            assert_eq!(Request::from_wire(&buf[..len]), Ok(*request));
+        }
+
+        let mut buf = [0u8; 8];
+        Request::Write { offset: 0x20, data: &[1, 2, 3] }.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf, [0x02, 0x00, 0x00, 0x00, 0x20, 1, 2, 3]);
+        assert!(Request::from_wire(&[0x00][..]).is_err());
+    }
+
+    #[test]
+    fn status_round_trip() {
+        let status = UpdateStatus {
+            state: UpdateState::Receiving,
+            error: Some(UpdateError::UnexpectedOffset),
+            image_len: 0x100,
+            written: 0x20,
+        };
+        let mut buf = [0u8; 10];
+        status.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf, [0x01, 0x03, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x20]);
+        assert_eq!(UpdateStatus::from_wire(&buf[..]), Ok(status));
+    }
+
+    #[test]
+    fn update() {
+        let mut region = Region { data: [0; 64], committed: false };
+        let mut update = FirmwareUpdate::new(64);
+        let mut buf = [0u8; manifest::MAX_MANIFEST_LEN];
+        let len = manifest(&mut buf, &[(0, 16), (16, 24)]);
+        let verify = Request::Verify { manifest: &buf[..len] };
+
+        let status = region.process(&mut update, &Request::Begin { image_len: 40 }, Ok(()));
+        assert_eq!(status, UpdateStatus { state: UpdateState::Receiving, error: None, image_len: 40, written: 0 });
+        assert_eq!(region.data[..40], [0xff; 40][..]);
+
+        let status = region.process(&mut update, &Request::Write { offset: 0, data: &[0xa5; 16] }, Ok(()));
+        assert_eq!((status.error, status.written), (None, 16));
+        let status = region.process(&mut update, &Request::Write { offset: 0, data: &[0xa5; 16] }, Ok(()));
+        assert_eq!((status.error, status.written), (Some(UpdateError::UnexpectedOffset), 16));
+        let status = region.process(&mut update, &verify, Ok(()));
+        assert_eq!(status.error, Some(UpdateError::InvalidState));
+        let status = region.process(&mut update, &Request::Write { offset: 16, data: &[0xa5; 15] }, Ok(()));
+        assert_eq!((status.error, status.written), (Some(UpdateError::InvalidLength), 16));
+        let status = region.process(&mut update, &Request::Write { offset: 16, data: &[0xa5; 16] }, Ok(()));
+        assert_eq!((status.error, status.written), (None, 32));
+        let status = region.process(&mut update, &Request::Write { offset: 32, data: &[0x5a; 9] }, Ok(()));
+        assert_eq!((status.error, status.written), (Some(UpdateError::InvalidLength), 32));
+
+        // The last chunk may be unaligned.
+        let status = region.process(&mut update, &Request::Write { offset: 32, data: &[0x5a; 8] }, Ok(()));
+        assert_eq!((status.error, status.written), (None, 40));
+
+        let status = region.process(&mut update, &Request::Commit, Ok(()));
+        assert_eq!(status.error, Some(UpdateError::InvalidState));
+        assert!(!region.committed);
+        let status = region.process(&mut update, &verify, Ok(()));
+        assert_eq!((status.state, status.error), (UpdateState::Verified, None));
+        let status = region.process(&mut update, &Request::Commit, Ok(()));
+        assert_eq!((status.state, status.error), (UpdateState::Committed, None));
+        assert!(region.committed);
+    }
+
+    #[test]
+    fn split_header_writes() {
+        const HEADER_LEN: usize = 1024;
+        const IMAGE_LEN: usize = 5 * 1024 + 3;
+
+        let mut image = [0u8; IMAGE_LEN];
+        for (i, byte) in image.iter_mut().enumerate() {
+            *byte = (i % 251) as u8;
+        }
+        let mut header = [0xffu8; HEADER_LEN];
+        let mut flash = [0xffu8; IMAGE_LEN];
+        let mut update = FirmwareUpdate::new(IMAGE_LEN as u32);
+        update.process(&Request::Begin { image_len: IMAGE_LEN as u32 },
+            |_| Ok::<_, ()>(()), |_, _| Ok(()), |_| Ok(()), || Ok(()));
+
+        // Chunks that end before, at, across and beyond the end of the
+        // header.
+        for chunk in image.chunks(700) {
+            let offset = update.status().written;
+            let status = update.process(&Request::Write { offset, data: chunk },
+                |_| Ok(()),
+                |offset, data| {
+                    let (in_header, rest_offset, rest) = split_header(offset, data, HEADER_LEN as u32);
+                    if !in_header.is_empty() {
+                        let offset = offset as usize;
+                        header[offset..offset + in_header.len()].copy_from_slice(in_header);
+                    }
+                    let rest_offset = rest_offset as usize;
+                    flash[rest_offset..rest_offset + rest.len()].copy_from_slice(rest);
+                    Ok::<_, ()>(())
+                },
+                |_| Ok(()), || Ok(()));
+            assert_eq!(status.error, None);
+        }
+        assert_eq!(update.status().written, IMAGE_LEN as u32);
+        assert_eq!(header[..], image[..HEADER_LEN]);
+        assert_eq!(flash[..HEADER_LEN], [0xff; HEADER_LEN][..]);
+        assert_eq!(flash[HEADER_LEN..], image[HEADER_LEN..]);
+
+        assert_eq!(split_header(1020, &[1; 8], 1024), (&[1; 4][..], 1024, &[1; 4][..]));
+        assert_eq!(split_header(2048, &[1; 8], 1024), (&[][..], 2048, &[1; 8][..]));
+        assert_eq!(split_header(0, &[1; 8], 1024), (&[1; 8][..], 8, &[][..]));
+    }
+
+    #[test]
+    fn manifest_coverage() {
+        let mut update = FirmwareUpdate::new(64);
+        let mut region = Region { data: [0; 64], committed: false };
+        let mut buf = [0u8; manifest::MAX_MANIFEST_LEN];
+
+        // A gap, an overlap, a short and a long manifest.
+        let cases: &[&[(u32, u32)]] = &[
+            &[(0, 16), (20, 20)],
+            &[(0, 16), (8, 32)],
+            &[(0, 32)],
+            &[(0, 48)],
+            &[(0, 40), (40, u32::MAX)],
+            &[],
+        ];
+        for regions in cases {
+            let len = manifest(&mut buf, regions);
+            region.process(&mut update, &Request::Begin { image_len: 40 }, Ok(()));
+            region.process(&mut update, &Request::Write { offset: 0, data: &[0; 40] }, Ok(()));
+            let status = region.process(&mut update, &Request::Verify { manifest: &buf[..len] }, Ok(()));
+            assert_eq!((status.state, status.error), (UpdateState::Idle, Some(UpdateError::InvalidManifest)),
+                "{:?}", regions);
+        }
+
+        // Not a manifest at all.
+        region.process(&mut update, &Request::Begin { image_len: 40 }, Ok(()));
+        region.process(&mut update, &Request::Write { offset: 0, data: &[0; 40] }, Ok(()));
+        let status = region.process(&mut update, &Request::Verify { manifest: &[0xff; 16] }, Ok(()));
+        assert_eq!(status.error, Some(UpdateError::InvalidManifest));
+    }
+
+    #[test]
+    fn failures() {
+        let mut region = Region { data: [0; 64], committed: false };
+        let mut update = FirmwareUpdate::new(64);
+        let mut buf = [0u8; manifest::MAX_MANIFEST_LEN];
+        let len = manifest(&mut buf, &[(0, 32)]);
+        let verify = Request::Verify { manifest: &buf[..len] };
+
+        let status = region.process(&mut update, &Request::Begin { image_len: 65 }, Ok(()));
+        assert_eq!((status.state, status.error), (UpdateState::Idle, Some(UpdateError::ImageTooLarge)));
+        let status = region.process(&mut update, &Request::Write { offset: 0, data: &[0; 4] }, Ok(()));
+        assert_eq!(status.error, Some(UpdateError::InvalidState));
+
+        let failures = [
+            (manifest::Failure::UntrustedKey, UpdateError::UntrustedKey),
+            (manifest::Failure::BadSignature, UpdateError::BadSignature),
+            (manifest::Failure::DigestMismatch, UpdateError::DigestMismatch),
+            (manifest::Failure::Internal, UpdateError::Flash),
+        ];
+        for &(failure, error) in failures.iter() {
+            region.process(&mut update, &Request::Begin { image_len: 32 }, Ok(()));
+            region.process(&mut update, &Request::Write { offset: 0, data: &[0x5a; 32] }, Ok(()));
+            let status = region.process(&mut update, &verify, Err(failure));
+            assert_eq!((status.state, status.error), (UpdateState::Idle, Some(error)));
+        }
+
+        let status = update.process(&Request::Begin { image_len: 32 },
+            |_| Err(()), |_, _| Ok(()), |_| Ok(()), || Ok(()));
+        assert_eq!((status.state, status.error), (UpdateState::Idle, Some(UpdateError::Flash)));
+
+        region.process(&mut update, &Request::Begin { image_len: 32 }, Ok(()));
+        let status = update.process(&Request::Write { offset: 0, data: &[0; 4] },
+            |_| Ok(()), |_, _| Err(()), |_| Ok(()), || Ok(()));
+        assert_eq!((status.state, status.error), (UpdateState::Idle, Some(UpdateError::Flash)));
+
+        region.process(&mut update, &Request::Begin { image_len: 32 }, Ok(()));
+        region.process(&mut update, &Request::Write { offset: 0, data: &[0x5a; 32] }, Ok(()));
+        region.process(&mut update, &verify, Ok(()));
+        let status = update.process(&Request::Commit,
+            |_| Ok(()), |_, _| Ok(()), |_| Ok(()), || Err(()));
+        assert_eq!((status.state, status.error), (UpdateState::Idle, Some(UpdateError::Flash)));
+        assert!(!region.committed);
+
+        // The status request clears the error.
+        let status = region.process(&mut update, &Request::Status, Ok(()));
+        assert_eq!((status.state, status.error), (UpdateState::Idle, None));
+    }
+}
@@ -19,14 +19,16 @@
 use crate::io::BeInt;
 use crate::io::Read;
 use crate::io::Write;
-use crate::protocol::wire::FromWireError;
+use crate::protocol::wire::FieldError;
 use crate::protocol::wire::FromWire;
+use crate::protocol::wire::FromWireError;
 use crate::protocol::wire::ToWireError;
 use crate::protocol::wire::ToWire;
 use crate::protocol::wire::WireEnum;
 
 use core::convert::Into;
 use core::convert::TryFrom;
+use core::ops::Range;
 use core::result::Result;
 
 /// SPI flash address modes.
@@ -95,14 +97,23 @@ wire_enum! {
         /// Must be implemented in software. HW sets BUSY bit.
         SectorErase = 0x20,
 
+        /// Similar to SectorErase but uses explicit 4 byte addressing.
+        SectorErase4B = 0x21,
+
         /// Clears bits of a particular 32KB block to '1'.
         /// Must be implemented in software. HW sets BUSY bit.
         BlockErase32KB = 0x52,
 
+        /// Similar to BlockErase32KB but uses explicit 4 byte addressing.
+        BlockErase32KB4B = 0x5c,
+
         /// Clears bits of a particular 64KB block to '1'.
         /// Must be implemented in software. HW sets BUSY bit.
         BlockErase64KB = 0xd8,
 
+        /// Similar to BlockErase64KB but uses explicit 4 byte addressing.
+        BlockErase64KB4B = 0xdc,
+
         /// Clears all bits to '1'.
         /// Must be implemented in software. HW sets BUSY bit.
         ChipErase = 0xc7,
@@ -114,6 +125,21 @@ wire_enum! {
         /// Must be implemented in software. HW sets BUSY bit.
         PageProgram = 0x02,
 
+        /// Similar to PageProgram but uses explicit 4 byte addressing.
+        PageProgram4B = 0x12,
+
+        /// Similar to PageProgram with data input on four lines.
+        QuadPageProgram = 0x32,
+
+        /// Similar to QuadPageProgram but uses explicit 4 byte addressing.
+        QuadPageProgram4B = 0x34,
+
+        /// Similar to PageProgram with address and data input on four lines.
+        QuadIoPageProgram = 0x38,
+
+        /// Similar to QuadIoPageProgram but uses explicit 4 byte addressing.
+        QuadIoPageProgram4B = 0x3e,
+
         ////////////////////////////////////////////////////////////
         // ID commands
 
@@ -132,6 +158,9 @@ wire_enum! {
         /// mode.
         NormalRead = 0x03,
 
+        /// Similar to NormalRead but uses explicit 4 byte addressing.
+        NormalRead4B = 0x13,
+
         /// Retrieves data. The behavior of this command depends on the selected
         /// mode. Fast read includes a 1 byte delay after retrieving the last
         /// bit of the addrees before the first bit of data is delivered.
@@ -143,6 +172,27 @@ wire_enum! {
         /// Similar to FastRead with output on both MISO and MOSI.
         FastReadDualOutput = 0x3b,
 
+        /// Similar to FastReadDualOutput but uses explicit 4 byte addressing.
+        FastReadDualOutput4B = 0x3c,
+
+        /// Similar to FastRead with address and output on both MISO and MOSI.
+        FastReadDualIo = 0xbb,
+
+        /// Similar to FastReadDualIo but uses explicit 4 byte addressing.
+        FastReadDualIo4B = 0xbc,
+
+        /// Similar to FastRead with output on four lines.
+        FastReadQuadOutput = 0x6b,
+
+        /// Similar to FastReadQuadOutput but uses explicit 4 byte addressing.
+        FastReadQuadOutput4B = 0x6c,
+
+        /// Similar to FastRead with address and output on four lines.
+        FastReadQuadIo = 0xeb,
+
+        /// Similar to FastReadQuadIo but uses explicit 4 byte addressing.
+        FastReadQuadIo4B = 0xec,
+
         ////////////////////////////////////////////////////////////
         // Address mode commands
 
@@ -156,52 +206,192 @@ wire_enum! {
     }
 }
 
+/// The width of the address following an op code.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AddressWidth {
+    /// The op code has no address.
+    None,
+
+    /// The address width is selected by the current address mode.
+    AddressMode,
+
+    /// The address is always represented by 3 bytes.
+    ThreeByte,
+
+    /// The address is always represented by 4 bytes.
+    FourByte,
+}
+
+/// The direction of the data phase following an op code.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DataDirection {
+    /// The op code has no data.
+    None,
+
+    /// Data is sent by the SPI host.
+    HostToDevice,
+
+    /// Data is sent by the SPI device.
+    DeviceToHost,
+}
+
+/// Describes the phases of a transaction for an op code.
+///
+/// Every transaction starts with the op code on a single line, followed by
+/// the address, the mode bits, the dummy cycles and finally the data.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct OpCodeDescriptor {
+    /// The width of the address.
+    pub address_width: AddressWidth,
+
+    /// The number of lines used for the address, mode bits and dummy cycles.
+    pub address_lines: u8,
+
+    /// The number of mode bits following the address.
+    pub mode_bits: u8,
+
+    /// The number of dummy cycles following the mode bits.
+    pub dummy_cycles: u8,
+
+    /// The direction of the data.
+    pub data_direction: DataDirection,
+
+    /// The number of lines used for the data.
+    pub data_lines: u8,
+}
+
+impl OpCodeDescriptor {
+    const fn new(address_width: AddressWidth, address_lines: u8, mode_bits: u8, dummy_cycles: u8,
+        data_direction: DataDirection, data_lines: u8) -> Self {
+        Self {
+            address_width,
+            address_lines,
+            mode_bits,
+            dummy_cycles,
+            data_direction,
+            data_lines,
+        }
+    }
+
+    /// Returns the number of bytes taken up by mode bits and dummy cycles.
+    pub fn dummy_len(&self) -> usize {
+        (self.mode_bits as usize + self.dummy_cycles as usize * self.address_lines as usize) / 8
+    }
+}
+
 impl<'a> OpCode {
-    /// Returns true iff the OpCode requires an address.
-    pub fn has_address(&self) -> bool {
+    /// Returns the descriptor for the OpCode.
+    pub fn descriptor(&self) -> OpCodeDescriptor {
+        use AddressWidth as A;
+        use DataDirection as D;
         match self {
-            Self::SectorErase => true,
-            Self::BlockErase32KB => true,
-            Self::BlockErase64KB => true,
-            Self::PageProgram => true,
-            Self::ReadSfdp => true,
-            Self::NormalRead => true,
-            Self::FastRead => true,
-            Self::FastRead4B => true,
-            Self::FastReadDualOutput => true,
-            _ => false,
+            Self::WriteStatusRegister => OpCodeDescriptor::new(A::None, 1, 0, 0, D::HostToDevice, 1),
+            Self::ReadStatusRegister => OpCodeDescriptor::new(A::None, 1, 0, 0, D::DeviceToHost, 1),
+            Self::SectorErase => OpCodeDescriptor::new(A::AddressMode, 1, 0, 0, D::None, 0),
+            Self::SectorErase4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 0, D::None, 0),
+            Self::BlockErase32KB => OpCodeDescriptor::new(A::AddressMode, 1, 0, 0, D::None, 0),
+            Self::BlockErase32KB4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 0, D::None, 0),
+            Self::BlockErase64KB => OpCodeDescriptor::new(A::AddressMode, 1, 0, 0, D::None, 0),
+            Self::BlockErase64KB4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 0, D::None, 0),
+            Self::PageProgram => OpCodeDescriptor::new(A::AddressMode, 1, 0, 0, D::HostToDevice, 1),
+            Self::PageProgram4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 0, D::HostToDevice, 1),
+            Self::QuadPageProgram => OpCodeDescriptor::new(A::AddressMode, 1, 0, 0, D::HostToDevice, 4),
+            Self::QuadPageProgram4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 0, D::HostToDevice, 4),
+            Self::QuadIoPageProgram => OpCodeDescriptor::new(A::AddressMode, 4, 0, 0, D::HostToDevice, 4),
+            Self::QuadIoPageProgram4B => OpCodeDescriptor::new(A::FourByte, 4, 0, 0, D::HostToDevice, 4),
+            Self::ReadJedec => OpCodeDescriptor::new(A::None, 1, 0, 0, D::DeviceToHost, 1),
+            // SFDP always uses 3 byte addressing and 8 dummy cycles (JESD216).
+            Self::ReadSfdp => OpCodeDescriptor::new(A::ThreeByte, 1, 0, 8, D::DeviceToHost, 1),
+            Self::NormalRead => OpCodeDescriptor::new(A::AddressMode, 1, 0, 0, D::DeviceToHost, 1),
+            Self::NormalRead4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 0, D::DeviceToHost, 1),
+            Self::FastRead => OpCodeDescriptor::new(A::AddressMode, 1, 0, 8, D::DeviceToHost, 1),
+            Self::FastRead4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 8, D::DeviceToHost, 1),
+            Self::FastReadDualOutput => OpCodeDescriptor::new(A::AddressMode, 1, 0, 8, D::DeviceToHost, 2),
+            Self::FastReadDualOutput4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 8, D::DeviceToHost, 2),
+            Self::FastReadDualIo => OpCodeDescriptor::new(A::AddressMode, 2, 8, 0, D::DeviceToHost, 2),
+            Self::FastReadDualIo4B => OpCodeDescriptor::new(A::FourByte, 2, 8, 0, D::DeviceToHost, 2),
+            Self::FastReadQuadOutput => OpCodeDescriptor::new(A::AddressMode, 1, 0, 8, D::DeviceToHost, 4),
+            Self::FastReadQuadOutput4B => OpCodeDescriptor::new(A::FourByte, 1, 0, 8, D::DeviceToHost, 4),
+            Self::FastReadQuadIo => OpCodeDescriptor::new(A::AddressMode, 4, 8, 4, D::DeviceToHost, 4),
+            Self::FastReadQuadIo4B => OpCodeDescriptor::new(A::FourByte, 4, 8, 4, D::DeviceToHost, 4),
+            _ => OpCodeDescriptor::new(A::None, 1, 0, 0, D::None, 0),
         }
     }
 
+    /// Returns true iff the OpCode requires an address.
+    pub fn has_address(&self) -> bool {
+        self.descriptor().address_width != AddressWidth::None
+    }
+
     /// Returns true iff the OpCode requires a dummy byte.
     pub fn has_dummy_byte(&self) -> bool {
-        match self {
-            Self::FastRead => true,
-            Self::FastRead4B => true,
-            Self::FastReadDualOutput => true,
-            _ => false,
+        self.dummy_len() > 0
+    }
+
+    /// Returns the number of bytes taken up by mode bits and dummy cycles.
+    pub fn dummy_len(&self) -> usize {
+        self.descriptor().dummy_len()
+    }
+
+    /// Returns the number of address bytes for the OpCode in `address_mode`.
+    pub fn address_len(&self, address_mode: AddressMode) -> usize {
+        match self.descriptor().address_width {
+            AddressWidth::None => 0,
+            AddressWidth::ThreeByte => 3,
+            AddressWidth::FourByte => 4,
+            AddressWidth::AddressMode => match address_mode {
+                AddressMode::ThreeByte => 3,
+                AddressMode::FourByte => 4,
+            },
         }
     }
 
     /// Returns true iff the OpCode requires data.
     pub fn has_data(&self) -> bool {
+        self.descriptor().data_direction == DataDirection::HostToDevice
+    }
+
+    /// Returns the number of bytes erased by the OpCode, if it erases a
+    /// fixed size sector or block.
+    pub fn erase_len(&self) -> Option<u32> {
         match self {
-            Self::PageProgram => true,
-            _ => false,
+            Self::SectorErase | Self::SectorErase4B => Some(4 * 1024),
+            Self::BlockErase32KB | Self::BlockErase32KB4B => Some(32 * 1024),
+            Self::BlockErase64KB | Self::BlockErase64KB4B => Some(64 * 1024),
+            _ => None,
         }
     }
 
+    /// Returns true iff the OpCode programs a page.
+    ///
+    /// The data of such OpCodes wraps around at the end of a page of
+    /// `PAGE_SIZE` bytes.
+    pub fn is_page_program(&self) -> bool {
+        matches!(self,
+            Self::PageProgram | Self::PageProgram4B
+                | Self::QuadPageProgram | Self::QuadPageProgram4B
+                | Self::QuadIoPageProgram | Self::QuadIoPageProgram4B)
+    }
+
     /// Returns true iff the OpCode requires for the BUSY bit to clear.
     pub fn wait_busy_clear(&self) -> bool {
         match self {
             Self::WriteSuspend => true,
             Self::WriteResume => true,
             Self::SectorErase => true,
+            Self::SectorErase4B => true,
             Self::BlockErase32KB => true,
+            Self::BlockErase32KB4B => true,
             Self::BlockErase64KB => true,
+            Self::BlockErase64KB4B => true,
             Self::ChipErase => true,
             Self::ChipErase2 => true,
             Self::PageProgram => true,
+            Self::PageProgram4B => true,
+            Self::QuadPageProgram => true,
+            Self::QuadPageProgram4B => true,
+            Self::QuadIoPageProgram => true,
+            Self::QuadIoPageProgram4B => true,
             _ => false,
         }
     }
@@ -241,40 +431,79 @@ impl Address for u32 {
 
 /// A parsed SPI flash protocol header.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
-pub struct Header<AddrType> {
+pub struct Header {
     /// The SPI op code
     pub opcode: OpCode,
 
+    /// The address mode the header was received or is sent in.
+    ///
+    /// This only affects op codes whose address width depends on the address
+    /// mode. Op codes with an intrinsic address width ignore it.
+    pub address_mode: AddressMode,
+
     /// The address.
     ///
     /// Note that not all SPI op codes require an address.
-    pub address: Option<AddrType>,
+    pub address: Option<u32>,
 }
 
-impl<'a, AddrType> Header<AddrType>
-where AddrType: Address {
+impl Header {
+    /// Returns the header of a normal read of `len` bytes at `address` from a
+    /// device in `address_mode`.
+    ///
+    /// If the read doesn't fit into the 3-byte address space of a device in
+    /// 3-byte address mode, it uses `NormalRead4B`, which takes a 4-byte
+    /// address regardless of the address mode.
+    pub fn read(address_mode: AddressMode, address: u32, len: u32) -> Self {
+        let opcode = match address_mode {
+            AddressMode::ThreeByte if address as u64 + len as u64 > 1u64 << 24 => OpCode::NormalRead4B,
+            _ => OpCode::NormalRead,
+        };
+        Self {
+            opcode,
+            address_mode,
+            address: Some(address),
+        }
+    }
+
     /// Get the address as an Option<u32>
     pub fn get_address(&self) -> Option<u32> {
-        self.address.map(|val| val.into())
+        self.address
+    }
+
+    /// Returns the number of address bytes on the wire.
+    pub fn address_len(&self) -> usize {
+        self.opcode.address_len(self.address_mode)
+    }
+
+    /// Returns the number of bytes on the wire, including mode bits and
+    /// dummy cycles.
+    pub fn wire_len(&self) -> usize {
+        1 + self.address_len() + self.opcode.dummy_len()
     }
 
     /// Deserializes a `Header` from `r`.
-    pub fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+    ///
+    /// `address_mode` is the current address mode of the SPI device. It is
+    /// only used for op codes that don't have an intrinsic address width.
+    pub fn from_wire<'a, R: Read<'a>>(mut r: R, address_mode: AddressMode) -> Result<Self, FromWireError> {
         let opcode_u8 = r.read_be::<u8>()?;
-        let opcode = OpCode::from_wire_value(opcode_u8).ok_or(FromWireError::OutOfRange)?;
+        let opcode = OpCode::from_wire_value(opcode_u8)
+            .ok_or_else(|| FromWireError::UnknownValue(FieldError::new("opcode").with_value(opcode_u8).at(0)))?;
 
-        let address = match opcode.has_address() {
-            true => Some(r.read_be::<AddrType>()?),
-            false => None,
+        let address = match opcode.address_len(address_mode) {
+            0 => None,
+            3 => Some(r.read_be::<ux::u24>()?.into()),
+            _ => Some(r.read_be::<u32>()?),
         };
 
-        if opcode.has_dummy_byte() {
-            // We don't actually care about the value, we just need to consume it.
-            let _ = r.read_be::<u8>()?;
-        }
+        // We don't actually care about the mode bits and dummy cycles, we
+        // just need to consume them.
+        let _ = r.read_bytes(opcode.dummy_len())?;
 
         Ok(Self {
             opcode,
+            address_mode,
             address,
         })
     }
@@ -282,13 +511,17 @@ where AddrType: Address {
     /// Serializes `self` into `w`.
     pub fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
         w.write_be(self.opcode.to_wire_value())?;
-        if self.opcode.has_address() {
-            if ! self.address.is_some() {
-                return Err(ToWireError::InvalidData)
+        match (self.address_len(), self.address) {
+            (0, _) => {}
+            (3, Some(address)) => {
+                let address = <ux::u24 as Address>::try_from(address)
+                    .map_err(|_| ToWireError::InvalidData(FieldError::new("address").with_value(address).at(1)))?;
+                w.write_be(address)?;
             }
-            w.write_be(self.address.unwrap())?;
+            (_, Some(address)) => w.write_be(address)?,
+            (_, None) => return Err(ToWireError::InvalidData(FieldError::new("address").at(1))),
         }
-        if self.opcode.has_dummy_byte() {
+        for _ in 0..self.opcode.dummy_len() {
             w.write_be(DUMMY_BYTE_VALUE)?;
         }
 
@@ -296,27 +529,748 @@ where AddrType: Address {
     }
 }
 
-impl<'a> FromWire<'a> for Header<ux::u24> {
-    fn from_wire<R: Read<'a>>(r: R) -> Result<Self, FromWireError> {
-        Self::from_wire(r)
+impl ToWire for Header {
+    fn to_wire<W: Write>(&self, w: W) -> Result<(), ToWireError> {
+        self.to_wire(w)
     }
 }
 
-impl ToWire for Header<ux::u24> {
-    fn to_wire<W: Write>(&self, w: W) -> Result<(), ToWireError> {
-        self.to_wire(w)
+/// The size of a flash page, which is the largest amount of data that can
+/// be programmed by a single page program command.
+pub const PAGE_SIZE: u32 = 256;
+
+/// A single SPI transaction of a `Transfer`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Chunk {
+    /// The header of the transaction, with the address advanced to the
+    /// start of its data.
+    pub header: Header,
+
+    /// The offset of the transaction's data in the data of the transfer.
+    pub offset: usize,
+
+    /// The number of data bytes in the transaction.
+    pub len: usize,
+}
+
+/// Splits a command with an arbitrary amount of data into SPI transactions
+/// that fit into a buffer of `max_transaction_len` bytes.
+///
+/// Every transaction repeats the header of the command, with the address
+/// advanced by the data of the previous transactions. The data of page
+/// program commands is additionally split at page boundaries, so that it
+/// doesn't wrap around within a page. Commands without data result in a
+/// single transaction.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Transfer {
+    header: Header,
+    data_len: usize,
+    max_data_len: usize,
+    offset: usize,
+    done: bool,
+}
+
+impl Transfer {
+    /// Creates a transfer of `data_len` bytes of data for the command with
+    /// `header`.
+    ///
+    /// Fails if the header doesn't leave room for data in a transaction, if
+    /// the data must be split but the command has no address, or if the data
+    /// extends past the end of the address space of the header, which is
+    /// 2^24 bytes for 3-byte addresses.
+    pub fn new(header: Header, data_len: usize, max_transaction_len: usize) -> Result<Self, ToWireError> {
+        let max_data_len = max_transaction_len.checked_sub(header.wire_len())
+            .filter(|&max_data_len| max_data_len > 0 || data_len == 0)
+            .ok_or_else(|| ToWireError::InvalidData(FieldError::new("max_transaction_len")
+                .with_value(max_transaction_len as u32)))?;
+        match header.address {
+            None if data_len > max_data_len => {
+                return Err(ToWireError::InvalidData(FieldError::new("data_len").with_value(data_len as u32)));
+            }
+            Some(address) if header.address_len() > 0
+                && address as u64 + data_len as u64 > 1u64 << (8 * header.address_len()) => {
+                return Err(ToWireError::InvalidData(FieldError::new("data_len").with_value(data_len as u32)));
+            }
+            _ => {}
+        }
+        Ok(Self {
+            header,
+            data_len,
+            max_data_len,
+            offset: 0,
+            done: false,
+        })
+    }
+
+    /// Returns the header of the command.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the total number of data bytes.
+    pub fn data_len(&self) -> usize {
+        self.data_len
     }
 }
 
-impl<'a> FromWire<'a> for Header<u32> {
-    fn from_wire<R: Read<'a>>(r: R) -> Result<Self, FromWireError> {
-        Self::from_wire(r)
+impl Iterator for Transfer {
+    type Item = Chunk;
+
+    fn next(&mut self) -> Option<Chunk> {
+        if self.done {
+            return None;
+        }
+        let mut header = self.header;
+        let mut len = core::cmp::min(self.data_len - self.offset, self.max_data_len);
+        if let Some(address) = header.address {
+            // Can't overflow: `new` checked that the data ends within the
+            // address space of the header.
+            let address = address.wrapping_add(self.offset as u32);
+            header.address = Some(address);
+            if header.opcode.is_page_program() {
+                len = core::cmp::min(len, (PAGE_SIZE - address % PAGE_SIZE) as usize);
+            }
+        }
+        let chunk = Chunk {
+            header,
+            offset: self.offset,
+            len,
+        };
+        self.offset += len;
+        self.done = self.offset == self.data_len;
+        Some(chunk)
     }
 }
 
-impl ToWire for Header<u32> {
-    fn to_wire<W: Write>(&self, w: W) -> Result<(), ToWireError> {
-        self.to_wire(w)
+const STATUS_WIP: u16 = 1 << 0;
+const STATUS_WEL: u16 = 1 << 1;
+const STATUS_BP_SHIFT: u16 = 2;
+const STATUS_BP_MASK: u16 = 0xf;
+const STATUS_TB: u16 = 1 << 6;
+const STATUS_SEC: u16 = 1 << 7;
+const STATUS_SRP: u16 = 1 << 8;
+
+/// The size of the unit protected by each step of BP3-BP0 if SEC is clear.
+const PROTECT_BLOCK_SIZE: u64 = 64 * 1024;
+
+/// The size of the unit protected by each step of BP3-BP0 if SEC is set.
+const PROTECT_SECTOR_SIZE: u64 = 4 * 1024;
+
+/// The contents of the SPI flash status register.
+///
+/// The low byte is status register 1, as returned by
+/// `OpCode::ReadStatusRegister`. SRP lives in bit 0 of status register 2,
+/// which is written by an `OpCode::WriteStatusRegister` with two data bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct StatusRegister {
+    /// WIP: A program, erase or status register write is in progress.
+    pub write_in_progress: bool,
+
+    /// WEL: Program, erase and status register writes are enabled.
+    pub write_enable_latch: bool,
+
+    /// BP3-BP0: The size of the protected region.
+    ///
+    /// Only the low 4 bits are used. See `protected_range`.
+    pub block_protect: u8,
+
+    /// TB: The protected region is at the bottom instead of the top of the
+    /// flash.
+    pub top_bottom: bool,
+
+    /// SEC: BP3-BP0 count 4 KiB sectors instead of 64 KiB blocks.
+    pub sector_protect: bool,
+
+    /// SRP: The status register can't be written while WP# is asserted.
+    pub status_register_protect: bool,
+}
+
+impl StatusRegister {
+    /// Decodes the status register from its bits.
+    ///
+    /// Unknown bits are ignored.
+    pub const fn from_bits(bits: u16) -> Self {
+        Self {
+            write_in_progress: bits & STATUS_WIP != 0,
+            write_enable_latch: bits & STATUS_WEL != 0,
+            block_protect: ((bits >> STATUS_BP_SHIFT) & STATUS_BP_MASK) as u8,
+            top_bottom: bits & STATUS_TB != 0,
+            sector_protect: bits & STATUS_SEC != 0,
+            status_register_protect: bits & STATUS_SRP != 0,
+        }
+    }
+
+    /// Encodes the status register into its bits.
+    pub fn bits(&self) -> u16 {
+        let flag = |set: bool, bit: u16| if set { bit } else { 0 };
+        flag(self.write_in_progress, STATUS_WIP)
+            | flag(self.write_enable_latch, STATUS_WEL)
+            | (self.block_protect as u16 & STATUS_BP_MASK) << STATUS_BP_SHIFT
+            | flag(self.top_bottom, STATUS_TB)
+            | flag(self.sector_protect, STATUS_SEC)
+            | flag(self.status_register_protect, STATUS_SRP)
+    }
+
+    /// Returns status register 1, i.e. the low byte.
+    pub fn register_1(&self) -> u8 {
+        self.bits() as u8
+    }
+
+    /// Returns the status register after an `OpCode::WriteStatusRegister`
+    /// with `data`.
+    ///
+    /// One data byte writes status register 1, two data bytes also write
+    /// status register 2. WIP and WEL can't be written and keep their value.
+    ///
+    /// Returns `None` if `data` is empty.
+    pub fn written(&self, data: &[u8]) -> Option<Self> {
+        let read_only = STATUS_WIP | STATUS_WEL;
+        let (written, mask) = match data {
+            [] => return None,
+            [sr1] => (*sr1 as u16, 0x00ff),
+            [sr1, sr2, ..] => ((*sr2 as u16) << 8 | *sr1 as u16, 0xffff),
+        };
+        let mask = mask & !read_only;
+        Some(Self::from_bits(self.bits() & !mask | written & mask))
+    }
+
+    /// Returns whether status register writes are ignored, given whether the
+    /// WP# pin is asserted.
+    pub fn is_locked(&self, write_protect: bool) -> bool {
+        self.status_register_protect && write_protect
+    }
+
+    /// Returns the range of addresses protected against program and erase
+    /// operations in a flash of `flash_size` bytes.
+    ///
+    /// If BP3-BP0 is 0, nothing is protected. Otherwise, the protected region
+    /// is `unit << (BP3-BP0 - 1)` bytes long, capped at `flash_size`, where
+    /// `unit` is a 64 KiB block, or a 4 KiB sector if SEC is set. The region
+    /// ends at the top of the flash, or starts at the bottom if TB is set.
+    pub fn protected_range(&self, flash_size: u32) -> Range<u32> {
+        let block_protect = self.block_protect & STATUS_BP_MASK as u8;
+        if block_protect == 0 {
+            return 0..0;
+        }
+        let unit = if self.sector_protect { PROTECT_SECTOR_SIZE } else { PROTECT_BLOCK_SIZE };
+        let len = core::cmp::min(unit << (block_protect - 1), flash_size as u64) as u32;
+        if self.top_bottom {
+            0..len
+        } else {
+            flash_size - len..flash_size
+        }
+    }
+
+    /// Returns whether any of the `len` bytes starting at `address` are
+    /// protected in a flash of `flash_size` bytes.
+    pub fn is_protected(&self, flash_size: u32, address: u32, len: u32) -> bool {
+        let protected = self.protected_range(flash_size);
+        let end = address as u64 + len as u64;
+        len > 0 && (address as u64) < protected.end as u64 && end > protected.start as u64
+    }
+}
+
+/// The JEP106 continuation code, which selects the next manufacturer bank.
+pub const JEDEC_CONTINUATION_CODE: u8 = 0x7f;
+
+/// The maximum length of a JEDEC ID on the wire, in bytes.
+///
+/// This is the size of the JEDEC ID registers of the SPI device (3 32-bit
+/// registers).
+pub const MAX_JEDEC_ID_LEN: usize = 12;
+
+/// The maximum JEP106 bank that fits into `MAX_JEDEC_ID_LEN` bytes.
+pub const MAX_JEDEC_BANK: u8 = (MAX_JEDEC_ID_LEN - 2) as u8;
+
+/// A manufacturer with a JEP106 ID.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct JedecManufacturer {
+    /// The JEP106 bank, starting at 1.
+    pub bank: u8,
+
+    /// The manufacturer ID within the bank.
+    pub id: u8,
+
+    /// The name of the manufacturer.
+    pub name: &'static str,
+}
+
+/// Manufacturers of SPI flash devices.
+pub const KNOWN_MANUFACTURERS: &[JedecManufacturer] = &[
+    JedecManufacturer { bank: 1, id: 0x01, name: "Spansion" },
+    JedecManufacturer { bank: 1, id: 0x1c, name: "Eon" },
+    JedecManufacturer { bank: 1, id: 0x1f, name: "Atmel" },
+    JedecManufacturer { bank: 1, id: 0x20, name: "Micron (ST)" },
+    JedecManufacturer { bank: 1, id: 0x2c, name: "Micron" },
+    JedecManufacturer { bank: 1, id: 0x89, name: "Intel" },
+    JedecManufacturer { bank: 1, id: 0x9d, name: "ISSI" },
+    JedecManufacturer { bank: 1, id: 0xbf, name: "SST" },
+    JedecManufacturer { bank: 1, id: 0xc2, name: "Macronix" },
+    JedecManufacturer { bank: 1, id: 0xc8, name: "GigaDevice" },
+    JedecManufacturer { bank: 1, id: 0xef, name: "Winbond" },
+    JedecManufacturer { bank: 9, id: 0x26, name: "Google" },
+];
+
+/// A JEDEC ID, as returned by `OpCode::ReadJedec`.
+///
+/// On the wire, the manufacturer ID is preceded by `bank - 1` continuation
+/// codes and followed by the device and capacity bytes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct JedecId {
+    /// The JEP106 bank of the manufacturer, starting at 1.
+    pub bank: u8,
+
+    /// The manufacturer ID within the bank.
+    pub manufacturer: u8,
+
+    /// The device ID (memory type).
+    pub device: u8,
+
+    /// The capacity, as a power of two in bytes.
+    pub capacity: u8,
+}
+
+impl JedecId {
+    /// Returns the length of the JEDEC ID on the wire, in bytes.
+    pub fn wire_len(&self) -> usize {
+        self.bank as usize + 2
+    }
+
+    /// Returns the manufacturer, if known.
+    pub fn manufacturer(&self) -> Option<&'static JedecManufacturer> {
+        KNOWN_MANUFACTURERS.iter()
+            .find(|m| m.bank == self.bank && m.id == self.manufacturer)
+    }
+
+    /// Returns the capacity in bytes, if it fits into a `u64`.
+    pub fn capacity_bytes(&self) -> Option<u64> {
+        1u64.checked_shl(self.capacity as u32)
     }
 }
 
+impl<'a> FromWire<'a> for JedecId {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let mut bank = 1;
+        let manufacturer = loop {
+            let byte = r.read_be::<u8>()?;
+            if byte != JEDEC_CONTINUATION_CODE {
+                break byte;
+            }
+            if bank == MAX_JEDEC_BANK {
+                return Err(FromWireError::OutOfRange(
+                    FieldError::new("continuation codes").with_value(bank).at(0)));
+            }
+            bank += 1;
+        };
+        let device = r.read_be::<u8>()?;
+        let capacity = r.read_be::<u8>()?;
+        Ok(Self {
+            bank,
+            manufacturer,
+            device,
+            capacity,
+        })
+    }
+}
+
+impl ToWire for JedecId {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        if self.bank == 0 || self.bank > MAX_JEDEC_BANK {
+            return Err(ToWireError::InvalidData(FieldError::new("bank").with_value(self.bank)));
+        }
+        if self.manufacturer == JEDEC_CONTINUATION_CODE {
+            return Err(ToWireError::InvalidData(FieldError::new("manufacturer").with_value(self.manufacturer)));
+        }
+        for _ in 1..self.bank {
+            w.write_be(JEDEC_CONTINUATION_CODE)?;
+        }
+        w.write_be(self.manufacturer)?;
+        w.write_be(self.device)?;
+        w.write_be(self.capacity)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::io::Cursor;
+    use proptest::prelude::*;
+
+    fn opcode() -> impl Strategy<Value = OpCode> {
+        any::<u8>().prop_filter_map("not an opcode", OpCode::from_wire_value)
+    }
+
+    fn address_mode() -> impl Strategy<Value = AddressMode> {
+        prop_oneof![Just(AddressMode::ThreeByte), Just(AddressMode::FourByte)]
+    }
+
+    // Values at the edges of the 24-bit and 32-bit ranges are picked more
+    // often than chance would have it.
+    fn address() -> impl Strategy<Value = u32> {
+        prop_oneof![
+            Just(0),
+            Just(0x00ff_ffff),
+            Just(0x0100_0000),
+            Just(u32::MAX),
+            any::<u32>(),
+        ]
+    }
+
+    #[test]
+    fn descriptor() {
+        let desc = OpCode::FastReadQuadIo.descriptor();
+        assert_eq!(desc.address_width, AddressWidth::AddressMode);
+        assert_eq!(desc.address_lines, 4);
+        assert_eq!(desc.data_lines, 4);
+        assert_eq!(desc.dummy_len(), 3);
+
+        assert_eq!(OpCode::FastRead.dummy_len(), 1);
+        assert_eq!(OpCode::FastReadDualIo.dummy_len(), 1);
+        assert_eq!(OpCode::FastReadQuadOutput.dummy_len(), 1);
+        assert_eq!(OpCode::NormalRead.dummy_len(), 0);
+        assert!(OpCode::QuadPageProgram.has_data());
+        assert!(OpCode::QuadIoPageProgram.wait_busy_clear());
+        assert!(!OpCode::WriteEnable.has_address());
+    }
+
+    #[test]
+    fn header_errors() {
+        let mut data: &[u8] = &[0x9e, 0x00, 0x00, 0x00];
+        let err = Header::from_wire(&mut data, AddressMode::ThreeByte).unwrap_err();
+        assert_eq!(err, FromWireError::UnknownValue(FieldError::new("opcode").with_value(0x9eu8).at(0)));
+        assert_eq!(format!("{}", err), "unknown opcode 0x9e at offset 0");
+
+        let header = Header {
+            opcode: OpCode::NormalRead,
+            address_mode: AddressMode::ThreeByte,
+            address: Some(0x02000000),
+        };
+        let mut buf = [0u8; 8];
+        let err = header.to_wire(Cursor::new(&mut buf)).unwrap_err();
+        assert_eq!(format!("{}", err), "invalid address 0x2000000 at offset 1");
+    }
+
+    #[test]
+    fn quad_io_header() {
+        let mut data: &[u8] = &[0xeb, 0x12, 0x34, 0x56, 0xa0, 0x00, 0x00, 0x99];
+        let header = Header::from_wire(&mut data, AddressMode::ThreeByte).unwrap();
+        assert_eq!(header.opcode, OpCode::FastReadQuadIo);
+        assert_eq!(header.get_address(), Some(0x123456));
+        assert_eq!(data, &[0x99]);
+
+        let mut buf = [0; 8];
+        let mut cursor = crate::io::Cursor::new(&mut buf);
+        header.to_wire(&mut cursor).unwrap();
+        assert_eq!(cursor.consumed_bytes(), &[0xeb, 0x12, 0x34, 0x56, 0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn read_header() {
+        let header = Header::read(AddressMode::ThreeByte, 0x10000, 0x1000);
+        assert_eq!(header.opcode, OpCode::NormalRead);
+        assert_eq!(header.address_len(), 3);
+
+        // The recovery image of a 32 MiB flash is out of reach of 3-byte
+        // addresses, as are reads that cross into it.
+        for &(address, len) in &[(0x0100_0000, 0x100), (0x00ff_ff00, 0x200)] {
+            let header = Header::read(AddressMode::ThreeByte, address, len);
+            assert_eq!(header.opcode, OpCode::NormalRead4B);
+            assert_eq!(header.address_len(), 4);
+
+            let mut buf = [0u8; 5];
+            header.to_wire(Cursor::new(&mut buf)).unwrap();
+            assert_eq!(buf[0], 0x13);
+            assert_eq!(buf[1..], address.to_be_bytes());
+        }
+
+        let header = Header::read(AddressMode::ThreeByte, 0x00ff_ff00, 0x100);
+        assert_eq!(header.opcode, OpCode::NormalRead);
+
+        let header = Header::read(AddressMode::FourByte, 0x0100_0000, 0x100);
+        assert_eq!(header.opcode, OpCode::NormalRead);
+        assert_eq!(header.address_len(), 4);
+    }
+
+    #[test]
+    fn four_byte_opcodes_ignore_address_mode() {
+        for address_mode in &[AddressMode::ThreeByte, AddressMode::FourByte] {
+            let mut data: &[u8] = &[0x13, 0x01, 0x23, 0x45, 0x67, 0x99];
+            let header = Header::from_wire(&mut data, *address_mode).unwrap();
+            assert_eq!(header.opcode, OpCode::NormalRead4B);
+            assert_eq!(header.get_address(), Some(0x01234567));
+            assert_eq!(data, &[0x99]);
+        }
+    }
+
+    #[test]
+    fn address_mode_opcodes() {
+        let mut data: &[u8] = &[0x02, 0x01, 0x23, 0x45, 0x67];
+        let header = Header::from_wire(&mut data, AddressMode::ThreeByte).unwrap();
+        assert_eq!(header.get_address(), Some(0x012345));
+        assert_eq!(data, &[0x67]);
+
+        let mut data: &[u8] = &[0x02, 0x01, 0x23, 0x45, 0x67];
+        let header = Header::from_wire(&mut data, AddressMode::FourByte).unwrap();
+        assert_eq!(header.get_address(), Some(0x01234567));
+        assert_eq!(data.len(), 0);
+    }
+
+    #[test]
+    fn sfdp_is_always_three_byte() {
+        let mut data: &[u8] = &[0x5a, 0x00, 0x00, 0x10, 0x00, 0x99];
+        let header = Header::from_wire(&mut data, AddressMode::FourByte).unwrap();
+        assert_eq!(header.get_address(), Some(0x10));
+        assert_eq!(data, &[0x99]);
+    }
+
+    #[test]
+    fn to_wire_checks_address_width() {
+        let header = Header {
+            opcode: OpCode::PageProgram,
+            address_mode: AddressMode::ThreeByte,
+            address: Some(0x01000000),
+        };
+        let mut buf = [0; 8];
+        assert!(header.to_wire(&mut buf[..]).is_err());
+
+        let header = Header {
+            opcode: OpCode::PageProgram4B,
+            ..header
+        };
+        let mut cursor = crate::io::Cursor::new(&mut buf);
+        header.to_wire(&mut cursor).unwrap();
+        assert_eq!(cursor.consumed_bytes(), &[0x12, 0x01, 0x00, 0x00, 0x00]);
+    }
+
+    #[test]
+    fn status_register() {
+        let status = StatusRegister::from_bits(0x1ff);
+        assert_eq!(status.block_protect, 0xf);
+        assert!(status.write_in_progress && status.write_enable_latch);
+        assert!(status.top_bottom && status.sector_protect && status.status_register_protect);
+        assert_eq!(status.bits(), 0x1ff);
+        assert_eq!(status.register_1(), 0xff);
+
+        // WIP and WEL can't be written and a single byte leaves SRP alone.
+        let status = StatusRegister::from_bits(0x103).written(&[0x0c]).unwrap();
+        assert_eq!(status.bits(), 0x10f);
+        let status = status.written(&[0x00, 0x00]).unwrap();
+        assert_eq!(status.bits(), 0x003);
+        assert_eq!(status.written(&[]), None);
+    }
+
+    #[test]
+    fn protected_range() {
+        const SIZE: u32 = 32 * 1024 * 1024;
+        let mut status = StatusRegister::default();
+        assert_eq!(status.protected_range(SIZE), 0..0);
+        assert!(!status.is_protected(SIZE, 0, SIZE));
+
+        status.block_protect = 1;
+        assert_eq!(status.protected_range(SIZE), SIZE - 0x10000..SIZE);
+        status.block_protect = 3;
+        assert_eq!(status.protected_range(SIZE), SIZE - 0x40000..SIZE);
+        status.top_bottom = true;
+        assert_eq!(status.protected_range(SIZE), 0..0x40000);
+        status.sector_protect = true;
+        assert_eq!(status.protected_range(SIZE), 0..0x4000);
+        status.block_protect = 0xf;
+        assert_eq!(status.protected_range(SIZE), 0..SIZE);
+
+        let status = StatusRegister { block_protect: 1, ..Default::default() };
+        assert!(status.is_protected(SIZE, SIZE - 1, 1));
+        assert!(status.is_protected(SIZE, SIZE - 0x10001, 2));
+        assert!(!status.is_protected(SIZE, SIZE - 0x10001, 1));
+        assert!(!status.is_protected(SIZE, SIZE - 1, 0));
+    }
+
+    #[test]
+    fn jedec_id() {
+        let legacy = JedecId { bank: 1, manufacturer: 0x26, device: 0x02, capacity: 0x17 };
+        let mut buf = [0u8; MAX_JEDEC_ID_LEN];
+        let mut cursor = Cursor::new(&mut buf);
+        legacy.to_wire(&mut cursor).expect("to_wire failed");
+        assert_eq!(cursor.consumed_bytes(), &[0x26, 0x02, 0x17]);
+        assert_eq!(legacy.manufacturer(), None);
+        assert_eq!(legacy.capacity_bytes(), Some(8 * 1024 * 1024));
+
+        let opentitan = JedecId { bank: 9, manufacturer: 0x26, device: 0x31, capacity: 0x19 };
+        let mut buf = [0u8; MAX_JEDEC_ID_LEN];
+        let mut cursor = Cursor::new(&mut buf);
+        opentitan.to_wire(&mut cursor).expect("to_wire failed");
+        assert_eq!(cursor.consumed_len(), opentitan.wire_len());
+        assert_eq!(cursor.consumed_bytes(), &[0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x7f, 0x26, 0x31, 0x19]);
+        assert_eq!(opentitan.manufacturer().map(|m| m.name), Some("Google"));
+
+        let mut data = cursor.consumed_bytes();
+        assert_eq!(JedecId::from_wire(&mut data).expect("from_wire failed"), opentitan);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn jedec_id_fits_registers() {
+        let id = JedecId { bank: MAX_JEDEC_BANK, manufacturer: 0x01, device: 0, capacity: 0 };
+        assert_eq!(id.wire_len(), MAX_JEDEC_ID_LEN);
+        let mut buf = [0u8; MAX_JEDEC_ID_LEN];
+        assert!(id.to_wire(Cursor::new(&mut buf)).is_ok());
+
+        let mut buf = [0u8; MAX_JEDEC_ID_LEN + 1];
+        let id = JedecId { bank: MAX_JEDEC_BANK + 1, ..id };
+        assert!(matches!(id.to_wire(Cursor::new(&mut buf)), Err(ToWireError::InvalidData(_))));
+        let id = JedecId { bank: 0, ..id };
+        assert!(matches!(id.to_wire(Cursor::new(&mut buf)), Err(ToWireError::InvalidData(_))));
+        let id = JedecId { bank: 1, manufacturer: JEDEC_CONTINUATION_CODE, ..id };
+        assert!(matches!(id.to_wire(Cursor::new(&mut buf)), Err(ToWireError::InvalidData(_))));
+
+        let mut data: &[u8] = &[0x7f; MAX_JEDEC_ID_LEN];
+        assert!(matches!(JedecId::from_wire(&mut data), Err(FromWireError::OutOfRange(_))));
+        let mut data: &[u8] = &[0x7f, 0x7f, 0x26];
+        assert!(JedecId::from_wire(&mut data).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn header_round_trip(opcode in opcode(), address_mode in address_mode(), address in address()) {
+            let address = match opcode.address_len(address_mode) {
+                0 => None,
+                3 => Some(address & 0x00ff_ffff),
+                _ => Some(address),
+            };
+            let header = Header { opcode, address_mode, address };
+
+            let mut buf = [0u8; 16];
+            let mut cursor = Cursor::new(&mut buf);
+            header.to_wire(&mut cursor).unwrap();
+            prop_assert_eq!(cursor.consumed_len(), 1 + header.address_len() + opcode.dummy_len());
+
+            let mut data = cursor.consumed_bytes();
+            prop_assert_eq!(Header::from_wire(&mut data, address_mode), Ok(header));
+            prop_assert!(data.is_empty());
+        }
+
+        #[test]
+        fn header_from_arbitrary_bytes(
+            address_mode in address_mode(),
+            input in prop::collection::vec(any::<u8>(), 0..=16),
+        ) {
+            let mut data = &input[..];
+            if let Ok(header) = Header::from_wire(&mut data, address_mode) {
+                // Everything but the dummy bytes survives a round trip.
+                let consumed = input.len() - data.len();
+                let mut out = [0u8; 16];
+                let mut cursor = Cursor::new(&mut out);
+                header.to_wire(&mut cursor).unwrap();
+                prop_assert_eq!(cursor.consumed_len(), consumed);
+                let len = 1 + header.address_len();
+                prop_assert_eq!(&cursor.consumed_bytes()[..len], &input[..len]);
+            }
+        }
+
+        #[test]
+        fn u24_address_conversion(val in address()) {
+            match <ux::u24 as Address>::try_from(val) {
+                Ok(address) => prop_assert_eq!(u32::from(address), val),
+                Err(AddressConversionError) => prop_assert!(val > 0x00ff_ffff),
+            }
+        }
+
+        #[test]
+        fn jedec_id_from_arbitrary_bytes(
+            mut input in prop::collection::vec(any::<u8>(), 0..=MAX_JEDEC_ID_LEN + 2),
+            codes in any::<usize>(),
+        ) {
+            // Random bytes are rarely continuation codes.
+            let codes = codes % (input.len() + 1);
+            for byte in input[..codes].iter_mut() {
+                *byte = JEDEC_CONTINUATION_CODE;
+            }
+
+            let mut data = &input[..];
+            if let Ok(id) = JedecId::from_wire(&mut data) {
+                let consumed = input.len() - data.len();
+                prop_assert_eq!(id.wire_len(), consumed);
+
+                let mut out = [0u8; MAX_JEDEC_ID_LEN];
+                let mut cursor = Cursor::new(&mut out);
+                id.to_wire(&mut cursor).unwrap();
+                prop_assert_eq!(cursor.consumed_bytes(), &input[..consumed]);
+            }
+        }
+    }
+
+    fn assert_chunks(transfer: Transfer, expected: &[(Option<u32>, usize, usize)]) {
+        let mut count = 0;
+        for (chunk, &(address, offset, len)) in transfer.zip(expected.iter()) {
+            assert_eq!((chunk.header.address, chunk.offset, chunk.len), (address, offset, len));
+            count += 1;
+        }
+        assert_eq!(transfer.count(), expected.len());
+        assert_eq!(count, expected.len());
+    }
+
+    fn header(opcode: OpCode, address: Option<u32>) -> Header {
+        Header {
+            opcode,
+            address_mode: AddressMode::ThreeByte,
+            address,
+        }
+    }
+
+    #[test]
+    fn transfer_read() {
+        let transfer = Transfer::new(header(OpCode::NormalRead, Some(0x1000)), 300, 128).unwrap();
+        assert_chunks(transfer, &[
+            (Some(0x1000), 0, 124),
+            (Some(0x107c), 124, 124),
+            (Some(0x10f8), 248, 52),
+        ]);
+
+        // Dummy bytes take up room in the transaction.
+        let transfer = Transfer::new(header(OpCode::FastRead, Some(0)), 128, 128).unwrap();
+        assert_chunks(transfer, &[
+            (Some(0), 0, 123),
+            (Some(123), 123, 5),
+        ]);
+    }
+
+    #[test]
+    fn transfer_page_program() {
+        let transfer = Transfer::new(header(OpCode::PageProgram, Some(0x10f0)), 300, 128).unwrap();
+        assert_chunks(transfer, &[
+            (Some(0x10f0), 0, 16),
+            (Some(0x1100), 16, 124),
+            (Some(0x117c), 140, 124),
+            (Some(0x11f8), 264, 8),
+            (Some(0x1200), 272, 28),
+        ]);
+    }
+
+    #[test]
+    fn transfer_without_data() {
+        let transfer = Transfer::new(header(OpCode::SectorErase, Some(0x1000)), 0, 128).unwrap();
+        assert_chunks(transfer, &[(Some(0x1000), 0, 0)]);
+
+        let transfer = Transfer::new(header(OpCode::ReadStatusRegister, None), 1, 128).unwrap();
+        assert_chunks(transfer, &[(None, 0, 1)]);
+    }
+
+    #[test]
+    fn transfer_invalid() {
+        // The header doesn't leave room for data.
+        assert!(Transfer::new(header(OpCode::NormalRead, Some(0)), 1, 4).is_err());
+        // Without an address, the data can't be split.
+        assert!(Transfer::new(header(OpCode::ReadStatusRegister, None), 128, 128).is_err());
+        // The data extends past the end of the address space.
+        let end = header(OpCode::NormalRead, Some(0x00ff_fff0));
+        assert!(Transfer::new(end, 0x10, 128).is_ok());
+        assert!(Transfer::new(end, 0x11, 128).is_err());
+        let mut end = header(OpCode::NormalRead, Some(0xffff_fff0));
+        end.address_mode = AddressMode::FourByte;
+        assert!(Transfer::new(end, 0x10, 128).is_ok());
+        assert!(Transfer::new(end, 0x11, 128).is_err());
+        let end = header(OpCode::NormalRead4B, Some(0xffff_fff0));
+        assert!(Transfer::new(end, 0x10, 128).is_ok());
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! SPI mailbox status.
+//!
+//! Requests and responses are split into packets, see
+//! `payload::write_packet`. The SPI host sends a request by programming its
+//! packets, one page program command per packet, to the mailbox address. The
+//! device reports the progress of the request through a status word at the
+//! start of the mailbox, which the SPI host polls with regular reads. Once
+//! the status word reports `MailboxState::ResponseReady`, the first packet of
+//! the response follows the status word. The SPI host requests each further
+//! packet by erasing the mailbox. Erasing the mailbox after the last packet
+//! clears it.
+//!
+//! The status word also reports "write" type commands to the host flash that
+//! were rejected by the device's write policy, and the outcome of verified
+//! boot.
+
+use crate::io::Read;
+use crate::io::Write;
+use crate::manifest::BootStatus;
+use crate::policy::Protection;
+use crate::protocol::payload;
+use crate::protocol::wire::FieldError;
+use crate::protocol::wire::FromWire;
+use crate::protocol::wire::FromWireError;
+use crate::protocol::wire::ToWire;
+use crate::protocol::wire::ToWireError;
+use crate::protocol::wire::WireEnum;
+
+wire_enum! {
+    /// The state of the mailbox.
+    pub enum MailboxState: u8 {
+        /// No request has been received since the mailbox was cleared.
+        Idle = 0x00,
+
+        /// A request has been received and is being processed.
+        RequestPending = 0x01,
+
+        /// The response follows the status word.
+        ResponseReady = 0x02,
+
+        /// Processing the request failed. There is no response.
+        Error = 0x03,
+    }
+}
+
+/// The length of the status word on the wire, in bytes.
+pub const STATUS_LEN: usize = 8;
+
+/// The offset of the response within the mailbox.
+pub const RESPONSE_OFFSET: usize = STATUS_LEN;
+
+/// The size of the mailbox, in bytes.
+pub const MAILBOX_LEN: usize = 512;
+
+/// The maximum length of a request packet, including its packet header.
+///
+/// This is the data length of a single page program command.
+pub const MAX_REQUEST_PACKET_LEN: usize = 256;
+
+/// The content length of all but the last packet of a request.
+pub const REQUEST_PAYLOAD_LEN: usize = MAX_REQUEST_PACKET_LEN - payload::PACKET_HEADER_LEN;
+
+/// The content length of all but the last packet of a response.
+pub const RESPONSE_PAYLOAD_LEN: usize = MAILBOX_LEN - RESPONSE_OFFSET - payload::PACKET_HEADER_LEN;
+
+/// The status word at the start of the mailbox.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct MailboxStatus {
+    /// The state of the mailbox.
+    pub state: MailboxState,
+
+    /// The sequence number of the last request.
+    ///
+    /// It is incremented for every request, so that the SPI host can tell
+    /// the status of its request from that of a previous one.
+    pub sequence: u8,
+
+    /// The length of the response packet following the status word,
+    /// including its packet header.
+    ///
+    /// Only valid in `MailboxState::ResponseReady`.
+    pub response_len: u16,
+
+    /// The number of host flash writes rejected by the write policy,
+    /// saturating at `u16::MAX`.
+    pub rejected_writes: u16,
+
+    /// The protection of the region that caused the last rejection, if any.
+    pub last_rejection: Option<Protection>,
+
+    /// The outcome of verified boot, once it is decided.
+    pub boot_status: Option<BootStatus>,
+}
+
+impl<'a> FromWire<'a> for MailboxStatus {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let state = MailboxState::from_wire(&mut r)?;
+        let sequence = r.read_be::<u8>()?;
+        let response_len = r.read_be::<u16>()?;
+        let rejected_writes = r.read_be::<u16>()?;
+        let last_rejection = match r.read_be::<u8>()? {
+            0 => None,
+            protection_u8 => Some(Protection::from_wire_value(protection_u8)
+                .ok_or_else(|| FromWireError::UnknownValue(
+                    FieldError::new("last rejection").with_value(protection_u8).at(6)))?),
+        };
+        let boot_status = match r.read_be::<u8>()? {
+            0 => None,
+            boot_status_u8 => Some(BootStatus::from_wire_value(boot_status_u8)
+                .ok_or_else(|| FromWireError::UnknownValue(
+                    FieldError::new("boot status").with_value(boot_status_u8).at(7)))?),
+        };
+        Ok(Self {
+            state,
+            sequence,
+            response_len,
+            rejected_writes,
+            last_rejection,
+            boot_status,
+        })
+    }
+}
+
+impl ToWire for MailboxStatus {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        w.write_be(self.state.to_wire_value())?;
+        w.write_be(self.sequence)?;
+        w.write_be(self.response_len)?;
+        w.write_be(self.rejected_writes)?;
+        w.write_be(self.last_rejection.map_or(0, |protection| protection.to_wire_value()))?;
+        w.write_be(self.boot_status.map_or(0, |boot_status| boot_status.to_wire_value()))?;
+        Ok(())
+    }
+}
+
+/// An invalid mailbox state transition.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidTransition {
+    /// The state the mailbox was in.
+    pub from: MailboxState,
+
+    /// The state the mailbox was supposed to transition to.
+    pub to: MailboxState,
+}
+
+/// The device side of the mailbox state machine.
+///
+/// Each transition returns the new status word, which needs to be made
+/// available to the SPI host.
+pub struct Mailbox {
+    status: MailboxStatus,
+}
+
+impl Mailbox {
+    /// Creates a new idle `Mailbox`.
+    pub fn new() -> Self {
+        Self {
+            status: MailboxStatus {
+                state: MailboxState::Idle,
+                sequence: 0,
+                response_len: 0,
+                rejected_writes: 0,
+                last_rejection: None,
+                boot_status: None,
+            },
+        }
+    }
+
+    /// Returns the current status word.
+    pub fn status(&self) -> MailboxStatus {
+        self.status
+    }
+
+    /// Starts processing a new request.
+    ///
+    /// A new request may be received in any state. It discards any response
+    /// that wasn't read yet.
+    pub fn request_received(&mut self) -> MailboxStatus {
+        self.status = MailboxStatus {
+            state: MailboxState::RequestPending,
+            sequence: self.status.sequence.wrapping_add(1),
+            response_len: 0,
+            ..self.status
+        };
+        self.status
+    }
+
+    /// Completes the pending request with the first packet of the response,
+    /// which is `response_len` bytes long.
+    pub fn response_ready(&mut self, response_len: u16) -> Result<MailboxStatus, InvalidTransition> {
+        self.complete(MailboxState::ResponseReady, response_len)
+    }
+
+    /// Replaces the response packet with the next one, which is
+    /// `response_len` bytes long.
+    pub fn response_continued(&mut self, response_len: u16) -> Result<MailboxStatus, InvalidTransition> {
+        if self.status.state != MailboxState::ResponseReady {
+            return Err(InvalidTransition {
+                from: self.status.state,
+                to: MailboxState::ResponseReady,
+            });
+        }
+        self.status.response_len = response_len;
+        Ok(self.status)
+    }
+
+    /// Fails the pending request.
+    pub fn failed(&mut self) -> Result<MailboxStatus, InvalidTransition> {
+        self.complete(MailboxState::Error, 0)
+    }
+
+    /// Records a host flash write that was rejected because it modifies a
+    /// region with `protection`.
+    ///
+    /// This doesn't change the state of the mailbox.
+    pub fn write_rejected(&mut self, protection: Protection) -> MailboxStatus {
+        self.status = MailboxStatus {
+            rejected_writes: self.status.rejected_writes.saturating_add(1),
+            last_rejection: Some(protection),
+            ..self.status
+        };
+        self.status
+    }
+
+    /// Records the outcome of verified boot.
+    ///
+    /// This doesn't change the state of the mailbox.
+    pub fn boot_decided(&mut self, boot_status: BootStatus) -> MailboxStatus {
+        self.status = MailboxStatus {
+            boot_status: Some(boot_status),
+            ..self.status
+        };
+        self.status
+    }
+
+    /// Clears the mailbox, e.g. after the SPI host erased it.
+    pub fn clear(&mut self) -> MailboxStatus {
+        self.status = MailboxStatus {
+            state: MailboxState::Idle,
+            response_len: 0,
+            ..self.status
+        };
+        self.status
+    }
+
+    fn complete(&mut self, state: MailboxState, response_len: u16) -> Result<MailboxStatus, InvalidTransition> {
+        if self.status.state != MailboxState::RequestPending {
+            return Err(InvalidTransition {
+                from: self.status.state,
+                to: state,
+            });
+        }
+        self.status = MailboxStatus {
+            state,
+            response_len,
+            ..self.status
+        };
+        Ok(self.status)
+    }
+}
+
+impl Default for Mailbox {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::io::Cursor;
+    use crate::manifest::Decision;
+    use crate::manifest::Failure;
+
+    #[test]
+    fn status_round_trip() {
+        let status = MailboxStatus {
+            state: MailboxState::ResponseReady,
+            sequence: 7,
+            response_len: 0x123,
+            rejected_writes: 0x456,
+            last_rejection: Some(Protection::WriteOnce),
+            boot_status: Some(BootStatus { decision: Decision::Recovery, failure: Some(Failure::UntrustedKey) }),
+        };
+        let mut buf = [0u8; STATUS_LEN];
+        status.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf, [0x02, 0x07, 0x01, 0x23, 0x04, 0x56, 0x02, 0x22]);
+        assert_eq!(MailboxStatus::from_wire(&buf[..]), Ok(status));
+
+        let status = MailboxStatus {
+            last_rejection: None,
+            boot_status: None,
+            ..status
+        };
+        status.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf[6..], [0x00, 0x00]);
+        assert_eq!(MailboxStatus::from_wire(&buf[..]), Ok(status));
+
+        // An erased mailbox doesn't have a valid status.
+        assert!(MailboxStatus::from_wire(&[0xff; STATUS_LEN][..]).is_err());
+    }
+
+    #[test]
+    fn transitions() {
+        let mut mailbox = Mailbox::new();
+        assert_eq!(mailbox.status().state, MailboxState::Idle);
+        assert_eq!(mailbox.response_ready(4), Err(InvalidTransition {
+            from: MailboxState::Idle,
+            to: MailboxState::ResponseReady,
+        }));
+
+        let status = mailbox.request_received();
+        assert_eq!((status.state, status.sequence), (MailboxState::RequestPending, 1));
+        let status = mailbox.response_ready(4).unwrap();
+        assert_eq!((status.state, status.sequence, status.response_len), (MailboxState::ResponseReady, 1, 4));
+        assert!(mailbox.failed().is_err());
+
+        mailbox.request_received();
+        let status = mailbox.failed().unwrap();
+        assert_eq!((status.state, status.sequence, status.response_len), (MailboxState::Error, 2, 0));
+
+        let status = mailbox.clear();
+        assert_eq!((status.state, status.sequence), (MailboxState::Idle, 2));
+        assert!(mailbox.response_continued(4).is_err());
+
+        mailbox.request_received();
+        mailbox.response_ready(4).unwrap();
+        let status = mailbox.response_continued(2).unwrap();
+        assert_eq!((status.state, status.sequence, status.response_len), (MailboxState::ResponseReady, 3, 2));
+    }
+
+    #[test]
+    fn write_rejected() {
+        let mut mailbox = Mailbox::new();
+        mailbox.request_received();
+        let status = mailbox.write_rejected(Protection::ReadOnly);
+        assert_eq!(status.state, MailboxState::RequestPending);
+        assert_eq!((status.rejected_writes, status.last_rejection), (1, Some(Protection::ReadOnly)));
+
+        // Rejections are kept across requests.
+        mailbox.response_ready(4).unwrap();
+        mailbox.clear();
+        let status = mailbox.request_received();
+        assert_eq!((status.rejected_writes, status.last_rejection), (1, Some(Protection::ReadOnly)));
+
+        for _ in 0..u16::MAX {
+            mailbox.write_rejected(Protection::RecoveryOnly);
+        }
+        let status = mailbox.status();
+        assert_eq!((status.rejected_writes, status.last_rejection), (u16::MAX, Some(Protection::RecoveryOnly)));
+    }
+
+    #[test]
+    fn boot_decided() {
+        let mut mailbox = Mailbox::new();
+        let boot_status = BootStatus { decision: Decision::Verified, failure: None };
+        let status = mailbox.boot_decided(boot_status);
+        assert_eq!((status.state, status.boot_status), (MailboxState::Idle, Some(boot_status)));
+
+        // The outcome is kept across requests.
+        mailbox.request_received();
+        let status = mailbox.clear();
+        assert_eq!(status.boot_status, Some(boot_status));
+    }
+}
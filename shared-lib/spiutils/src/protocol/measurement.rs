@@ -0,0 +1,306 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host flash measurement messages.
+//!
+//! At boot, the device measures regions of the host flash by computing their
+//! SHA-256 digests and records them in a `Log`. Every digest is also folded
+//! into a platform measurement register (PMR), which summarizes the log.
+//!
+//! The log is served through `Measurement` payloads, which have their own
+//! content type so their commands cannot collide with Manticore commands.
+//! Every message starts with its `Command`, and a response repeats the
+//! command of its request.
+
+use crate::io::Read;
+use crate::io::Write;
+use crate::protocol::wire::FieldError;
+use crate::protocol::wire::FromWire;
+use crate::protocol::wire::FromWireError;
+use crate::protocol::wire::ToWire;
+use crate::protocol::wire::ToWireError;
+use crate::protocol::wire::WireEnum;
+
+/// The length of a SHA-256 digest, in bytes.
+pub const DIGEST_LEN: usize = 32;
+
+/// The maximum number of measurements in a `Log`.
+pub const MAX_MEASUREMENTS: usize = 8;
+
+wire_enum! {
+    /// A measurement command.
+    pub enum Command: u8 {
+        /// Get the platform measurement register.
+        GetPmr = 0x01,
+
+        /// Get the digests of all measured regions.
+        GetDigests = 0x02,
+    }
+}
+
+/// A measurement request.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Request {
+    /// Get the platform measurement register.
+    GetPmr,
+
+    /// Get the digests of all measured regions.
+    GetDigests,
+}
+
+impl<'a> FromWire<'a> for Request {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let command_u8 = r.read_be::<u8>()?;
+        let command = Command::from_wire_value(command_u8)
+            .ok_or_else(|| FromWireError::UnknownValue(FieldError::new("command").with_value(command_u8).at(0)))?;
+        match command {
+            Command::GetPmr => Ok(Request::GetPmr),
+            Command::GetDigests => Ok(Request::GetDigests),
+        }
+    }
+}
+
+impl ToWire for Request {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        let command = match self {
+            Request::GetPmr => Command::GetPmr,
+            Request::GetDigests => Command::GetDigests,
+        };
+        w.write_be(command.to_wire_value())?;
+        Ok(())
+    }
+}
+
+/// A measured region of the host flash.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, FromWire, ToWire)]
+pub struct Region {
+    /// The address of the first byte of the region.
+    pub start: u32,
+
+    /// The length of the region, in bytes.
+    pub len: u32,
+}
+
+/// The measurement of a host flash region.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, FromWire, ToWire)]
+pub struct Measurement {
+    /// The measured region.
+    pub region: Region,
+
+    /// The SHA-256 digest of the region.
+    pub digest: [u8; DIGEST_LEN],
+}
+
+/// The length of a `Measurement` on the wire, in bytes.
+pub const MEASUREMENT_LEN: usize = 8 + DIGEST_LEN;
+
+/// A measurement response.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Response<'a> {
+    /// The platform measurement register and the number of measurements
+    /// folded into it.
+    Pmr {
+        /// The number of measurements.
+        count: u8,
+
+        /// The platform measurement register.
+        pmr: [u8; DIGEST_LEN],
+    },
+
+    /// The measurements of all measured regions.
+    Digests(&'a [Measurement]),
+}
+
+impl ToWire for Response<'_> {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        match self {
+            Response::Pmr { count, pmr } => {
+                w.write_be(Command::GetPmr.to_wire_value())?;
+                w.write_be(*count)?;
+                w.write_bytes(pmr)?;
+            }
+            Response::Digests(measurements) => {
+                w.write_be(Command::GetDigests.to_wire_value())?;
+                w.write_be(measurements.len() as u8)?;
+                for measurement in measurements.iter() {
+                    measurement.to_wire(&mut w)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error returned by `Log::record`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error<E> {
+    /// The log already holds `MAX_MEASUREMENTS` measurements.
+    Full,
+
+    /// Extending the platform measurement register failed.
+    Extend(E),
+}
+
+/// A log of host flash measurements.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Log {
+    measurements: [Measurement; MAX_MEASUREMENTS],
+    len: usize,
+    pmr: [u8; DIGEST_LEN],
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Log {
+    /// Creates a new empty `Log`, whose PMR is all zeros.
+    pub fn new() -> Self {
+        Self {
+            measurements: [Measurement {
+                region: Region { start: 0, len: 0 },
+                digest: [0; DIGEST_LEN],
+            }; MAX_MEASUREMENTS],
+            len: 0,
+            pmr: [0; DIGEST_LEN],
+        }
+    }
+
+    /// Returns the recorded measurements.
+    pub fn measurements(&self) -> &[Measurement] {
+        &self.measurements[..self.len]
+    }
+
+    /// Returns the platform measurement register.
+    pub fn pmr(&self) -> &[u8; DIGEST_LEN] {
+        &self.pmr
+    }
+
+    /// Records `measurement`.
+    ///
+    /// `extend(pmr, digest)` returns the new PMR, which is the SHA-256 digest
+    /// of the current PMR followed by the digest of the measurement.
+    pub fn record<E, F>(&mut self, measurement: Measurement, extend: F) -> Result<(), Error<E>>
+    where F: FnOnce(&[u8; DIGEST_LEN], &[u8; DIGEST_LEN]) -> Result<[u8; DIGEST_LEN], E>
+    {
+        if self.len == MAX_MEASUREMENTS {
+            return Err(Error::Full);
+        }
+        self.pmr = extend(&self.pmr, &measurement.digest).map_err(Error::Extend)?;
+        self.measurements[self.len] = measurement;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the response to `request`.
+    pub fn respond(&self, request: &Request) -> Response<'_> {
+        match request {
+            Request::GetPmr => Response::Pmr {
+                count: self.len as u8,
+                pmr: self.pmr,
+            },
+            Request::GetDigests => Response::Digests(self.measurements()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::io::Cursor;
+
+    // A fake extension that XORs the digest into the PMR.
+    fn extend(pmr: &[u8; DIGEST_LEN], digest: &[u8; DIGEST_LEN]) -> Result<[u8; DIGEST_LEN], ()> {
+        let mut result = *pmr;
+        for (byte, digest_byte) in result.iter_mut().zip(digest.iter()) {
+            *byte ^= *digest_byte;
+        }
+        Ok(result)
+    }
+
+    fn measurement(start: u32, byte: u8) -> Measurement {
+        Measurement {
+            region: Region { start, len: 0x1000 },
+            digest: [byte; DIGEST_LEN],
+        }
+    }
+
+    #[test]
+    fn request_round_trip() {
+        let mut buf = [0u8; 1];
+        Request::GetDigests.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf, [0x02]);
+        assert_eq!(Request::from_wire(&buf[..]), Ok(Request::GetDigests));
+
+        Request::GetPmr.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf, [0x01]);
+        assert_eq!(Request::from_wire(&buf[..]), Ok(Request::GetPmr));
+
+        assert!(Request::from_wire(&[0x03][..]).is_err());
+        assert!(Request::from_wire(&[][..]).is_err());
+    }
+
+    #[test]
+    fn measurement_round_trip() {
+        let measurement = measurement(0x12345678, 0xa5);
+        let mut buf = [0u8; MEASUREMENT_LEN];
+        measurement.to_wire(Cursor::new(&mut buf)).unwrap();
+        assert_eq!(buf[..8], [0x12, 0x34, 0x56, 0x78, 0x00, 0x00, 0x10, 0x00]);
+        assert_eq!(Measurement::from_wire(&buf[..]), Ok(measurement));
+    }
+
+    #[test]
+    fn log() {
+        let mut log = Log::new();
+        assert_eq!(log.measurements(), &[]);
+        assert_eq!(log.pmr(), &[0; DIGEST_LEN]);
+
+        log.record(measurement(0x0000, 0x0f), extend).unwrap();
+        log.record(measurement(0x1000, 0xf1), extend).unwrap();
+        assert_eq!(log.measurements(), &[measurement(0x0000, 0x0f), measurement(0x1000, 0xf1)]);
+        assert_eq!(log.pmr(), &[0xfe; DIGEST_LEN]);
+
+        assert_eq!(log.record(measurement(0x2000, 0x00), |_, _| Err(7)), Err(Error::Extend(7)));
+        assert_eq!(log.measurements().len(), 2);
+
+        for index in 2..MAX_MEASUREMENTS {
+            log.record(measurement(index as u32 * 0x1000, 0x00), extend).unwrap();
+        }
+        assert_eq!(log.record(measurement(0x10000, 0x00), extend), Err(Error::Full));
+    }
+
+    #[test]
+    fn respond() {
+        let mut log = Log::new();
+        log.record(measurement(0x1000, 0xa5), extend).unwrap();
+
+        let mut buf = [0u8; 64];
+        let mut cursor = Cursor::new(&mut buf);
+        log.respond(&Request::GetDigests).to_wire(&mut cursor).unwrap();
+        assert_eq!(cursor.consumed_len(), 2 + MEASUREMENT_LEN);
+        assert_eq!(buf[..2], [0x02, 0x01]);
+        assert_eq!(Measurement::from_wire(&buf[2..]), Ok(measurement(0x1000, 0xa5)));
+
+        let mut cursor = Cursor::new(&mut buf);
+        log.respond(&Request::GetPmr).to_wire(&mut cursor).unwrap();
+        assert_eq!(cursor.consumed_len(), 2 + DIGEST_LEN);
+        assert_eq!(buf[..2], [0x01, 0x01]);
+        assert_eq!(buf[2..2 + DIGEST_LEN], [0xa5; DIGEST_LEN]);
+    }
+}
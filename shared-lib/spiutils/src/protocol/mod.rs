@@ -19,5 +19,9 @@
 #[macro_use]
 pub mod wire;
 
+pub mod firmware;
 pub mod flash;
+pub mod mailbox;
+pub mod measurement;
 pub mod payload;
+pub mod sfdp;
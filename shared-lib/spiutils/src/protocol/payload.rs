@@ -16,14 +16,18 @@
 
 //! SPI flash protocol payload.
 
+use crate::io::Cursor;
 use crate::io::Read;
 use crate::io::Write;
+use crate::protocol::wire::FieldError;
 use crate::protocol::wire::FromWireError;
 use crate::protocol::wire::FromWire;
 use crate::protocol::wire::ToWireError;
 use crate::protocol::wire::ToWire;
 use crate::protocol::wire::WireEnum;
 
+use core::convert::TryFrom;
+
 wire_enum! {
     /// The content type.
     pub enum ContentType: u8 {
@@ -32,11 +36,23 @@ wire_enum! {
 
         /// Manticore
         Manticore = 0x01,
+
+        /// A chunk of a firmware update.
+        FirmwareUpdate = 0x02,
+
+        /// A raw SPDM message.
+        Spdm = 0x03,
+
+        /// Vendor-specific diagnostics.
+        VendorDiagnostics = 0x04,
+
+        /// A host flash measurement message.
+        Measurement = 0x05,
     }
 }
 
 /// A parsed header.
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, FromWire, ToWire)]
 pub struct Header {
     /// The content type following the header.
     pub content: ContentType,
@@ -48,22 +64,737 @@ pub struct Header {
 /// The length of a payload header on the wire, in bytes.
 pub const HEADER_LEN: usize = 3;
 
-impl<'a> FromWire<'a> for Header {
+/// The maximum number of handlers a `Dispatcher` can hold.
+pub const MAX_HANDLERS: usize = 8;
+
+/// A handler for payloads of a particular content type.
+pub trait PayloadHandler {
+    /// The error returned by the handler.
+    type Error;
+
+    /// Processes the `request` content and writes the response content into
+    /// `response`.
+    ///
+    /// Returns the length of the response content.
+    fn process(&mut self, request: &[u8], response: &mut [u8]) -> Result<usize, Self::Error>;
+}
+
+/// An error while dispatching a payload.
+#[derive(Clone, Copy, Debug)]
+pub enum DispatchError<E> {
+    /// The request could not be parsed.
+    FromWire(FromWireError),
+
+    /// The response could not be written.
+    ToWire(ToWireError),
+
+    /// No handler is registered for the content type.
+    UnsupportedContentType(ContentType),
+
+    /// No more handlers can be registered.
+    TooManyHandlers,
+
+    /// The handler returned an error.
+    Handler(E),
+}
+
+impl<E> From<FromWireError> for DispatchError<E> {
+    fn from(err: FromWireError) -> Self {
+        DispatchError::FromWire(err)
+    }
+}
+
+impl<E> From<ToWireError> for DispatchError<E> {
+    fn from(err: ToWireError) -> Self {
+        DispatchError::ToWire(err)
+    }
+}
+
+/// Dispatches payloads to the handler registered for their content type.
+pub struct Dispatcher<'a, E> {
+    handlers: [Option<(ContentType, &'a mut dyn PayloadHandler<Error = E>)>; MAX_HANDLERS],
+}
+
+impl<'a, E> Default for Dispatcher<'a, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, E> Dispatcher<'a, E> {
+    /// Creates a new `Dispatcher` without any handlers.
+    pub fn new() -> Self {
+        Self {
+            handlers: [None, None, None, None, None, None, None, None],
+        }
+    }
+
+    /// Registers `handler` for `content`.
+    ///
+    /// A handler previously registered for `content` is replaced.
+    pub fn register(&mut self, content: ContentType, handler: &'a mut dyn PayloadHandler<Error = E>)
+        -> Result<(), DispatchError<E>> {
+        let slot = match self.handlers.iter().position(|entry| matches!(entry, Some((c, _)) if *c == content)) {
+            Some(idx) => idx,
+            None => self.handlers.iter().position(|entry| entry.is_none())
+                .ok_or(DispatchError::TooManyHandlers)?,
+        };
+        self.handlers[slot] = Some((content, handler));
+        Ok(())
+    }
+
+    /// Returns true iff a handler is registered for `content`.
+    pub fn is_supported(&self, content: ContentType) -> bool {
+        self.handlers.iter().any(|entry| matches!(entry, Some((c, _)) if *c == content))
+    }
+
+    /// Processes `request`, which starts with a payload `Header`, and writes
+    /// the response including its `Header` into `response`.
+    ///
+    /// The response has the same content type as the request.
+    ///
+    /// Returns the length of the response.
+    pub fn dispatch(&mut self, mut request: &[u8], response: &mut [u8]) -> Result<usize, DispatchError<E>> {
+        let header = Header::from_wire(&mut request)?;
+        let content = request.get(..header.content_len as usize)
+            .ok_or(FromWireError::Io(crate::io::Error::BufferExhausted))?;
+        if response.len() < HEADER_LEN {
+            return Err(DispatchError::ToWire(ToWireError::Io(crate::io::Error::BufferExhausted)));
+        }
+
+        let (response_header, response_content) = response.split_at_mut(HEADER_LEN);
+        let response_len = self.dispatch_content(header.content, content, response_content)?;
+        let response_header_data = Header {
+            content: header.content,
+            content_len: u16::try_from(response_len).map_err(|_| ToWireError::InvalidData(
+                FieldError::new("content length").with_value(response_len as u64).at(1)))?,
+        };
+        response_header_data.to_wire(response_header)?;
+        Ok(HEADER_LEN + response_len)
+    }
+
+    /// Processes the `request` content of type `content`, e.g. a message
+    /// reassembled from packets, and writes the response content into
+    /// `response`.
+    ///
+    /// Returns the length of the response content.
+    pub fn dispatch_content(&mut self, content: ContentType, request: &[u8], response: &mut [u8])
+        -> Result<usize, DispatchError<E>> {
+        let handler = self.handlers.iter_mut()
+            .filter_map(|entry| entry.as_mut())
+            .find(|(c, _)| *c == content)
+            .map(|(_, handler)| handler)
+            .ok_or(DispatchError::UnsupportedContentType(content))?;
+        handler.process(request, response).map_err(DispatchError::Handler)
+    }
+}
+
+/// The version of the packet header.
+pub const PACKET_VERSION: u8 = 1;
+
+/// The length of a packet header on the wire, in bytes.
+pub const PACKET_HEADER_LEN: usize = 9;
+
+/// The maximum number of packets a message can be split into.
+pub const MAX_PACKET_COUNT: usize = 32;
+
+/// Computes the CRC-16/CCITT-FALSE of `data`, starting from `crc`.
+///
+/// Use `0xffff` as initial value.
+pub fn crc16(mut crc: u16, data: &[u8]) -> u16 {
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// A parsed packet header.
+///
+/// A message is split into one or more packets. Each packet carries a part of
+/// the message, which can be reassembled using a [`Reassembler`].
+///
+/// All packets except the last carry exactly the maximum payload length
+/// agreed upon by both sides, so that a packet's offset within the message
+/// is `packet_index * max_payload_len`.
+///
+/// [`Reassembler`]: struct.Reassembler.html
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PacketHeader {
+    /// The content type of the message.
+    pub content: ContentType,
+
+    /// An identifier for the message, used to detect packets from different
+    /// messages.
+    pub message_id: u8,
+
+    /// The index of this packet within the message.
+    pub packet_index: u8,
+
+    /// The total number of packets in the message.
+    pub packet_count: u8,
+
+    /// The length of the content following the header in this packet.
+    pub content_len: u16,
+
+    /// The CRC-16 over the header (excluding this field) and the content.
+    pub crc: u16,
+}
+
+impl PacketHeader {
+    /// Writes all fields except the CRC to `w`.
+    fn fields_to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        w.write_be(PACKET_VERSION)?;
+        w.write_be(self.content.to_wire_value())?;
+        w.write_be(self.message_id)?;
+        w.write_be(self.packet_index)?;
+        w.write_be(self.packet_count)?;
+        w.write_be(self.content_len)?;
+        Ok(())
+    }
+
+    /// Computes the CRC over the header and the `content` of the packet.
+    pub fn compute_crc(&self, content: &[u8]) -> u16 {
+        let mut buf = [0u8; PACKET_HEADER_LEN];
+        let mut cursor = Cursor::new(&mut buf);
+        // The buffer is large enough to hold the fields.
+        let _ = self.fields_to_wire(&mut cursor);
+        let crc = crc16(0xffff, cursor.consumed_bytes());
+        crc16(crc, content)
+    }
+}
+
+impl<'a> FromWire<'a> for PacketHeader {
     fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let version = r.read_be::<u8>()?;
+        if version != PACKET_VERSION {
+            return Err(FromWireError::OutOfRange(FieldError::new("packet version").with_value(version).at(0)));
+        }
         let content_u8 = r.read_be::<u8>()?;
-        let content = ContentType::from_wire_value(content_u8).ok_or(FromWireError::OutOfRange)?;
+        let content = ContentType::from_wire_value(content_u8)
+            .ok_or_else(|| FromWireError::UnknownValue(FieldError::new("content type").with_value(content_u8).at(1)))?;
+        let message_id = r.read_be::<u8>()?;
+        let packet_index = r.read_be::<u8>()?;
+        let packet_count = r.read_be::<u8>()?;
         let content_len = r.read_be::<u16>()?;
+        let crc = r.read_be::<u16>()?;
         Ok(Self {
             content,
+            message_id,
+            packet_index,
+            packet_count,
             content_len,
+            crc,
         })
     }
 }
 
-impl ToWire for Header {
+impl ToWire for PacketHeader {
     fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
-        w.write_be(self.content.to_wire_value())?;
-        w.write_be(self.content_len)?;
+        self.fields_to_wire(&mut w)?;
+        w.write_be(self.crc)?;
         Ok(())
     }
 }
+
+/// Reads a packet from `r` and verifies its CRC.
+///
+/// Returns the packet header and the content of the packet.
+pub fn read_packet<'a, R: Read<'a>>(mut r: R) -> Result<(PacketHeader, &'a [u8]), FromWireError> {
+    let header = PacketHeader::from_wire(&mut r)?;
+    let content = r.read_bytes(header.content_len as usize)?;
+    if header.compute_crc(content) != header.crc {
+        return Err(FromWireError::OutOfRange(
+            FieldError::new("packet crc").with_value(header.crc).at(PACKET_HEADER_LEN - 2)));
+    }
+    Ok((header, content))
+}
+
+/// Returns the number of packets needed to send a `message_len` bytes long
+/// message in packets carrying at most `max_payload_len` bytes.
+///
+/// Returns `None` if the message needs more than `MAX_PACKET_COUNT` packets.
+pub fn packet_count(message_len: usize, max_payload_len: usize) -> Option<u8> {
+    if max_payload_len == 0 || max_payload_len > u16::MAX as usize {
+        return None;
+    }
+    // An empty message is still sent as a single packet.
+    let count = message_len.saturating_sub(1) / max_payload_len + 1;
+    if count > MAX_PACKET_COUNT {
+        return None;
+    }
+    Some(count as u8)
+}
+
+/// Writes packet `packet_index` of `message` to `w`.
+///
+/// The message is split into packets carrying at most `max_payload_len`
+/// bytes each. See [`packet_count`] for the number of packets.
+///
+/// [`packet_count`]: fn.packet_count.html
+pub fn write_packet<W: Write>(
+    mut w: W,
+    content: ContentType,
+    message_id: u8,
+    message: &[u8],
+    packet_index: u8,
+    max_payload_len: usize) -> Result<(), ToWireError> {
+
+    let packet_count = packet_count(message.len(), max_payload_len)
+        .ok_or_else(|| ToWireError::InvalidData(FieldError::new("message length").with_value(message.len() as u64)))?;
+    if packet_index >= packet_count {
+        return Err(ToWireError::InvalidData(FieldError::new("packet index").with_value(packet_index)));
+    }
+    let start = packet_index as usize * max_payload_len;
+    let end = core::cmp::min(start + max_payload_len, message.len());
+    let data = &message[start..end];
+
+    let mut header = PacketHeader {
+        content,
+        message_id,
+        packet_index,
+        packet_count,
+        content_len: data.len() as u16,
+        crc: 0,
+    };
+    header.crc = header.compute_crc(data);
+    header.to_wire(&mut w)?;
+    w.write_bytes(data)?;
+    Ok(())
+}
+
+/// An error while reassembling a message.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ReassemblyError {
+    /// The packet index or count is invalid.
+    InvalidPacketIndex,
+
+    /// The packet's content length doesn't match its position in the message.
+    InvalidLength,
+
+    /// The packet doesn't match previous packets of the same message.
+    Inconsistent,
+
+    /// The message doesn't fit into the reassembly buffer.
+    BufferTooSmall,
+}
+
+/// The state of a message being reassembled.
+#[derive(Clone, Copy, Debug)]
+struct Reassembly {
+    content: ContentType,
+    message_id: u8,
+    packet_count: u8,
+    received: u32,
+    message_len: usize,
+}
+
+impl Reassembly {
+    /// Returns true iff all packets of the message have been received.
+    fn is_complete(&self) -> bool {
+        let all = ((1u64 << self.packet_count) - 1) as u32;
+        self.received == all
+    }
+}
+
+/// Reassembles messages from packets.
+///
+/// Packets may arrive in any order and may be repeated. The following
+/// packets start a new message, discarding the message reassembled so far:
+///
+/// - a packet for a different message id,
+/// - any packet after the message is complete, so that senders may reuse
+///   message ids, and
+/// - a repeated packet whose content differs from the first copy, which
+///   means that the sender reused the message id before completing the
+///   previous message.
+///
+/// A packet whose content type or packet count doesn't match the message
+/// being reassembled is rejected. Callers must call [`reset`] to abandon such
+/// a message, e.g. when the sender gives up on it.
+///
+/// [`reset`]: struct.Reassembler.html#method.reset
+pub struct Reassembler<'a> {
+    buf: &'a mut [u8],
+    max_payload_len: usize,
+    state: Option<Reassembly>,
+}
+
+impl<'a> Reassembler<'a> {
+    /// Creates a new `Reassembler` storing messages in `buf`.
+    ///
+    /// `max_payload_len` is the content length of all but the last packet of
+    /// a message.
+    pub fn new(buf: &'a mut [u8], max_payload_len: usize) -> Self {
+        Self {
+            buf,
+            max_payload_len,
+            state: None,
+        }
+    }
+
+    /// Discards any partially or fully reassembled message.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// Adds a packet to the message.
+    ///
+    /// The packet's CRC must have been verified already, e.g. by using
+    /// [`read_packet`].
+    ///
+    /// Returns true iff the message is complete.
+    ///
+    /// [`read_packet`]: fn.read_packet.html
+    pub fn push(&mut self, header: &PacketHeader, content: &[u8]) -> Result<bool, ReassemblyError> {
+        let packet_count = header.packet_count as usize;
+        let packet_index = header.packet_index as usize;
+        if packet_count == 0 || packet_count > MAX_PACKET_COUNT || packet_index >= packet_count {
+            return Err(ReassemblyError::InvalidPacketIndex);
+        }
+        let is_last = packet_index + 1 == packet_count;
+        if content.len() != header.content_len as usize
+            || content.len() > self.max_payload_len
+            || (!is_last && content.len() != self.max_payload_len) {
+            return Err(ReassemblyError::InvalidLength);
+        }
+        let offset = packet_index * self.max_payload_len;
+        let end = offset + content.len();
+        if end > self.buf.len() {
+            return Err(ReassemblyError::BufferTooSmall);
+        }
+
+        let fresh = Reassembly {
+            content: header.content,
+            message_id: header.message_id,
+            packet_count: header.packet_count,
+            received: 0,
+            message_len: 0,
+        };
+        let mut state = match self.state {
+            Some(state) if state.message_id == header.message_id && !state.is_complete() => {
+                if state.content != header.content || state.packet_count != header.packet_count {
+                    return Err(ReassemblyError::Inconsistent);
+                }
+                let is_retry = state.received & (1 << packet_index) != 0;
+                if is_retry && (self.buf[offset..end] != *content || (is_last && state.message_len != end)) {
+                    fresh
+                } else {
+                    state
+                }
+            }
+            _ => fresh,
+        };
+
+        self.buf[offset..end].copy_from_slice(content);
+        state.received |= 1 << packet_index;
+        if is_last {
+            state.message_len = end;
+        }
+        self.state = Some(state);
+
+        Ok(self.is_complete())
+    }
+
+    /// Returns true iff all packets of the message have been received.
+    pub fn is_complete(&self) -> bool {
+        match self.state {
+            Some(state) => state.is_complete(),
+            None => false,
+        }
+    }
+
+    /// Returns the content type and the reassembled message if complete.
+    pub fn message(&self) -> Option<(ContentType, &[u8])> {
+        match self.state {
+            Some(state) if self.is_complete() => Some((state.content, &self.buf[..state.message_len])),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn content_type() -> impl Strategy<Value = ContentType> {
+        any::<u8>().prop_filter_map("not a content type", ContentType::from_wire_value)
+    }
+
+    #[test]
+    fn crc16_check_value() {
+        assert_eq!(crc16(0xffff, b"123456789"), 0x29b1);
+    }
+
+    #[test]
+    fn packet_round_trip() {
+        let mut buf = [0; 64];
+        let mut cursor = Cursor::new(&mut buf);
+        write_packet(&mut cursor, ContentType::Manticore, 7, b"Hello", 0, 16).unwrap();
+        assert_eq!(cursor.consumed_len(), PACKET_HEADER_LEN + 5);
+
+        let mut data = cursor.consumed_bytes();
+        let (header, content) = read_packet(&mut data).unwrap();
+        assert_eq!(header.content, ContentType::Manticore);
+        assert_eq!(header.message_id, 7);
+        assert_eq!(header.packet_index, 0);
+        assert_eq!(header.packet_count, 1);
+        assert_eq!(content, b"Hello");
+    }
+
+    #[test]
+    fn packet_crc_mismatch() {
+        let mut buf = [0; 64];
+        let mut cursor = Cursor::new(&mut buf);
+        write_packet(&mut cursor, ContentType::Manticore, 7, b"Hello", 0, 16).unwrap();
+        let len = cursor.consumed_len();
+        buf[len - 1] ^= 0x01;
+        assert!(read_packet(&buf[..len]).is_err());
+    }
+
+    #[test]
+    fn reassemble_out_of_order_and_retried() {
+        let message: Vec<u8> = (0..40).collect();
+        let count = packet_count(message.len(), 16).unwrap();
+        assert_eq!(count, 3);
+
+        let mut packets = Vec::new();
+        for idx in 0..count {
+            let mut buf = [0; 64];
+            let mut cursor = Cursor::new(&mut buf);
+            write_packet(&mut cursor, ContentType::Manticore, 1, &message, idx, 16).unwrap();
+            packets.push(cursor.consumed_bytes().to_vec());
+        }
+
+        let mut buf = [0; 64];
+        let mut reassembler = Reassembler::new(&mut buf, 16);
+        for idx in &[2, 0, 0, 1] {
+            let (header, content) = read_packet(&packets[*idx][..]).unwrap();
+            let complete = reassembler.push(&header, content).unwrap();
+            assert_eq!(complete, *idx == 1);
+        }
+        assert_eq!(reassembler.message(), Some((ContentType::Manticore, &message[..])));
+    }
+
+    #[test]
+    fn reassemble_new_message_id_restarts() {
+        let mut buf = [0; 64];
+        let mut reassembler = Reassembler::new(&mut buf, 4);
+        let mut header = PacketHeader {
+            content: ContentType::Manticore,
+            message_id: 1,
+            packet_index: 0,
+            packet_count: 2,
+            content_len: 4,
+            crc: 0,
+        };
+        assert_eq!(reassembler.push(&header, b"abcd"), Ok(false));
+
+        header.message_id = 2;
+        header.packet_index = 1;
+        header.content_len = 1;
+        assert_eq!(reassembler.push(&header, b"e"), Ok(false));
+        assert_eq!(reassembler.message(), None);
+    }
+
+    #[test]
+    fn reassemble_reused_message_id() {
+        let mut buf = [0; 64];
+        let mut reassembler = Reassembler::new(&mut buf, 4);
+        let first = PacketHeader {
+            content: ContentType::Manticore,
+            message_id: 0,
+            packet_index: 0,
+            packet_count: 2,
+            content_len: 4,
+            crc: 0,
+        };
+        let last = PacketHeader { packet_index: 1, content_len: 1, ..first };
+        assert_eq!(reassembler.push(&first, b"abcd"), Ok(false));
+        assert_eq!(reassembler.push(&last, b"e"), Ok(true));
+        assert_eq!(reassembler.message(), Some((ContentType::Manticore, &b"abcde"[..])));
+
+        // A new message with the same id.
+        assert_eq!(reassembler.push(&first, b"fghi"), Ok(false));
+        assert_eq!(reassembler.message(), None);
+        assert_eq!(reassembler.push(&last, b"j"), Ok(true));
+        assert_eq!(reassembler.message(), Some((ContentType::Manticore, &b"fghij"[..])));
+    }
+
+    #[test]
+    fn reassemble_mismatched_retry_restarts() {
+        let mut buf = [0; 64];
+        let mut reassembler = Reassembler::new(&mut buf, 4);
+        let first = PacketHeader {
+            content: ContentType::Manticore,
+            message_id: 0,
+            packet_index: 0,
+            packet_count: 3,
+            content_len: 4,
+            crc: 0,
+        };
+        let second = PacketHeader { packet_index: 1, ..first };
+        let last = PacketHeader { packet_index: 2, content_len: 1, ..first };
+        assert_eq!(reassembler.push(&first, b"abcd"), Ok(false));
+        assert_eq!(reassembler.push(&second, b"efgh"), Ok(false));
+
+        // The sender restarted with a different message using the same id.
+        assert_eq!(reassembler.push(&first, b"ijkl"), Ok(false));
+        assert_eq!(reassembler.push(&last, b"m"), Ok(false));
+        assert_eq!(reassembler.push(&second, b"nopq"), Ok(true));
+        assert_eq!(reassembler.message(), Some((ContentType::Manticore, &b"ijklnopqm"[..])));
+    }
+
+    #[test]
+    fn reassemble_invalid_packets() {
+        let mut buf = [0; 8];
+        let mut reassembler = Reassembler::new(&mut buf, 4);
+        let header = PacketHeader {
+            content: ContentType::Manticore,
+            message_id: 1,
+            packet_index: 0,
+            packet_count: 3,
+            content_len: 3,
+            crc: 0,
+        };
+        assert_eq!(reassembler.push(&header, b"abc"), Err(ReassemblyError::InvalidLength));
+
+        let header = PacketHeader { packet_index: 3, ..header };
+        assert_eq!(reassembler.push(&header, b"abc"), Err(ReassemblyError::InvalidPacketIndex));
+
+        let header = PacketHeader { packet_index: 2, ..header };
+        assert_eq!(reassembler.push(&header, b"abc"), Err(ReassemblyError::BufferTooSmall));
+    }
+
+    struct Echo;
+
+    impl PayloadHandler for Echo {
+        type Error = ();
+
+        fn process(&mut self, request: &[u8], response: &mut [u8]) -> Result<usize, ()> {
+            response[..request.len()].copy_from_slice(request);
+            Ok(request.len())
+        }
+    }
+
+    #[test]
+    fn dispatch() {
+        let mut echo = Echo;
+        let mut dispatcher = Dispatcher::new();
+        dispatcher.register(ContentType::VendorDiagnostics, &mut echo).unwrap();
+        assert!(dispatcher.is_supported(ContentType::VendorDiagnostics));
+        assert!(!dispatcher.is_supported(ContentType::Manticore));
+
+        let mut response = [0xff; 16];
+        let len = dispatcher.dispatch(&[0x04, 0x00, 0x02, 0xaa, 0xbb, 0xcc], &mut response).unwrap();
+        assert_eq!(&response[..len], &[0x04, 0x00, 0x02, 0xaa, 0xbb]);
+
+        match dispatcher.dispatch(&[0x01, 0x00, 0x00], &mut response) {
+            Err(DispatchError::UnsupportedContentType(ContentType::Manticore)) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let len = dispatcher.dispatch_content(ContentType::VendorDiagnostics, &[0xaa, 0xbb], &mut response)
+            .unwrap();
+        assert_eq!(&response[..len], &[0xaa, 0xbb]);
+    }
+
+    proptest! {
+        #[test]
+        fn header_round_trip(content in content_type(), content_len in any::<u16>()) {
+            let header = Header { content, content_len };
+            let mut buf = [0u8; HEADER_LEN];
+            header.to_wire(Cursor::new(&mut buf)).unwrap();
+            prop_assert_eq!(Header::from_wire(&buf[..]), Ok(header));
+        }
+
+        #[test]
+        fn header_from_arbitrary_bytes(input in prop::collection::vec(any::<u8>(), 0..=HEADER_LEN + 1)) {
+            if let Ok(header) = Header::from_wire(&input[..]) {
+                let mut out = [0u8; HEADER_LEN];
+                header.to_wire(Cursor::new(&mut out)).unwrap();
+                prop_assert_eq!(&out[..], &input[..HEADER_LEN]);
+            }
+        }
+
+        #[test]
+        fn packets_round_trip(
+            message in prop::collection::vec(any::<u8>(), 0..=64),
+            max_payload_len in 0..16usize,
+            content in content_type(),
+            message_id in any::<u8>(),
+        ) {
+            let count = match packet_count(message.len(), max_payload_len) {
+                Some(count) => count,
+                None => {
+                    let mut buf = [0u8; 64];
+                    prop_assert!(write_packet(Cursor::new(&mut buf), content, message_id, &message, 0,
+                        max_payload_len).is_err());
+                    return Ok(());
+                }
+            };
+
+            let mut reassembled = [0u8; 64];
+            let mut reassembled_len = 0;
+            for index in 0..count {
+                let mut buf = [0u8; PACKET_HEADER_LEN + 16];
+                let mut cursor = Cursor::new(&mut buf);
+                write_packet(&mut cursor, content, message_id, &message, index, max_payload_len).unwrap();
+
+                let mut data = cursor.consumed_bytes();
+                let (header, packet) = read_packet(&mut data).unwrap();
+                prop_assert!(data.is_empty());
+                prop_assert_eq!(header.content, content);
+                prop_assert_eq!(header.message_id, message_id);
+                prop_assert_eq!((header.packet_index, header.packet_count), (index, count));
+                reassembled[reassembled_len..reassembled_len + packet.len()].copy_from_slice(packet);
+                reassembled_len += packet.len();
+            }
+            prop_assert_eq!(&reassembled[..reassembled_len], &message[..]);
+
+            let mut buf = [0u8; 64];
+            prop_assert!(write_packet(Cursor::new(&mut buf), content, message_id, &message, count,
+                max_payload_len).is_err());
+        }
+
+        #[test]
+        fn packet_from_arbitrary_bytes(
+            mut input in prop::collection::vec(any::<u8>(), 0..=PACKET_HEADER_LEN + 8),
+            fix_crc in any::<bool>(),
+        ) {
+            if input.len() >= PACKET_HEADER_LEN {
+                // Random bytes rarely have the right version and a short
+                // enough content.
+                input[0] = PACKET_VERSION;
+                input[5] = 0;
+                input[6] %= 8;
+            }
+            // Neither do they have the right CRC.
+            if let Ok(header) = PacketHeader::from_wire(&input[..]) {
+                let content = input.get(PACKET_HEADER_LEN..PACKET_HEADER_LEN + header.content_len as usize);
+                if let (Some(content), true) = (content, fix_crc) {
+                    let crc = header.compute_crc(content);
+                    input[PACKET_HEADER_LEN - 2..PACKET_HEADER_LEN].copy_from_slice(&crc.to_be_bytes());
+                }
+            }
+
+            let mut data = &input[..];
+            if let Ok((header, content)) = read_packet(&mut data) {
+                let consumed = input.len() - data.len();
+                prop_assert_eq!(consumed, PACKET_HEADER_LEN + content.len());
+
+                let mut out = [0u8; PACKET_HEADER_LEN];
+                header.to_wire(Cursor::new(&mut out)).unwrap();
+                prop_assert_eq!(&out[..], &input[..PACKET_HEADER_LEN]);
+            }
+        }
+    }
+}
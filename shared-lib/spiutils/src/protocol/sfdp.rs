@@ -0,0 +1,1566 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Serial Flash Discoverable Parameters (SFDP), as defined by JESD216.
+//!
+//! An SFDP structure starts with a [`Header`], followed by one or more
+//! [`ParameterHeader`]s, each of which points to a parameter table. The first
+//! table is always the [`BasicFlashParameters`] table.
+//!
+//! [`Sfdp`] builds a complete SFDP structure from a list of [`Table`]s, and
+//! [`SfdpReader`] parses one.
+//!
+//! All multi-byte values in SFDP are little-endian.
+//!
+//! [`Header`]: struct.Header.html
+//! [`ParameterHeader`]: struct.ParameterHeader.html
+//! [`BasicFlashParameters`]: struct.BasicFlashParameters.html
+//! [`Sfdp`]: struct.Sfdp.html
+//! [`SfdpReader`]: struct.SfdpReader.html
+//! [`Table`]: enum.Table.html
+
+use crate::io::Read;
+use crate::io::Write;
+use crate::protocol::flash::OpCode;
+use crate::protocol::wire::FieldError;
+use crate::protocol::wire::FromWire;
+use crate::protocol::wire::FromWireError;
+use crate::protocol::wire::ToWire;
+use crate::protocol::wire::ToWireError;
+use crate::protocol::wire::WireEnum;
+
+use core::convert::TryFrom;
+use core::time::Duration;
+
+/// The SFDP signature, "SFDP" in little-endian order.
+pub const SIGNATURE: u32 = 0x50444653;
+
+/// The length of the SFDP header on the wire, in bytes.
+pub const HEADER_LEN: usize = 8;
+
+/// The length of a parameter header on the wire, in bytes.
+pub const PARAMETER_HEADER_LEN: usize = 8;
+
+/// The length of a DWORD, in bytes.
+pub const DWORD_LEN: usize = 4;
+
+/// The SFDP major revision.
+pub const MAJOR_VERSION: u8 = 1;
+
+/// The SFDP minor revision for JESD216.
+pub const JESD216_MINOR_VERSION: u8 = 0;
+
+/// The SFDP minor revision for JESD216A.
+pub const JESD216A_MINOR_VERSION: u8 = 5;
+
+/// The SFDP minor revision for JESD216B.
+pub const JESD216B_MINOR_VERSION: u8 = 6;
+
+/// The SFDP minor revision for JESD216C.
+pub const JESD216C_MINOR_VERSION: u8 = 7;
+
+/// The SFDP minor revision for JESD216D.
+pub const JESD216D_MINOR_VERSION: u8 = 8;
+
+/// The access protocol of legacy devices, which don't specify one.
+pub const LEGACY_ACCESS_PROTOCOL: u8 = 0xff;
+
+/// The parameter ID of the Basic Flash Parameter Table.
+pub const BASIC_FLASH_PARAMETER_ID: u16 = 0xff00;
+
+/// The parameter ID of the 4-Byte Address Instruction Table.
+pub const FOUR_BYTE_ADDRESS_INSTRUCTION_ID: u16 = 0xff84;
+
+/// The length of the Basic Flash Parameter Table in JESD216, in DWORDs.
+pub const BASIC_FLASH_PARAMETER_JESD216_LEN: u8 = 9;
+
+/// The length of the Basic Flash Parameter Table in JESD216A and JESD216B,
+/// in DWORDs.
+pub const BASIC_FLASH_PARAMETER_JESD216B_LEN: u8 = 16;
+
+/// The length of the Basic Flash Parameter Table in JESD216C and JESD216D,
+/// in DWORDs.
+pub const BASIC_FLASH_PARAMETER_JESD216D_LEN: u8 = 20;
+
+/// The length of the 4-Byte Address Instruction Table, in DWORDs.
+pub const FOUR_BYTE_ADDRESS_INSTRUCTION_LEN: u8 = 2;
+
+/// The maximum table pointer, which is a 24-bit value.
+const MAX_TABLE_POINTER: u32 = 0xff_ffff;
+
+/// Returns `value` of the field `name` shifted to bit `lsb`, checking that it
+/// fits into `width` bits.
+fn field(name: &'static str, value: u32, lsb: u32, width: u32) -> Result<u32, ToWireError> {
+    if width < 32 && value >> width != 0 {
+        return Err(ToWireError::InvalidData(FieldError::new(name).with_value(value)));
+    }
+    Ok(value << lsb)
+}
+
+/// Returns the `width` bits of `dword` starting at bit `lsb`.
+fn bits(dword: u32, lsb: u32, width: u32) -> u32 {
+    ((dword as u64 >> lsb) & ((1u64 << width) - 1)) as u32
+}
+
+/// Returns whether bit `bit` of `dword` is set.
+fn bit(dword: u32, bit: u32) -> bool {
+    bits(dword, bit, 1) != 0
+}
+
+/// The SFDP header.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Header {
+    /// The SFDP minor revision.
+    pub minor_version: u8,
+
+    /// The SFDP major revision.
+    pub major_version: u8,
+
+    /// The number of parameter headers following the SFDP header.
+    ///
+    /// This is one more than the value on the wire. There is always at least
+    /// one parameter header.
+    pub parameter_header_count: u16,
+
+    /// The SFDP access protocol (JESD216B and later).
+    pub access_protocol: u8,
+}
+
+impl<'a> FromWire<'a> for Header {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let signature = r.read_le::<u32>()?;
+        if signature != SIGNATURE {
+            return Err(FromWireError::OutOfRange(FieldError::new("signature").with_value(signature).at(0)));
+        }
+        let minor_version = r.read_le::<u8>()?;
+        let major_version = r.read_le::<u8>()?;
+        let parameter_header_count = r.read_le::<u8>()? as u16 + 1;
+        let access_protocol = r.read_le::<u8>()?;
+        Ok(Self {
+            minor_version,
+            major_version,
+            parameter_header_count,
+            access_protocol,
+        })
+    }
+}
+
+impl ToWire for Header {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        let count = self.parameter_header_count.checked_sub(1)
+            .and_then(|count| u8::try_from(count).ok())
+            .ok_or_else(|| ToWireError::InvalidData(
+                FieldError::new("parameter_header_count").with_value(self.parameter_header_count)))?;
+        w.write_le(SIGNATURE)?;
+        w.write_le(self.minor_version)?;
+        w.write_le(self.major_version)?;
+        w.write_le(count)?;
+        w.write_le(self.access_protocol)?;
+        Ok(())
+    }
+}
+
+/// A parameter header, describing a parameter table.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ParameterHeader {
+    /// The parameter ID.
+    ///
+    /// The MSB is 0xff for tables defined by JEDEC. For vendor tables, the
+    /// MSB is the JEP106 bank number and the LSB is the manufacturer ID.
+    pub id: u16,
+
+    /// The minor revision of the table.
+    pub minor_version: u8,
+
+    /// The major revision of the table.
+    pub major_version: u8,
+
+    /// The length of the table, in DWORDs.
+    pub length: u8,
+
+    /// The byte offset of the table within the SFDP structure.
+    pub pointer: u32,
+}
+
+impl ParameterHeader {
+    /// Returns whether the table is a vendor table.
+    pub fn is_vendor(&self) -> bool {
+        self.id >> 8 != 0xff
+    }
+
+    /// Returns the length of the table, in bytes.
+    pub fn byte_len(&self) -> usize {
+        self.length as usize * DWORD_LEN
+    }
+}
+
+impl<'a> FromWire<'a> for ParameterHeader {
+    fn from_wire<R: Read<'a>>(mut r: R) -> Result<Self, FromWireError> {
+        let id_lsb = r.read_le::<u8>()?;
+        let minor_version = r.read_le::<u8>()?;
+        let major_version = r.read_le::<u8>()?;
+        let length = r.read_le::<u8>()?;
+        let pointer = r.read_le::<ux::u24>()?.into();
+        let id_msb = r.read_le::<u8>()?;
+        Ok(Self {
+            id: (id_msb as u16) << 8 | id_lsb as u16,
+            minor_version,
+            major_version,
+            length,
+            pointer,
+        })
+    }
+}
+
+impl ToWire for ParameterHeader {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        if self.pointer > MAX_TABLE_POINTER {
+            return Err(ToWireError::InvalidData(FieldError::new("pointer").with_value(self.pointer)));
+        }
+        w.write_le(self.id as u8)?;
+        w.write_le(self.minor_version)?;
+        w.write_le(self.major_version)?;
+        w.write_le(self.length)?;
+        w.write_le(ux::u24::new(self.pointer))?;
+        w.write_le((self.id >> 8) as u8)?;
+        Ok(())
+    }
+}
+
+/// The supported address bytes of a device.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AddressBytes {
+    /// The device only supports 3-byte addresses.
+    ThreeByteOnly = 0,
+
+    /// The device defaults to 3-byte addresses and supports entering 4-byte
+    /// address mode.
+    ThreeOrFourByte = 1,
+
+    /// The device only supports 4-byte addresses.
+    FourByteOnly = 2,
+}
+
+impl TryFrom<u32> for AddressBytes {
+    type Error = FromWireError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::ThreeByteOnly),
+            1 => Ok(Self::ThreeOrFourByte),
+            2 => Ok(Self::FourByteOnly),
+            _ => Err(FromWireError::OutOfRange(FieldError::new("address bytes").with_value(value))),
+        }
+    }
+}
+
+/// A fast read instruction.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FastRead {
+    /// The op code.
+    pub opcode: u8,
+
+    /// The number of mode clocks following the address.
+    pub mode_clocks: u8,
+
+    /// The number of wait states (dummy clocks) following the mode clocks.
+    pub dummy_clocks: u8,
+}
+
+impl FastRead {
+    /// Decodes a fast read instruction from the low 16 bits of `half`.
+    fn decode(half: u32) -> Self {
+        Self {
+            opcode: bits(half, 8, 8) as u8,
+            mode_clocks: bits(half, 5, 3) as u8,
+            dummy_clocks: bits(half, 0, 5) as u8,
+        }
+    }
+
+    /// Encodes the fast read instruction into 16 bits.
+    fn encode(&self) -> Result<u32, ToWireError> {
+        Ok(field("dummy_clocks", self.dummy_clocks as u32, 0, 5)?
+            | field("mode_clocks", self.mode_clocks as u32, 5, 3)?
+            | field("opcode", self.opcode as u32, 8, 8)?)
+    }
+}
+
+/// Encodes an optional fast read instruction into 16 bits.
+fn encode_fast_read(fast_read: &Option<FastRead>) -> Result<u32, ToWireError> {
+    fast_read.as_ref().map_or(Ok(0), FastRead::encode)
+}
+
+/// An erase type.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct EraseType {
+    /// The erase size, as a power of two.
+    pub size_exponent: u8,
+
+    /// The op code.
+    pub opcode: u8,
+}
+
+impl EraseType {
+    /// Returns the erase size, in bytes.
+    pub fn size(&self) -> u64 {
+        1u64.checked_shl(self.size_exponent as u32).unwrap_or(0)
+    }
+}
+
+/// Units for typical erase times.
+pub const ERASE_TIME_UNITS: [Duration; 4] = [
+    Duration::from_millis(1),
+    Duration::from_millis(16),
+    Duration::from_millis(128),
+    Duration::from_secs(1),
+];
+
+/// Units for typical page program times.
+pub const PAGE_PROGRAM_TIME_UNITS: [Duration; 2] = [
+    Duration::from_micros(8),
+    Duration::from_micros(64),
+];
+
+/// Units for typical byte program times.
+pub const BYTE_PROGRAM_TIME_UNITS: [Duration; 2] = [
+    Duration::from_micros(1),
+    Duration::from_micros(8),
+];
+
+/// Units for typical chip erase times.
+pub const CHIP_ERASE_TIME_UNITS: [Duration; 4] = [
+    Duration::from_millis(16),
+    Duration::from_millis(256),
+    Duration::from_secs(4),
+    Duration::from_secs(64),
+];
+
+/// Units for suspend and deep powerdown latencies.
+pub const LATENCY_UNITS: [Duration; 4] = [
+    Duration::from_nanos(128),
+    Duration::from_micros(1),
+    Duration::from_micros(8),
+    Duration::from_micros(64),
+];
+
+/// A time, encoded as `(count + 1) * unit`.
+///
+/// The meaning of `unit` depends on the field, see e.g. `ERASE_TIME_UNITS`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Time {
+    /// The count.
+    pub count: u8,
+
+    /// The index of the unit.
+    pub unit: u8,
+}
+
+impl Time {
+    /// Returns the time as a `Duration`, given the `units` of the field.
+    pub fn duration(&self, units: &[Duration]) -> Option<Duration> {
+        units.get(self.unit as usize)?.checked_mul(self.count as u32 + 1)
+    }
+
+    /// Decodes a time from the bits of `dword` starting at `lsb`.
+    fn decode(dword: u32, lsb: u32, count_width: u32, unit_width: u32) -> Self {
+        Self {
+            count: bits(dword, lsb, count_width) as u8,
+            unit: bits(dword, lsb + count_width, unit_width) as u8,
+        }
+    }
+
+    /// Encodes the time into bits starting at `lsb`.
+    fn encode(&self, lsb: u32, count_width: u32, unit_width: u32) -> Result<u32, ToWireError> {
+        Ok(field("time count", self.count as u32, lsb, count_width)?
+            | field("time unit", self.unit as u32, lsb + count_width, unit_width)?)
+    }
+}
+
+/// Suspend and resume parameters (DWORDs 12 and 13).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct SuspendResume {
+    /// Operations prohibited during program suspend.
+    pub program_suspend_prohibited: u8,
+
+    /// Operations prohibited during erase suspend.
+    pub erase_suspend_prohibited: u8,
+
+    /// The minimum interval from program resume to suspend, in units of
+    /// 64us minus one.
+    pub program_resume_to_suspend: u8,
+
+    /// The maximum latency of suspending a program, see `LATENCY_UNITS`.
+    pub program_suspend_latency: Time,
+
+    /// The minimum interval from erase resume to suspend, in units of 64us
+    /// minus one.
+    pub erase_resume_to_suspend: u8,
+
+    /// The maximum latency of suspending an erase, see `LATENCY_UNITS`.
+    pub erase_suspend_latency: Time,
+
+    /// The op code to resume a program.
+    pub program_resume_opcode: u8,
+
+    /// The op code to suspend a program.
+    pub program_suspend_opcode: u8,
+
+    /// The op code to resume a write or erase.
+    pub resume_opcode: u8,
+
+    /// The op code to suspend a write or erase.
+    pub suspend_opcode: u8,
+}
+
+/// Deep powerdown parameters (DWORD 14).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct DeepPowerdown {
+    /// The delay from exiting deep powerdown to the next operation, see
+    /// `LATENCY_UNITS`.
+    pub exit_delay: Time,
+
+    /// The op code to exit deep powerdown.
+    pub exit_opcode: u8,
+
+    /// The op code to enter deep powerdown.
+    pub enter_opcode: u8,
+}
+
+/// Status register polling: legacy polling of the WIP bit using op code 05h.
+pub const STATUS_POLLING_LEGACY: u8 = 1 << 0;
+
+/// Status register polling: polling bit 7 of the flag status register using
+/// op code 70h.
+pub const STATUS_POLLING_FLAG_STATUS: u8 = 1 << 1;
+
+/// Status register 1 is non-volatile and written after op code 06h.
+pub const STATUS_REGISTER_1_NON_VOLATILE: u8 = 1 << 0;
+
+/// Status register 1 is volatile, powers up as all 1s and is written after
+/// op code 06h.
+pub const STATUS_REGISTER_1_VOLATILE_06: u8 = 1 << 1;
+
+/// Status register 1 is volatile, powers up as all 1s and is written after
+/// op code 50h.
+pub const STATUS_REGISTER_1_VOLATILE_50: u8 = 1 << 2;
+
+/// Exit 4-byte address mode: op code E9h.
+pub const EXIT_4BYTE_E9: u16 = 1 << 0;
+
+/// Exit 4-byte address mode: op code 06h followed by op code E9h.
+pub const EXIT_4BYTE_WRITE_ENABLE_E9: u16 = 1 << 1;
+
+/// Exit 4-byte address mode: hardware reset.
+pub const EXIT_4BYTE_HARDWARE_RESET: u16 = 1 << 5;
+
+/// Exit 4-byte address mode: software reset.
+pub const EXIT_4BYTE_SOFTWARE_RESET: u16 = 1 << 6;
+
+/// Exit 4-byte address mode: power cycle.
+pub const EXIT_4BYTE_POWER_CYCLE: u16 = 1 << 7;
+
+/// Enter 4-byte address mode: op code B7h.
+pub const ENTER_4BYTE_B7: u8 = 1 << 0;
+
+/// Enter 4-byte address mode: op code 06h followed by op code B7h.
+pub const ENTER_4BYTE_WRITE_ENABLE_B7: u8 = 1 << 1;
+
+/// Enter 4-byte address mode: dedicated 4-byte address instructions.
+pub const ENTER_4BYTE_INSTRUCTIONS: u8 = 1 << 5;
+
+/// Enter 4-byte address mode: the device always operates in 4-byte address
+/// mode.
+pub const ENTER_4BYTE_ALWAYS: u8 = 1 << 6;
+
+/// Basic Flash Parameter Table DWORDs added in JESD216A (DWORDs 10 to 16).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Jesd216bParameters {
+    /// The multiplier from typical to maximum erase time, where
+    /// `max = 2 * (multiplier + 1) * typical`.
+    pub erase_time_multiplier: u8,
+
+    /// The typical erase time for each erase type, see `ERASE_TIME_UNITS`.
+    pub erase_times: [Time; 4],
+
+    /// The multiplier from typical to maximum program time, where
+    /// `max = 2 * (multiplier + 1) * typical`.
+    pub program_time_multiplier: u8,
+
+    /// The page size, as a power of two.
+    pub page_size_exponent: u8,
+
+    /// The typical page program time, see `PAGE_PROGRAM_TIME_UNITS`.
+    pub page_program_time: Time,
+
+    /// The typical time to program the first byte, see
+    /// `BYTE_PROGRAM_TIME_UNITS`.
+    pub first_byte_program_time: Time,
+
+    /// The typical time to program each additional byte, see
+    /// `BYTE_PROGRAM_TIME_UNITS`.
+    pub additional_byte_program_time: Time,
+
+    /// The typical chip erase time, see `CHIP_ERASE_TIME_UNITS`.
+    pub chip_erase_time: Time,
+
+    /// The suspend and resume parameters, or `None` if unsupported.
+    pub suspend_resume: Option<SuspendResume>,
+
+    /// The supported status register polling methods, see
+    /// `STATUS_POLLING_*`.
+    pub status_polling: u8,
+
+    /// The deep powerdown parameters, or `None` if unsupported.
+    pub deep_powerdown: Option<DeepPowerdown>,
+
+    /// The 4-4-4 mode disable sequences.
+    pub quad_disable_sequences: u8,
+
+    /// The 4-4-4 mode enable sequences.
+    pub quad_enable_sequences: u8,
+
+    /// Whether 0-4-4 mode is supported.
+    pub mode_0_4_4: bool,
+
+    /// The 0-4-4 mode exit methods.
+    pub mode_0_4_4_exit: u8,
+
+    /// The 0-4-4 mode entry methods.
+    pub mode_0_4_4_entry: u8,
+
+    /// The quad enable requirements.
+    pub quad_enable_requirements: u8,
+
+    /// Whether HOLD and WIP can be disabled.
+    pub hold_reset_disable: bool,
+
+    /// The status register 1 write enable method, see
+    /// `STATUS_REGISTER_1_*`.
+    pub status_register_1: u8,
+
+    /// The supported soft reset sequences.
+    pub soft_reset: u8,
+
+    /// The methods to exit 4-byte address mode, see `EXIT_4BYTE_*`.
+    pub exit_4byte: u16,
+
+    /// The methods to enter 4-byte address mode, see `ENTER_4BYTE_*`.
+    pub enter_4byte: u8,
+}
+
+impl Jesd216bParameters {
+    /// Returns the maximum time of erase type `index`, or `None` if it
+    /// doesn't fit into a `Duration`.
+    pub fn max_erase_time(&self, index: usize) -> Option<Duration> {
+        Self::max_time(self.erase_times.get(index)?.duration(&ERASE_TIME_UNITS)?, self.erase_time_multiplier)
+    }
+
+    /// Returns the maximum page program time, or `None` if it doesn't fit
+    /// into a `Duration`.
+    pub fn max_page_program_time(&self) -> Option<Duration> {
+        Self::max_time(self.page_program_time.duration(&PAGE_PROGRAM_TIME_UNITS)?, self.program_time_multiplier)
+    }
+
+    /// Returns the maximum chip erase time, or `None` if it doesn't fit
+    /// into a `Duration`.
+    pub fn max_chip_erase_time(&self) -> Option<Duration> {
+        Self::max_time(self.chip_erase_time.duration(&CHIP_ERASE_TIME_UNITS)?, self.erase_time_multiplier)
+    }
+
+    /// Returns `2 * (multiplier + 1) * typical`.
+    fn max_time(typical: Duration, multiplier: u8) -> Option<Duration> {
+        typical.checked_mul(2 * (multiplier as u32 + 1))
+    }
+
+    /// Decodes DWORDs 10 to 16.
+    fn decode(dwords: &[u32]) -> Self {
+        let mut erase_times = [Time::default(); 4];
+        for (i, time) in erase_times.iter_mut().enumerate() {
+            *time = Time::decode(dwords[0], 4 + 7 * i as u32, 5, 2);
+        }
+        let suspend_resume = if bit(dwords[2], 31) {
+            None
+        } else {
+            Some(SuspendResume {
+                program_suspend_prohibited: bits(dwords[2], 0, 4) as u8,
+                erase_suspend_prohibited: bits(dwords[2], 4, 4) as u8,
+                program_resume_to_suspend: bits(dwords[2], 9, 4) as u8,
+                program_suspend_latency: Time::decode(dwords[2], 13, 5, 2),
+                erase_resume_to_suspend: bits(dwords[2], 20, 4) as u8,
+                erase_suspend_latency: Time::decode(dwords[2], 24, 5, 2),
+                program_resume_opcode: bits(dwords[3], 0, 8) as u8,
+                program_suspend_opcode: bits(dwords[3], 8, 8) as u8,
+                resume_opcode: bits(dwords[3], 16, 8) as u8,
+                suspend_opcode: bits(dwords[3], 24, 8) as u8,
+            })
+        };
+        let deep_powerdown = if bit(dwords[4], 31) {
+            None
+        } else {
+            Some(DeepPowerdown {
+                exit_delay: Time::decode(dwords[4], 8, 5, 2),
+                exit_opcode: bits(dwords[4], 15, 8) as u8,
+                enter_opcode: bits(dwords[4], 23, 8) as u8,
+            })
+        };
+        Self {
+            erase_time_multiplier: bits(dwords[0], 0, 4) as u8,
+            erase_times,
+            program_time_multiplier: bits(dwords[1], 0, 4) as u8,
+            page_size_exponent: bits(dwords[1], 4, 4) as u8,
+            page_program_time: Time::decode(dwords[1], 8, 5, 1),
+            first_byte_program_time: Time::decode(dwords[1], 14, 4, 1),
+            additional_byte_program_time: Time::decode(dwords[1], 19, 4, 1),
+            chip_erase_time: Time::decode(dwords[1], 24, 5, 2),
+            suspend_resume,
+            status_polling: bits(dwords[4], 2, 6) as u8,
+            deep_powerdown,
+            quad_disable_sequences: bits(dwords[5], 0, 4) as u8,
+            quad_enable_sequences: bits(dwords[5], 4, 5) as u8,
+            mode_0_4_4: bit(dwords[5], 9),
+            mode_0_4_4_exit: bits(dwords[5], 10, 6) as u8,
+            mode_0_4_4_entry: bits(dwords[5], 16, 4) as u8,
+            quad_enable_requirements: bits(dwords[5], 20, 3) as u8,
+            hold_reset_disable: bit(dwords[5], 23),
+            status_register_1: bits(dwords[6], 0, 7) as u8,
+            soft_reset: bits(dwords[6], 8, 6) as u8,
+            exit_4byte: bits(dwords[6], 14, 10) as u16,
+            enter_4byte: bits(dwords[6], 24, 8) as u8,
+        }
+    }
+
+    /// Encodes DWORDs 10 to 16.
+    fn encode(&self) -> Result<[u32; 7], ToWireError> {
+        let mut dword10 = field("erase_time_multiplier", self.erase_time_multiplier as u32, 0, 4)?;
+        for (i, time) in self.erase_times.iter().enumerate() {
+            dword10 |= time.encode(4 + 7 * i as u32, 5, 2)?;
+        }
+
+        let dword11 = field("program_time_multiplier", self.program_time_multiplier as u32, 0, 4)?
+            | field("page_size_exponent", self.page_size_exponent as u32, 4, 4)?
+            | self.page_program_time.encode(8, 5, 1)?
+            | self.first_byte_program_time.encode(14, 4, 1)?
+            | self.additional_byte_program_time.encode(19, 4, 1)?
+            | self.chip_erase_time.encode(24, 5, 2)?
+            | 1 << 31;
+
+        let (dword12, dword13) = match &self.suspend_resume {
+            None => (1 << 8 | 1 << 31, 0),
+            Some(sr) => (
+                field("program_suspend_prohibited", sr.program_suspend_prohibited as u32, 0, 4)?
+                    | field("erase_suspend_prohibited", sr.erase_suspend_prohibited as u32, 4, 4)?
+                    | 1 << 8
+                    | field("program_resume_to_suspend", sr.program_resume_to_suspend as u32, 9, 4)?
+                    | sr.program_suspend_latency.encode(13, 5, 2)?
+                    | field("erase_resume_to_suspend", sr.erase_resume_to_suspend as u32, 20, 4)?
+                    | sr.erase_suspend_latency.encode(24, 5, 2)?,
+                field("program_resume_opcode", sr.program_resume_opcode as u32, 0, 8)?
+                    | field("program_suspend_opcode", sr.program_suspend_opcode as u32, 8, 8)?
+                    | field("resume_opcode", sr.resume_opcode as u32, 16, 8)?
+                    | field("suspend_opcode", sr.suspend_opcode as u32, 24, 8)?,
+            ),
+        };
+
+        let deep_powerdown = match &self.deep_powerdown {
+            None => 1 << 31,
+            Some(dpd) => dpd.exit_delay.encode(8, 5, 2)?
+                | field("exit_opcode", dpd.exit_opcode as u32, 15, 8)?
+                | field("enter_opcode", dpd.enter_opcode as u32, 23, 8)?,
+        };
+        let dword14 = 0x3 | field("status_polling", self.status_polling as u32, 2, 6)? | deep_powerdown;
+
+        let dword15 = field("quad_disable_sequences", self.quad_disable_sequences as u32, 0, 4)?
+            | field("quad_enable_sequences", self.quad_enable_sequences as u32, 4, 5)?
+            | field("mode_0_4_4", self.mode_0_4_4 as u32, 9, 1)?
+            | field("mode_0_4_4_exit", self.mode_0_4_4_exit as u32, 10, 6)?
+            | field("mode_0_4_4_entry", self.mode_0_4_4_entry as u32, 16, 4)?
+            | field("quad_enable_requirements", self.quad_enable_requirements as u32, 20, 3)?
+            | field("hold_reset_disable", self.hold_reset_disable as u32, 23, 1)?
+            | 0xff << 24;
+
+        let dword16 = field("status_register_1", self.status_register_1 as u32, 0, 7)?
+            | 1 << 7
+            | field("soft_reset", self.soft_reset as u32, 8, 6)?
+            | field("exit_4byte", self.exit_4byte as u32, 14, 10)?
+            | field("enter_4byte", self.enter_4byte as u32, 24, 8)?;
+
+        Ok([dword10, dword11, dword12, dword13, dword14, dword15, dword16])
+    }
+}
+
+/// Basic Flash Parameter Table DWORDs added in JESD216C (DWORDs 17 to 20).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Jesd216dParameters {
+    /// The 1-1-8 fast read instruction, or `None` if unsupported.
+    pub fast_read_1_1_8: Option<FastRead>,
+
+    /// The 1-8-8 fast read instruction, or `None` if unsupported.
+    pub fast_read_1_8_8: Option<FastRead>,
+
+    /// DWORDs 18 to 20, which describe octal DTR (8D-8D-8D) operation and
+    /// maximum operating speeds. These are not interpreted.
+    pub octal_dwords: [u32; 3],
+}
+
+impl Jesd216dParameters {
+    /// Decodes DWORDs 17 to 20.
+    fn decode(dwords: &[u32]) -> Self {
+        let decode = |half: u32| Some(FastRead::decode(half)).filter(|fast_read| fast_read.opcode != 0);
+        Self {
+            fast_read_1_8_8: decode(bits(dwords[0], 0, 16)),
+            fast_read_1_1_8: decode(bits(dwords[0], 16, 16)),
+            octal_dwords: [dwords[1], dwords[2], dwords[3]],
+        }
+    }
+
+    /// Encodes DWORDs 17 to 20.
+    fn encode(&self) -> Result<[u32; 4], ToWireError> {
+        let dword17 = encode_fast_read(&self.fast_read_1_8_8)?
+            | encode_fast_read(&self.fast_read_1_1_8)? << 16;
+        Ok([dword17, self.octal_dwords[0], self.octal_dwords[1], self.octal_dwords[2]])
+    }
+}
+
+/// The Basic Flash Parameter Table.
+///
+/// Every SFDP structure contains this table. The fields of JESD216 (DWORDs 1
+/// to 9) are always present. `jesd216b` holds the fields added in
+/// JESD216A/B and `jesd216d` holds the fields added in JESD216C/D.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BasicFlashParameters {
+    /// The minor revision of the table.
+    pub minor_version: u8,
+
+    /// Whether 4KiB erase is uniformly available.
+    pub uniform_4k_erase: bool,
+
+    /// Whether the write buffer is at least 64 bytes.
+    pub write_granularity_64: bool,
+
+    /// Whether writing the volatile status register requires a write enable.
+    pub volatile_status_write_enable: bool,
+
+    /// Whether the write enable for the volatile status register is op code
+    /// 06h, rather than 50h.
+    pub volatile_status_write_enable_06: bool,
+
+    /// The 4KiB erase op code, or 0xff if unsupported.
+    pub erase_4k_opcode: u8,
+
+    /// The supported address bytes.
+    pub address_bytes: AddressBytes,
+
+    /// Whether double transfer rate clocking is supported.
+    pub dtr: bool,
+
+    /// The density of the device, in bits.
+    pub density_bits: u64,
+
+    /// The 1-1-2 fast read instruction, or `None` if unsupported.
+    pub fast_read_1_1_2: Option<FastRead>,
+
+    /// The 1-2-2 fast read instruction, or `None` if unsupported.
+    pub fast_read_1_2_2: Option<FastRead>,
+
+    /// The 2-2-2 fast read instruction, or `None` if unsupported.
+    pub fast_read_2_2_2: Option<FastRead>,
+
+    /// The 1-1-4 fast read instruction, or `None` if unsupported.
+    pub fast_read_1_1_4: Option<FastRead>,
+
+    /// The 1-4-4 fast read instruction, or `None` if unsupported.
+    pub fast_read_1_4_4: Option<FastRead>,
+
+    /// The 4-4-4 fast read instruction, or `None` if unsupported.
+    pub fast_read_4_4_4: Option<FastRead>,
+
+    /// The erase types, or `None` for unavailable ones.
+    pub erase_types: [Option<EraseType>; 4],
+
+    /// The JESD216A/B parameters, if present.
+    pub jesd216b: Option<Jesd216bParameters>,
+
+    /// The JESD216C/D parameters, if present. These require `jesd216b`.
+    pub jesd216d: Option<Jesd216dParameters>,
+}
+
+impl BasicFlashParameters {
+    /// Returns the length of the table, in DWORDs.
+    pub fn dword_len(&self) -> u8 {
+        match (&self.jesd216b, &self.jesd216d) {
+            (_, Some(_)) => BASIC_FLASH_PARAMETER_JESD216D_LEN,
+            (Some(_), None) => BASIC_FLASH_PARAMETER_JESD216B_LEN,
+            (None, None) => BASIC_FLASH_PARAMETER_JESD216_LEN,
+        }
+    }
+
+    /// Deserializes a `BasicFlashParameters` table of `len` DWORDs from `r`.
+    ///
+    /// DWORDs beyond the ones defined by JESD216D are skipped.
+    pub fn from_wire<'a, R: Read<'a>>(mut r: R, minor_version: u8, len: u8) -> Result<Self, FromWireError> {
+        if len < BASIC_FLASH_PARAMETER_JESD216_LEN {
+            return Err(FromWireError::OutOfRange(FieldError::new("table length").with_value(len)));
+        }
+        let mut dwords = [0u32; BASIC_FLASH_PARAMETER_JESD216D_LEN as usize];
+        for i in 0..len as usize {
+            let dword = r.read_le::<u32>()?;
+            if let Some(entry) = dwords.get_mut(i) {
+                *entry = dword;
+            }
+        }
+
+        let fast_read = |supported: bool, half: u32| Some(FastRead::decode(half)).filter(|_| supported);
+        let density_bits = if bit(dwords[1], 31) {
+            1u64.checked_shl(bits(dwords[1], 0, 31))
+                .ok_or_else(|| FromWireError::OutOfRange(FieldError::new("density").with_value(dwords[1]).at(4)))?
+        } else {
+            dwords[1] as u64 + 1
+        };
+        let mut erase_types = [None; 4];
+        for (i, erase_type) in erase_types.iter_mut().enumerate() {
+            let half = bits(dwords[7 + i / 2], 16 * (i as u32 % 2), 16);
+            if bits(half, 0, 8) != 0 {
+                *erase_type = Some(EraseType {
+                    size_exponent: bits(half, 0, 8) as u8,
+                    opcode: bits(half, 8, 8) as u8,
+                });
+            }
+        }
+        let jesd216b = if len >= BASIC_FLASH_PARAMETER_JESD216B_LEN {
+            Some(Jesd216bParameters::decode(&dwords[9..16]))
+        } else {
+            None
+        };
+        let jesd216d = if len >= BASIC_FLASH_PARAMETER_JESD216D_LEN {
+            Some(Jesd216dParameters::decode(&dwords[16..20]))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            minor_version,
+            uniform_4k_erase: bits(dwords[0], 0, 2) == 0x1,
+            write_granularity_64: bit(dwords[0], 2),
+            volatile_status_write_enable: bit(dwords[0], 3),
+            volatile_status_write_enable_06: bit(dwords[0], 4),
+            erase_4k_opcode: bits(dwords[0], 8, 8) as u8,
+            address_bytes: AddressBytes::try_from(bits(dwords[0], 17, 2))?,
+            dtr: bit(dwords[0], 19),
+            density_bits,
+            fast_read_1_1_2: fast_read(bit(dwords[0], 16), bits(dwords[3], 0, 16)),
+            fast_read_1_2_2: fast_read(bit(dwords[0], 20), bits(dwords[3], 16, 16)),
+            fast_read_2_2_2: fast_read(bit(dwords[4], 0), bits(dwords[5], 16, 16)),
+            fast_read_1_1_4: fast_read(bit(dwords[0], 22), bits(dwords[2], 16, 16)),
+            fast_read_1_4_4: fast_read(bit(dwords[0], 21), bits(dwords[2], 0, 16)),
+            fast_read_4_4_4: fast_read(bit(dwords[4], 4), bits(dwords[6], 16, 16)),
+            erase_types,
+            jesd216b,
+            jesd216d,
+        })
+    }
+}
+
+impl ToWire for BasicFlashParameters {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        if self.jesd216d.is_some() && self.jesd216b.is_none() {
+            return Err(ToWireError::InvalidData(FieldError::new("jesd216b")));
+        }
+
+        let dword1 = field("uniform_4k_erase", if self.uniform_4k_erase { 0x1 } else { 0x3 }, 0, 2)?
+            | field("write_granularity_64", self.write_granularity_64 as u32, 2, 1)?
+            | field("volatile_status_write_enable", self.volatile_status_write_enable as u32, 3, 1)?
+            | field("volatile_status_write_enable_06", self.volatile_status_write_enable_06 as u32, 4, 1)?
+            | 0x7 << 5
+            | field("erase_4k_opcode", self.erase_4k_opcode as u32, 8, 8)?
+            | field("fast_read_1_1_2", self.fast_read_1_1_2.is_some() as u32, 16, 1)?
+            | field("address_bytes", self.address_bytes as u32, 17, 2)?
+            | field("dtr", self.dtr as u32, 19, 1)?
+            | field("fast_read_1_2_2", self.fast_read_1_2_2.is_some() as u32, 20, 1)?
+            | field("fast_read_1_4_4", self.fast_read_1_4_4.is_some() as u32, 21, 1)?
+            | field("fast_read_1_1_4", self.fast_read_1_1_4.is_some() as u32, 22, 1)?
+            | 0x1ff << 23;
+
+        let density_error = ToWireError::InvalidData(FieldError::new("density_bits").with_value(self.density_bits));
+        let dword2 = if self.density_bits == 0 {
+            return Err(density_error);
+        } else if self.density_bits <= 1 << 31 {
+            (self.density_bits - 1) as u32
+        } else if self.density_bits.is_power_of_two() {
+            1 << 31 | self.density_bits.trailing_zeros()
+        } else {
+            return Err(density_error);
+        };
+
+        let dword3 = encode_fast_read(&self.fast_read_1_4_4)?
+            | encode_fast_read(&self.fast_read_1_1_4)? << 16;
+        let dword4 = encode_fast_read(&self.fast_read_1_1_2)?
+            | encode_fast_read(&self.fast_read_1_2_2)? << 16;
+        let dword5 = !(1 << 0 | 1 << 4)
+            | field("fast_read_2_2_2", self.fast_read_2_2_2.is_some() as u32, 0, 1)?
+            | field("fast_read_4_4_4", self.fast_read_4_4_4.is_some() as u32, 4, 1)?;
+        let dword6 = 0xffff | encode_fast_read(&self.fast_read_2_2_2)? << 16;
+        let dword7 = 0xffff | encode_fast_read(&self.fast_read_4_4_4)? << 16;
+
+        let mut erase_dwords = [0u32; 2];
+        for (i, erase_type) in self.erase_types.iter().enumerate() {
+            if let Some(erase_type) = erase_type {
+                if erase_type.size_exponent == 0 {
+                    return Err(ToWireError::InvalidData(FieldError::new("erase size_exponent").with_value(0u8)));
+                }
+                let half = erase_type.size_exponent as u32 | (erase_type.opcode as u32) << 8;
+                erase_dwords[i / 2] |= half << (16 * (i % 2));
+            }
+        }
+
+        for dword in [dword1, dword2, dword3, dword4, dword5, dword6, dword7].iter()
+            .chain(erase_dwords.iter()) {
+            w.write_le(*dword)?;
+        }
+        if let Some(jesd216b) = &self.jesd216b {
+            for dword in jesd216b.encode()?.iter() {
+                w.write_le(*dword)?;
+            }
+        }
+        if let Some(jesd216d) = &self.jesd216d {
+            for dword in jesd216d.encode()?.iter() {
+                w.write_le(*dword)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The 4-Byte Address Instruction Table.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FourByteAddressInstructions {
+    /// The minor revision of the table.
+    pub minor_version: u8,
+
+    /// The bitmap of supported 4-byte address instructions (DWORD 1).
+    ///
+    /// Bits 9 to 12 indicate support for erase types 1 to 4.
+    pub supported: u32,
+
+    /// The 4-byte address op codes of erase types 1 to 4, or `None` for
+    /// unsupported ones.
+    pub erase_opcodes: [Option<u8>; 4],
+}
+
+/// The bit in `FourByteAddressInstructions::supported` for erase type 1.
+const FOUR_BYTE_ERASE_TYPE_1: u32 = 9;
+
+impl FourByteAddressInstructions {
+    /// Returns the bit in `supported` for `opcode`, if any.
+    fn opcode_bit(opcode: OpCode) -> Option<u32> {
+        match opcode {
+            OpCode::NormalRead4B => Some(0),
+            OpCode::FastRead4B => Some(1),
+            OpCode::FastReadDualOutput4B => Some(2),
+            OpCode::FastReadDualIo4B => Some(3),
+            OpCode::FastReadQuadOutput4B => Some(4),
+            OpCode::FastReadQuadIo4B => Some(5),
+            OpCode::PageProgram4B => Some(6),
+            OpCode::QuadPageProgram4B => Some(7),
+            OpCode::QuadIoPageProgram4B => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the table indicates support for `opcode`.
+    pub fn supports(&self, opcode: OpCode) -> bool {
+        if let Some(opcode_bit) = Self::opcode_bit(opcode) {
+            return bit(self.supported, opcode_bit);
+        }
+        self.erase_opcodes.iter().enumerate().any(|(i, erase_opcode)| {
+            *erase_opcode == Some(opcode.to_wire_value())
+                && bit(self.supported, FOUR_BYTE_ERASE_TYPE_1 + i as u32)
+        })
+    }
+
+    /// Marks `opcode` as supported.
+    ///
+    /// Returns `false` if `opcode` isn't a read or program instruction listed
+    /// in the table. Erase instructions are set using `set_erase_opcode`.
+    pub fn set_supported(&mut self, opcode: OpCode) -> bool {
+        match Self::opcode_bit(opcode) {
+            Some(opcode_bit) => {
+                self.supported |= 1 << opcode_bit;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Sets the 4-byte address op code of erase type `index` (0 to 3).
+    pub fn set_erase_opcode(&mut self, index: usize, opcode: Option<u8>) {
+        if let Some(erase_opcode) = self.erase_opcodes.get_mut(index) {
+            *erase_opcode = opcode;
+            let mask = 1 << (FOUR_BYTE_ERASE_TYPE_1 + index as u32);
+            if opcode.is_some() {
+                self.supported |= mask;
+            } else {
+                self.supported &= !mask;
+            }
+        }
+    }
+
+    /// Deserializes a `FourByteAddressInstructions` table of `len` DWORDs
+    /// from `r`.
+    pub fn from_wire<'a, R: Read<'a>>(mut r: R, minor_version: u8, len: u8) -> Result<Self, FromWireError> {
+        if len < FOUR_BYTE_ADDRESS_INSTRUCTION_LEN {
+            return Err(FromWireError::OutOfRange(FieldError::new("table length").with_value(len)));
+        }
+        let supported = r.read_le::<u32>()?;
+        let mut erase_opcodes = [None; 4];
+        for erase_opcode in erase_opcodes.iter_mut() {
+            *erase_opcode = Some(r.read_le::<u8>()?).filter(|opcode| *opcode != 0xff);
+        }
+        let _ = r.read_bytes((len - FOUR_BYTE_ADDRESS_INSTRUCTION_LEN) as usize * DWORD_LEN)?;
+        Ok(Self {
+            minor_version,
+            supported,
+            erase_opcodes,
+        })
+    }
+}
+
+impl ToWire for FourByteAddressInstructions {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        w.write_le(self.supported)?;
+        for erase_opcode in self.erase_opcodes.iter() {
+            w.write_le(erase_opcode.unwrap_or(0xff))?;
+        }
+        Ok(())
+    }
+}
+
+/// A vendor table, or any other table that isn't interpreted.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VendorTable<'a> {
+    /// The parameter ID.
+    pub id: u16,
+
+    /// The minor revision of the table.
+    pub minor_version: u8,
+
+    /// The major revision of the table.
+    pub major_version: u8,
+
+    /// The contents of the table. The length must be a multiple of
+    /// `DWORD_LEN`.
+    pub data: &'a [u8],
+}
+
+impl VendorTable<'_> {
+    /// Returns DWORD `index` of the table, if present.
+    pub fn dword(&self, index: usize) -> Option<u32> {
+        let mut data = self.data.get(index * DWORD_LEN..)?;
+        data.read_le::<u32>().ok()
+    }
+}
+
+/// A parameter table.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Table<'a> {
+    /// The Basic Flash Parameter Table.
+    BasicFlash(BasicFlashParameters),
+
+    /// The 4-Byte Address Instruction Table.
+    FourByteAddress(FourByteAddressInstructions),
+
+    /// A vendor table.
+    Vendor(VendorTable<'a>),
+}
+
+impl<'a> Table<'a> {
+    /// Returns the parameter ID of the table.
+    pub fn id(&self) -> u16 {
+        match self {
+            Self::BasicFlash(_) => BASIC_FLASH_PARAMETER_ID,
+            Self::FourByteAddress(_) => FOUR_BYTE_ADDRESS_INSTRUCTION_ID,
+            Self::Vendor(table) => table.id,
+        }
+    }
+
+    /// Returns the length of the table, in DWORDs.
+    pub fn dword_len(&self) -> Result<u8, ToWireError> {
+        match self {
+            Self::BasicFlash(table) => Ok(table.dword_len()),
+            Self::FourByteAddress(_) => Ok(FOUR_BYTE_ADDRESS_INSTRUCTION_LEN),
+            Self::Vendor(table) => {
+                let error = ToWireError::InvalidData(
+                    FieldError::new("vendor table length").with_value(table.data.len() as u64));
+                if table.data.len() % DWORD_LEN != 0 {
+                    return Err(error);
+                }
+                u8::try_from(table.data.len() / DWORD_LEN).map_err(|_| error)
+            }
+        }
+    }
+
+    /// Returns the parameter header for the table, located at `pointer`.
+    pub fn parameter_header(&self, pointer: u32) -> Result<ParameterHeader, ToWireError> {
+        let (minor_version, major_version) = match self {
+            Self::BasicFlash(table) => (table.minor_version, MAJOR_VERSION),
+            Self::FourByteAddress(table) => (table.minor_version, MAJOR_VERSION),
+            Self::Vendor(table) => (table.minor_version, table.major_version),
+        };
+        Ok(ParameterHeader {
+            id: self.id(),
+            minor_version,
+            major_version,
+            length: self.dword_len()?,
+            pointer,
+        })
+    }
+
+    /// Deserializes the table described by `header` from `r`.
+    ///
+    /// Tables other than the Basic Flash Parameter Table and the 4-Byte
+    /// Address Instruction Table are returned as `Table::Vendor`.
+    pub fn from_wire<R: Read<'a>>(mut r: R, header: &ParameterHeader) -> Result<Self, FromWireError> {
+        match header.id {
+            BASIC_FLASH_PARAMETER_ID => BasicFlashParameters::from_wire(
+                r, header.minor_version, header.length).map(Self::BasicFlash),
+            FOUR_BYTE_ADDRESS_INSTRUCTION_ID => FourByteAddressInstructions::from_wire(
+                r, header.minor_version, header.length).map(Self::FourByteAddress),
+            id => Ok(Self::Vendor(VendorTable {
+                id,
+                minor_version: header.minor_version,
+                major_version: header.major_version,
+                data: r.read_bytes(header.byte_len())?,
+            })),
+        }
+    }
+}
+
+impl ToWire for Table<'_> {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        match self {
+            Self::BasicFlash(table) => table.to_wire(&mut w),
+            Self::FourByteAddress(table) => table.to_wire(&mut w),
+            Self::Vendor(table) => {
+                self.dword_len()?;
+                w.write_bytes(table.data)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A complete SFDP structure.
+///
+/// The parameter headers are generated from `tables`, followed by the tables
+/// themselves in the same order. The first table must be the Basic Flash
+/// Parameter Table.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Sfdp<'a> {
+    /// The SFDP minor revision.
+    pub minor_version: u8,
+
+    /// The SFDP major revision.
+    pub major_version: u8,
+
+    /// The SFDP access protocol.
+    pub access_protocol: u8,
+
+    /// The parameter tables.
+    pub tables: &'a [Table<'a>],
+}
+
+impl Sfdp<'_> {
+    /// Returns the pointer to the first table.
+    fn first_table_pointer(&self) -> usize {
+        HEADER_LEN + self.tables.len() * PARAMETER_HEADER_LEN
+    }
+
+    /// Returns the length of the SFDP structure on the wire, in bytes.
+    pub fn byte_len(&self) -> Result<usize, ToWireError> {
+        let mut len = self.first_table_pointer();
+        for table in self.tables.iter() {
+            len += table.dword_len()? as usize * DWORD_LEN;
+        }
+        Ok(len)
+    }
+}
+
+impl ToWire for Sfdp<'_> {
+    fn to_wire<W: Write>(&self, mut w: W) -> Result<(), ToWireError> {
+        match self.tables.first() {
+            Some(Table::BasicFlash(_)) => {}
+            Some(table) => return Err(ToWireError::InvalidData(FieldError::new("first table id").with_value(table.id()))),
+            None => return Err(ToWireError::InvalidData(FieldError::new("tables"))),
+        }
+        let parameter_header_count = u16::try_from(self.tables.len())
+            .map_err(|_| ToWireError::InvalidData(FieldError::new("tables").with_value(self.tables.len() as u64)))?;
+        Header {
+            minor_version: self.minor_version,
+            major_version: self.major_version,
+            parameter_header_count,
+            access_protocol: self.access_protocol,
+        }.to_wire(&mut w)?;
+
+        let mut pointer = self.first_table_pointer();
+        for table in self.tables.iter() {
+            let pointer_u32 = u32::try_from(pointer)
+                .map_err(|_| ToWireError::InvalidData(FieldError::new("pointer").with_value(pointer as u64)))?;
+            table.parameter_header(pointer_u32)?.to_wire(&mut w)?;
+            pointer += table.dword_len()? as usize * DWORD_LEN;
+        }
+        for table in self.tables.iter() {
+            table.to_wire(&mut w)?;
+        }
+        Ok(())
+    }
+}
+
+/// A parser for an SFDP structure.
+#[derive(Clone, Copy, Debug)]
+pub struct SfdpReader<'a> {
+    header: Header,
+    data: &'a [u8],
+}
+
+impl<'a> SfdpReader<'a> {
+    /// Parses the SFDP header at the start of `data`.
+    pub fn new(data: &'a [u8]) -> Result<Self, FromWireError> {
+        let mut r = data;
+        let header = Header::from_wire(&mut r)?;
+        Ok(Self {
+            header,
+            data,
+        })
+    }
+
+    /// Returns the SFDP header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns parameter header `index`.
+    pub fn parameter_header(&self, index: usize) -> Result<ParameterHeader, FromWireError> {
+        if index >= self.header.parameter_header_count as usize {
+            return Err(FromWireError::OutOfRange(FieldError::new("parameter header index").with_value(index as u64)));
+        }
+        let offset = HEADER_LEN + index * PARAMETER_HEADER_LEN;
+        let mut r = self.data.get(offset..)
+            .ok_or_else(|| FromWireError::OutOfRange(FieldError::new("parameter header").at(offset)))?;
+        ParameterHeader::from_wire(&mut r).map_err(|e| e.shift(offset))
+    }
+
+    /// Returns an iterator over the parameter headers.
+    pub fn parameter_headers(&self) -> impl Iterator<Item = Result<ParameterHeader, FromWireError>> + '_ {
+        (0..self.header.parameter_header_count as usize).map(move |index| self.parameter_header(index))
+    }
+
+    /// Returns the first parameter header with parameter ID `id`.
+    pub fn find(&self, id: u16) -> Option<ParameterHeader> {
+        self.parameter_headers().filter_map(Result::ok).find(|header| header.id == id)
+    }
+
+    /// Parses the table described by `header`.
+    pub fn table(&self, header: &ParameterHeader) -> Result<Table<'a>, FromWireError> {
+        let pointer = header.pointer as usize;
+        let mut r = self.data.get(pointer..)
+            .ok_or_else(|| FromWireError::OutOfRange(FieldError::new("table pointer").with_value(header.pointer)))?;
+        Table::from_wire(&mut r, header).map_err(|e| e.shift(pointer))
+    }
+
+    /// Parses the Basic Flash Parameter Table.
+    pub fn basic_flash_parameters(&self) -> Result<BasicFlashParameters, FromWireError> {
+        let missing = FromWireError::OutOfRange(
+            FieldError::new("basic flash parameter id").with_value(BASIC_FLASH_PARAMETER_ID));
+        let header = self.find(BASIC_FLASH_PARAMETER_ID).ok_or(missing)?;
+        match self.table(&header)? {
+            Table::BasicFlash(table) => Ok(table),
+            _ => Err(missing),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::io::Cursor;
+
+    fn basic_flash_parameters() -> BasicFlashParameters {
+        BasicFlashParameters {
+            minor_version: JESD216B_MINOR_VERSION,
+            uniform_4k_erase: true,
+            write_granularity_64: true,
+            volatile_status_write_enable: false,
+            volatile_status_write_enable_06: false,
+            erase_4k_opcode: 0x20,
+            address_bytes: AddressBytes::ThreeOrFourByte,
+            dtr: false,
+            density_bits: 256 * 1024 * 1024,
+            fast_read_1_1_2: Some(FastRead { opcode: 0x3b, mode_clocks: 0, dummy_clocks: 8 }),
+            fast_read_1_2_2: None,
+            fast_read_2_2_2: None,
+            fast_read_1_1_4: Some(FastRead { opcode: 0x6b, mode_clocks: 0, dummy_clocks: 8 }),
+            fast_read_1_4_4: Some(FastRead { opcode: 0xeb, mode_clocks: 2, dummy_clocks: 4 }),
+            fast_read_4_4_4: None,
+            erase_types: [
+                Some(EraseType { size_exponent: 12, opcode: 0x20 }),
+                Some(EraseType { size_exponent: 15, opcode: 0x52 }),
+                Some(EraseType { size_exponent: 16, opcode: 0xd8 }),
+                None,
+            ],
+            jesd216b: Some(Jesd216bParameters {
+                erase_time_multiplier: 1,
+                erase_times: [Time { count: 0, unit: 2 }, Time::default(), Time::default(), Time::default()],
+                program_time_multiplier: 1,
+                page_size_exponent: 8,
+                page_program_time: Time { count: 0xf, unit: 1 },
+                first_byte_program_time: Time { count: 0xf, unit: 1 },
+                additional_byte_program_time: Time { count: 0xf, unit: 1 },
+                chip_erase_time: Time { count: 1, unit: 3 },
+                suspend_resume: None,
+                status_polling: STATUS_POLLING_LEGACY,
+                deep_powerdown: None,
+                status_register_1: STATUS_REGISTER_1_VOLATILE_06,
+                exit_4byte: EXIT_4BYTE_E9 | EXIT_4BYTE_HARDWARE_RESET | EXIT_4BYTE_POWER_CYCLE,
+                enter_4byte: ENTER_4BYTE_B7,
+                ..Default::default()
+            }),
+            jesd216d: None,
+        }
+    }
+
+    #[test]
+    fn basic_flash_parameters_to_wire() {
+        let mut buf = [0u8; 64];
+        let mut cursor = Cursor::new(&mut buf);
+        basic_flash_parameters().to_wire(&mut cursor).expect("to_wire failed");
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0xe5, 0x20, 0xe3, 0xff,
+            0xff, 0xff, 0xff, 0x0f,
+            0x44, 0xeb, 0x08, 0x6b,
+            0x08, 0x3b, 0x00, 0x00,
+            0xee, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0x00, 0x00,
+            0xff, 0xff, 0x00, 0x00,
+            0x0c, 0x20, 0x0f, 0x52,
+            0x10, 0xd8, 0x00, 0x00,
+            0x01, 0x04, 0x00, 0x00,
+            0x81, 0xef, 0xff, 0xe1,
+            0x00, 0x01, 0x00, 0x80,
+            0x00, 0x00, 0x00, 0x00,
+            0x07, 0x00, 0x00, 0x80,
+            0x00, 0x00, 0x00, 0xff,
+            0x82, 0x40, 0x28, 0x01,
+        ];
+        assert_eq!(cursor.consumed_bytes(), expected);
+    }
+
+    #[test]
+    fn basic_flash_parameters_round_trip() {
+        let mut params = basic_flash_parameters();
+        for &(jesd216b, jesd216d) in [(false, false), (true, false), (true, true)].iter() {
+            if !jesd216b {
+                params.jesd216b = None;
+            } else if params.jesd216b.is_none() {
+                params.jesd216b = basic_flash_parameters().jesd216b;
+            }
+            params.jesd216d = if jesd216d {
+                Some(Jesd216dParameters {
+                    fast_read_1_1_8: Some(FastRead { opcode: 0x7c, mode_clocks: 0, dummy_clocks: 8 }),
+                    fast_read_1_8_8: None,
+                    octal_dwords: [0x1234, 0, 0xffff_ffff],
+                })
+            } else {
+                None
+            };
+
+            let mut buf = [0u8; 128];
+            let mut cursor = Cursor::new(&mut buf);
+            params.to_wire(&mut cursor).expect("to_wire failed");
+            assert_eq!(cursor.consumed_len(), params.dword_len() as usize * DWORD_LEN);
+
+            let mut data = cursor.consumed_bytes();
+            let parsed = BasicFlashParameters::from_wire(&mut data, params.minor_version, params.dword_len())
+                .expect("from_wire failed");
+            assert_eq!(parsed, params);
+        }
+    }
+
+    #[test]
+    fn density() {
+        let mut params = basic_flash_parameters();
+        for &(density_bits, dword2) in [
+            (1, 0u32),
+            (1 << 31, 0x7fff_ffff),
+            (1 << 32, 0x8000_0020),
+            (1 << 40, 0x8000_0028),
+        ].iter() {
+            params.density_bits = density_bits;
+            let mut buf = [0u8; 64];
+            let mut cursor = Cursor::new(&mut buf);
+            params.to_wire(&mut cursor).expect("to_wire failed");
+            let mut data = &cursor.consumed_bytes()[4..];
+            assert_eq!(data.read_le::<u32>().unwrap(), dword2);
+        }
+
+        let mut buf = [0u8; 64];
+        params.density_bits = (1 << 32) + 1;
+        assert!(params.to_wire(Cursor::new(&mut buf)).is_err());
+        params.density_bits = 0;
+        assert!(params.to_wire(Cursor::new(&mut buf)).is_err());
+    }
+
+    #[test]
+    fn field_overflow() {
+        let mut params = basic_flash_parameters();
+        params.fast_read_1_1_2 = Some(FastRead { opcode: 0x3b, mode_clocks: 8, dummy_clocks: 0 });
+        let mut buf = [0u8; 64];
+        assert!(matches!(params.to_wire(Cursor::new(&mut buf)), Err(ToWireError::InvalidData(_))));
+    }
+
+    #[test]
+    fn times() {
+        let time = Time { count: 0, unit: 2 };
+        assert_eq!(time.duration(&ERASE_TIME_UNITS), Some(Duration::from_millis(128)));
+        let time = Time { count: 1, unit: 3 };
+        assert_eq!(time.duration(&CHIP_ERASE_TIME_UNITS), Some(Duration::from_secs(128)));
+        let time = Time { count: 1, unit: 2 };
+        assert_eq!(time.duration(&PAGE_PROGRAM_TIME_UNITS), None);
+
+        let params = basic_flash_parameters().jesd216b.unwrap();
+        assert_eq!(params.max_erase_time(0), Some(Duration::from_millis(512)));
+        assert_eq!(params.max_erase_time(4), None);
+        assert_eq!(params.max_page_program_time(), Some(Duration::from_micros(4096)));
+        assert_eq!(params.max_chip_erase_time(), Some(Duration::from_secs(512)));
+    }
+
+    #[test]
+    fn four_byte_address_instructions() {
+        let mut table = FourByteAddressInstructions {
+            minor_version: 0,
+            supported: 0,
+            erase_opcodes: [None; 4],
+        };
+        assert!(table.set_supported(OpCode::NormalRead4B));
+        assert!(table.set_supported(OpCode::PageProgram4B));
+        assert!(!table.set_supported(OpCode::NormalRead));
+        table.set_erase_opcode(0, Some(OpCode::SectorErase4B.to_wire_value()));
+        assert!(table.supports(OpCode::NormalRead4B));
+        assert!(table.supports(OpCode::SectorErase4B));
+        assert!(!table.supports(OpCode::FastRead4B));
+        assert!(!table.supports(OpCode::BlockErase64KB4B));
+
+        let mut buf = [0u8; 8];
+        let mut cursor = Cursor::new(&mut buf);
+        table.to_wire(&mut cursor).expect("to_wire failed");
+        assert_eq!(cursor.consumed_bytes(), &[0x41, 0x02, 0x00, 0x00, 0x21, 0xff, 0xff, 0xff]);
+
+        let mut data = cursor.consumed_bytes();
+        let parsed = FourByteAddressInstructions::from_wire(&mut data, 0, FOUR_BYTE_ADDRESS_INSTRUCTION_LEN)
+            .expect("from_wire failed");
+        assert_eq!(parsed, table);
+    }
+
+    #[test]
+    fn sfdp_round_trip() {
+        let vendor_data = b"GOOG\x00\x00\x00\x02\x00\x02\x00\x00";
+        let mut four_byte = FourByteAddressInstructions {
+            minor_version: 0,
+            supported: 0,
+            erase_opcodes: [None; 4],
+        };
+        four_byte.set_supported(OpCode::NormalRead4B);
+        let tables = [
+            Table::BasicFlash(basic_flash_parameters()),
+            Table::FourByteAddress(four_byte),
+            Table::Vendor(VendorTable {
+                id: 0x0926,
+                minor_version: 0,
+                major_version: 1,
+                data: vendor_data,
+            }),
+        ];
+        let sfdp = Sfdp {
+            minor_version: JESD216B_MINOR_VERSION,
+            major_version: MAJOR_VERSION,
+            access_protocol: LEGACY_ACCESS_PROTOCOL,
+            tables: &tables,
+        };
+
+        let mut buf = [0u8; 256];
+        let mut cursor = Cursor::new(&mut buf);
+        sfdp.to_wire(&mut cursor).expect("to_wire failed");
+        let data = cursor.consumed_bytes();
+        assert_eq!(data.len(), sfdp.byte_len().unwrap());
+        assert_eq!(&data[..8], b"SFDP\x06\x01\x02\xff");
+
+        let reader = SfdpReader::new(data).expect("new failed");
+        assert_eq!(reader.header().parameter_header_count, 3);
+        let headers = [
+            reader.parameter_header(0).unwrap(),
+            reader.parameter_header(1).unwrap(),
+            reader.parameter_header(2).unwrap(),
+        ];
+        assert!(reader.parameter_header(3).is_err());
+        assert_eq!(headers[0], ParameterHeader {
+            id: BASIC_FLASH_PARAMETER_ID,
+            minor_version: JESD216B_MINOR_VERSION,
+            major_version: MAJOR_VERSION,
+            length: BASIC_FLASH_PARAMETER_JESD216B_LEN,
+            pointer: 32,
+        });
+        assert_eq!(headers[1].pointer, 96);
+        assert_eq!(headers[2].pointer, 104);
+        assert!(headers[2].is_vendor());
+        assert!(!headers[1].is_vendor());
+
+        for (header, table) in headers.iter().zip(tables.iter()) {
+            assert_eq!(&reader.table(header).expect("table failed"), table);
+        }
+        assert_eq!(reader.basic_flash_parameters().unwrap(), basic_flash_parameters());
+        match reader.table(&reader.find(0x0926).unwrap()).unwrap() {
+            Table::Vendor(table) => assert_eq!(table.dword(1), Some(0x02000000)),
+            _ => panic!("expected a vendor table"),
+        }
+    }
+
+    #[test]
+    fn sfdp_errors() {
+        let mut buf = [0u8; 256];
+        let vendor = Table::Vendor(VendorTable {
+            id: 0x0926,
+            minor_version: 0,
+            major_version: 1,
+            data: b"GOO",
+        });
+        let sfdp = Sfdp {
+            minor_version: JESD216B_MINOR_VERSION,
+            major_version: MAJOR_VERSION,
+            access_protocol: LEGACY_ACCESS_PROTOCOL,
+            tables: &[Table::BasicFlash(basic_flash_parameters()), vendor],
+        };
+        assert!(sfdp.to_wire(Cursor::new(&mut buf)).is_err());
+
+        let sfdp = Sfdp {
+            tables: &[],
+            ..sfdp
+        };
+        assert!(sfdp.to_wire(Cursor::new(&mut buf)).is_err());
+
+        assert!(SfdpReader::new(b"SFDQ\x06\x01\x00\xff").is_err());
+        assert!(SfdpReader::new(b"SFDP").is_err());
+    }
+}
@@ -20,6 +20,10 @@ use crate::io::BeInt;
 use crate::io::Read;
 use crate::io::Write;
 
+use core::fmt;
+
+pub use spiutils_derive::{FromWire, ToWire};
+
 /// A type which can be deserialized from the Cerberus wire format.
 ///
 /// The lifetime `'wire` indicates that the type can be deserialized from a
@@ -29,17 +33,95 @@ pub trait FromWire<'wire>: Sized {
     fn from_wire<R: Read<'wire>>(r: R) -> Result<Self, FromWireError>;
 }
 
+/// Context describing the part of a message that could not be converted.
+///
+/// This is kept small and `Copy` so that it can be returned from `no_std`
+/// code without allocating.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FieldError {
+    /// The name of the offending field.
+    pub field: &'static str,
+
+    /// The byte offset of the field, relative to the start of the value
+    /// being converted, if known.
+    pub offset: Option<usize>,
+
+    /// The offending value, if any.
+    pub value: Option<u64>,
+}
+
+impl FieldError {
+    /// Creates a new `FieldError` for `field`, without offset or value.
+    pub const fn new(field: &'static str) -> Self {
+        Self {
+            field,
+            offset: None,
+            value: None,
+        }
+    }
+
+    /// Sets the offending value.
+    pub fn with_value<V: Into<u64>>(mut self, value: V) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the byte offset of the field.
+    pub fn at(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Moves the offset back by `offset` bytes, e.g. to make it relative to
+    /// an enclosing value.
+    pub fn shift(mut self, offset: usize) -> Self {
+        self.offset = self.offset.map(|o| o + offset);
+        self
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.field)?;
+        if let Some(value) = self.value {
+            write!(f, " {:#x}", value)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " at offset {}", offset)?;
+        }
+        Ok(())
+    }
+}
+
 /// An deserialization error.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum FromWireError {
     /// Indicates that something went wrong in an `io` operation.
     ///
     /// [`io`]: ../../io/index.html
     Io(io::Error),
 
+    /// Indicates that a field contained a value that does not correspond to
+    /// any known variant, such as an unknown op code.
+    UnknownValue(FieldError),
+
     /// Indicates that some field within the request was outside of its
     /// valid range.
-    OutOfRange,
+    OutOfRange(FieldError),
+}
+
+impl FromWireError {
+    /// Moves the offset of the offending field back by `offset` bytes.
+    ///
+    /// This is used when deserializing a value that is embedded in another
+    /// one, to make the offset relative to the enclosing value.
+    pub fn shift(self, offset: usize) -> Self {
+        match self {
+            Self::Io(e) => Self::Io(e),
+            Self::UnknownValue(e) => Self::UnknownValue(e.shift(offset)),
+            Self::OutOfRange(e) => Self::OutOfRange(e.shift(offset)),
+        }
+    }
 }
 
 impl From<io::Error> for FromWireError {
@@ -48,6 +130,16 @@ impl From<io::Error> for FromWireError {
     }
 }
 
+impl fmt::Display for FromWireError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::UnknownValue(e) => write!(f, "unknown {}", e),
+            Self::OutOfRange(e) => write!(f, "invalid {}", e),
+        }
+    }
+}
+
 /// A type which can be serialized into the Cerberus wire format.
 pub trait ToWire: Sized {
     /// Serializes `self` into `w`.
@@ -55,7 +147,7 @@ pub trait ToWire: Sized {
 }
 
 /// A serializerion error.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ToWireError {
     /// Indicates that something went wrong in an [`io`] operation.
     ///
@@ -63,7 +155,7 @@ pub enum ToWireError {
     Io(io::Error),
 
     /// Indicates that the data to be serialized was invalid.
-    InvalidData,
+    InvalidData(FieldError),
 }
 
 impl From<io::Error> for ToWireError {
@@ -72,6 +164,15 @@ impl From<io::Error> for ToWireError {
     }
 }
 
+impl fmt::Display for ToWireError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::InvalidData(e) => write!(f, "invalid {}", e),
+        }
+    }
+}
+
 /// Represents a C-like enum that can be converted to and from a wire
 /// representation as well as to and from a string representation.
 ///
@@ -90,7 +191,7 @@ impl From<io::Error> for ToWireError {
 ///
 /// Also, the following identity must hold for all types T:
 /// ```
-/// # use manticore::protocol::wire::WireEnum;
+/// # use spiutils::protocol::wire::WireEnum;
 /// # fn test<T: WireEnum + Copy + PartialEq + std::fmt::Debug>(x: T) {
 /// assert_eq!(T::from_name(T::name(x)), Some(x));
 /// # }
@@ -98,7 +199,10 @@ impl From<io::Error> for ToWireError {
 pub trait WireEnum: Sized + Copy {
     /// The unrelying "wire type". This is almost always some kind of
     /// unsigned integer.
-    type Wire: BeInt;
+    type Wire: BeInt + Into<u64>;
+
+    /// The name of the enum, used to describe errors.
+    const NAME: &'static str;
 
     /// Converts `self` into its underlying wire representation.
     fn to_wire_value(self) -> Self::Wire;
@@ -120,7 +224,8 @@ where
 {
     fn from_wire<R: Read<'wire>>(mut r: R) -> Result<Self, FromWireError> {
         let wire = <Self as WireEnum>::Wire::read_from(&mut r)?;
-        Self::from_wire_value(wire).ok_or(FromWireError::OutOfRange)
+        Self::from_wire_value(wire)
+            .ok_or_else(|| FromWireError::UnknownValue(FieldError::new(Self::NAME).with_value(wire).at(0)))
     }
 }
 
@@ -171,6 +276,7 @@ macro_rules! wire_enum {
 
         impl $crate::protocol::wire::WireEnum for $name {
             type Wire = $wire;
+            const NAME: &'static str = stringify!($name);
             fn to_wire_value(self) -> Self::Wire {
                 self as $wire
             }
@@ -255,6 +361,28 @@ mod test {
         assert_eq!(None, DemoEnum::from_name("does not exist"));
     }
 
+    #[test]
+    fn unknown_value() {
+        use crate::protocol::wire::*;
+
+        let mut data: &[u8] = &[0x03];
+        let err = DemoEnum::from_wire(&mut data).unwrap_err();
+        assert_eq!(err, FromWireError::UnknownValue(FieldError::new("DemoEnum").with_value(3u8).at(0)));
+        assert_eq!(format!("{}", err), "unknown DemoEnum 0x3 at offset 0");
+        assert_eq!(format!("{}", err.shift(4)), "unknown DemoEnum 0x3 at offset 4");
+    }
+
+    #[test]
+    fn display() {
+        use crate::io;
+        use crate::protocol::wire::*;
+
+        assert_eq!(format!("{}", FromWireError::Io(io::Error::BufferExhausted)), "buffer exhausted");
+        assert_eq!(format!("{}", FromWireError::OutOfRange(FieldError::new("crc").with_value(0x1234u16).at(7))),
+            "invalid crc 0x1234 at offset 7");
+        assert_eq!(format!("{}", ToWireError::InvalidData(FieldError::new("tables"))), "invalid tables");
+    }
+
     #[test]
     fn name() {
         use crate::protocol::wire::*;
@@ -0,0 +1,430 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Decoding of SPI bus captures.
+
+use spiutils::protocol::flash;
+use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::DataDirection;
+use spiutils::protocol::flash::JedecId;
+use spiutils::protocol::flash::OpCode;
+use spiutils::protocol::mailbox;
+use spiutils::protocol::mailbox::MailboxState;
+use spiutils::protocol::mailbox::MailboxStatus;
+use spiutils::protocol::payload;
+use spiutils::protocol::wire::FromWire;
+
+use std::io::Write;
+
+/// The number of bytes shown per line of a hex dump.
+const HEX_DUMP_WIDTH: usize = 16;
+
+/// A single SPI transaction, i.e. everything clocked while CS was asserted.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Transaction {
+    /// The bytes sent by the host.
+    pub mosi: Vec<u8>,
+
+    /// The bytes sent by the device.
+    ///
+    /// This is empty if the capture does not include MISO.
+    pub miso: Vec<u8>,
+}
+
+/// An error encountered while parsing a CSV capture.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CsvError {
+    /// The line the error was encountered on, starting at 1.
+    pub line: usize,
+
+    /// A description of the error.
+    pub reason: &'static str,
+}
+
+fn parse_csv_value(value: &str) -> Option<u8> {
+    let value = value.to_ascii_lowercase();
+    if let Some(hex) = value.strip_prefix("0x") {
+        u8::from_str_radix(hex, 16).ok()
+    } else if let Some(bin) = value.strip_prefix("0b") {
+        u8::from_str_radix(bin, 2).ok()
+    } else {
+        value.parse::<u8>().ok()
+    }
+}
+
+/// Parses a CSV export of a logic analyzer's SPI decoder.
+///
+/// The first line must name the columns. The `MOSI` column is required, the
+/// `MISO` column is optional. Rows are grouped into transactions by the
+/// column whose name contains `packet`; without such a column, the whole
+/// capture is treated as a single transaction. Rows without a packet ID are
+/// ignored. Values are decimal, or hexadecimal or binary with a `0x` or `0b`
+/// prefix.
+pub fn parse_csv(text: &str) -> Result<Vec<Transaction>, CsvError> {
+    let mut lines = text.lines().enumerate();
+    let columns: Vec<String> = match lines.next() {
+        Some((_, header)) => header
+            .split(',')
+            .map(|column| column.trim().trim_matches('"').to_ascii_lowercase())
+            .collect(),
+        None => return Ok(Vec::new()),
+    };
+    let find = |name: &str| columns.iter().position(|column| column.contains(name));
+    let mosi_column = find("mosi").ok_or(CsvError { line: 1, reason: "missing MOSI column" })?;
+    let miso_column = find("miso");
+    let packet_column = find("packet");
+
+    let mut transactions = Vec::new();
+    let mut current: Option<(String, Transaction)> = None;
+    for (index, line) in lines {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line
+            .split(',')
+            .map(|field| field.trim().trim_matches('"'))
+            .collect();
+        let field = |column: usize| fields.get(column).copied().unwrap_or("");
+
+        let packet_id = match packet_column {
+            Some(column) if field(column).is_empty() => continue,
+            Some(column) => field(column),
+            None => "",
+        };
+        let is_new = match &current {
+            Some((id, _)) => id != packet_id,
+            None => true,
+        };
+        if is_new {
+            if let Some((_, transaction)) = current.take() {
+                transactions.push(transaction);
+            }
+            current = Some((packet_id.to_string(), Transaction::default()));
+        }
+        let transaction = match &mut current {
+            Some((_, transaction)) => transaction,
+            None => unreachable!(),
+        };
+
+        let mosi = parse_csv_value(field(mosi_column))
+            .ok_or(CsvError { line: line_number, reason: "invalid MOSI value" })?;
+        transaction.mosi.push(mosi);
+        if let Some(column) = miso_column {
+            let miso = parse_csv_value(field(column))
+                .ok_or(CsvError { line: line_number, reason: "invalid MISO value" })?;
+            transaction.miso.push(miso);
+        }
+    }
+    if let Some((_, transaction)) = current {
+        transactions.push(transaction);
+    }
+
+    Ok(transactions)
+}
+
+/// Writes `data` as a hex dump, prefixing each line with `indent`.
+pub fn hex_dump<W: Write>(out: &mut W, indent: &str, data: &[u8]) -> std::io::Result<()> {
+    for (index, chunk) in data.chunks(HEX_DUMP_WIDTH).enumerate() {
+        write!(out, "{}{:04x}:", indent, index * HEX_DUMP_WIDTH)?;
+        for byte in chunk {
+            write!(out, " {:02x}", byte)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn address_mode_name(address_mode: AddressMode) -> &'static str {
+    match address_mode {
+        AddressMode::ThreeByte => "3-byte",
+        AddressMode::FourByte => "4-byte",
+    }
+}
+
+/// Decodes SPI transactions, tracking the device state across them.
+pub struct Decoder {
+    /// The address mode the next transaction is decoded in.
+    pub address_mode: AddressMode,
+
+    /// The address of the mailbox that payloads are exchanged through.
+    pub mailbox_address: u32,
+}
+
+impl Decoder {
+    /// Creates a new `Decoder`.
+    pub fn new(address_mode: AddressMode, mailbox_address: u32) -> Self {
+        Self {
+            address_mode,
+            mailbox_address,
+        }
+    }
+
+    /// Decodes `transaction` and writes a description of it to `out`.
+    ///
+    /// `index` is the number of the transaction within the capture.
+    pub fn decode<W: Write>(&mut self, out: &mut W, index: usize, transaction: &Transaction)
+        -> std::io::Result<()> {
+        let mut mosi = transaction.mosi.as_slice();
+        let header = match flash::Header::from_wire(&mut mosi, self.address_mode) {
+            Ok(header) => header,
+            Err(err) => {
+                writeln!(out, "#{}: failed to parse header: {:?}", index, err)?;
+                return hex_dump(out, "    ", &transaction.mosi);
+            }
+        };
+
+        write!(out, "#{}: {:?}", index, header.opcode)?;
+        if let Some(address) = header.address {
+            write!(out, " address=0x{:08x} ({}-byte)", address, header.address_len())?;
+        }
+        writeln!(out)?;
+
+        let header_len = transaction.mosi.len() - mosi.len();
+        let direction = header.opcode.descriptor().data_direction;
+        let data = match direction {
+            DataDirection::None => &[][..],
+            DataDirection::HostToDevice => mosi,
+            DataDirection::DeviceToHost => transaction.miso.get(header_len..).unwrap_or(&[]),
+        };
+        if !data.is_empty() {
+            writeln!(out, "  data ({} bytes):", data.len())?;
+            hex_dump(out, "    ", data)?;
+        }
+
+        match header.opcode {
+            OpCode::Enter4ByteAddressMode => self.set_address_mode(out, AddressMode::FourByte)?,
+            OpCode::Exit4ByteAddressMode => self.set_address_mode(out, AddressMode::ThreeByte)?,
+            OpCode::ReadJedec if !data.is_empty() => {
+                match JedecId::from_wire(&mut &data[..]) {
+                    Ok(jedec_id) => {
+                        let name = jedec_id.manufacturer().map_or("unknown", |m| m.name);
+                        writeln!(out, "  jedec id: {:?} ({})", jedec_id, name)?;
+                    }
+                    Err(err) => writeln!(out, "  jedec id: failed to parse: {}", err)?,
+                }
+            }
+            _ if data.is_empty() => {}
+            _ if header.address == Some(self.mailbox_address) => match direction {
+                DataDirection::DeviceToHost => self.decode_mailbox_read(out, data)?,
+                _ => self.decode_payload(out, data)?,
+            },
+            _ if header.address == Some(self.response_address())
+                && direction == DataDirection::DeviceToHost => {
+                self.decode_payload(out, data)?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn set_address_mode<W: Write>(&mut self, out: &mut W, address_mode: AddressMode)
+        -> std::io::Result<()> {
+        if self.address_mode != address_mode {
+            writeln!(out, "  address mode: {} -> {}",
+                address_mode_name(self.address_mode), address_mode_name(address_mode))?;
+            self.address_mode = address_mode;
+        }
+        Ok(())
+    }
+
+    /// Returns the address of the response within the mailbox.
+    fn response_address(&self) -> u32 {
+        self.mailbox_address.wrapping_add(mailbox::RESPONSE_OFFSET as u32)
+    }
+
+    /// Decodes a read from the start of the mailbox, i.e. the status word
+    /// followed by the response, if any.
+    fn decode_mailbox_read<W: Write>(&self, out: &mut W, data: &[u8]) -> std::io::Result<()> {
+        let mut r = data;
+        let status = match MailboxStatus::from_wire(&mut r) {
+            Ok(status) => status,
+            Err(err) => return writeln!(out, "  mailbox: failed to parse status: {}", err),
+        };
+        writeln!(out, "  mailbox: {:?}", status)?;
+        if status.state == MailboxState::ResponseReady && !r.is_empty() {
+            self.decode_payload(out, r)?;
+        }
+        Ok(())
+    }
+
+    /// Decodes a payload exchanged through the mailbox.
+    ///
+    /// Packets are tried first since their CRC makes them unambiguous;
+    /// anything else is decoded as a plain payload header.
+    fn decode_payload<W: Write>(&self, out: &mut W, data: &[u8]) -> std::io::Result<()> {
+        if let Ok((packet, content)) = payload::read_packet(&mut &data[..]) {
+            writeln!(out, "  packet: {:?}", packet)?;
+            return self.decode_content(out, packet.content, content);
+        }
+
+        let mut r = data;
+        let header = match payload::Header::from_wire(&mut r) {
+            Ok(header) => header,
+            Err(err) => return writeln!(out, "  payload: failed to parse header: {}", err),
+        };
+        writeln!(out, "  payload: {:?}", header)?;
+        match r.get(..header.content_len as usize) {
+            Some(content) => self.decode_content(out, header.content, content),
+            None => writeln!(out, "  payload: content truncated, {} of {} bytes",
+                r.len(), header.content_len),
+        }
+    }
+
+    fn decode_content<W: Write>(&self, out: &mut W, content_type: payload::ContentType,
+        content: &[u8]) -> std::io::Result<()> {
+        match content_type {
+            payload::ContentType::Manticore => {
+                writeln!(out, "  manticore message ({} bytes):", content.len())?;
+                hex_dump(out, "    ", content)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use spiutils::policy::Protection;
+    use spiutils::io::Cursor;
+    use spiutils::io::Write as _;
+    use spiutils::protocol::wire::ToWire;
+
+    fn decode_all(decoder: &mut Decoder, transactions: &[Transaction]) -> String {
+        let mut out = Vec::new();
+        for (index, transaction) in transactions.iter().enumerate() {
+            decoder.decode(&mut out, index, transaction).unwrap();
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn csv() {
+        let text = "\"Time [s]\",\"Packet ID\",\"MOSI\",\"MISO\"\n\
+                    0.1,0,0x9F,0xFF\n\
+                    0.2,0,0xFF,0x7F\n\
+                    0.3,,0x00,0x00\n\
+                    0.4,1,6,255\n";
+        let transactions = parse_csv(text).unwrap();
+        assert_eq!(transactions, vec![
+            Transaction { mosi: vec![0x9f, 0xff], miso: vec![0xff, 0x7f] },
+            Transaction { mosi: vec![0x06], miso: vec![0xff] },
+        ]);
+
+        assert_eq!(parse_csv("Time,MOSI\n0.1,0x02\n0.2,0b101\n").unwrap(), vec![
+            Transaction { mosi: vec![0x02, 0x05], miso: vec![] },
+        ]);
+        assert_eq!(parse_csv("Time,MISO\n"), Err(CsvError { line: 1, reason: "missing MOSI column" }));
+        assert_eq!(parse_csv("MOSI\n0x100\n"), Err(CsvError { line: 2, reason: "invalid MOSI value" }));
+    }
+
+    #[test]
+    fn address_mode_tracking() {
+        let transactions = [
+            Transaction { mosi: vec![0x03, 0x00, 0x10, 0x00], miso: vec![] },
+            Transaction { mosi: vec![0xb7], miso: vec![] },
+            Transaction { mosi: vec![0x03, 0x00, 0x00, 0x10, 0x00], miso: vec![] },
+            Transaction { mosi: vec![0xe9], miso: vec![] },
+            Transaction { mosi: vec![0x03, 0x00, 0x10, 0x00], miso: vec![] },
+        ];
+        let mut decoder = Decoder::new(AddressMode::ThreeByte, 0x02000000);
+        let out = decode_all(&mut decoder, &transactions);
+        assert_eq!(out, "#0: NormalRead address=0x00001000 (3-byte)\n\
+                         #1: Enter4ByteAddressMode\n  \
+                         address mode: 3-byte -> 4-byte\n\
+                         #2: NormalRead address=0x00001000 (4-byte)\n\
+                         #3: Exit4ByteAddressMode\n  \
+                         address mode: 4-byte -> 3-byte\n\
+                         #4: NormalRead address=0x00001000 (3-byte)\n");
+        assert_eq!(decoder.address_mode, AddressMode::ThreeByte);
+    }
+
+    #[test]
+    fn mailbox_payload() {
+        let mut mosi = [0u8; 10];
+        let mut cursor = Cursor::new(&mut mosi);
+        flash::Header {
+            opcode: OpCode::PageProgram,
+            address_mode: AddressMode::ThreeByte,
+            address: Some(0x020000),
+        }.to_wire(&mut cursor).unwrap();
+        payload::Header {
+            content: payload::ContentType::Manticore,
+            content_len: 2,
+        }.to_wire(&mut cursor).unwrap();
+        cursor.write_bytes(&[0xaa, 0xbb]).unwrap();
+        let len = cursor.consumed_len();
+
+        let transactions = [
+            Transaction { mosi: mosi[..len].to_vec(), miso: vec![] },
+            Transaction { mosi: vec![0x9f], miso: vec![0xff, 0xef, 0x40, 0x19] },
+        ];
+        let mut decoder = Decoder::new(AddressMode::ThreeByte, 0x020000);
+        let out = decode_all(&mut decoder, &transactions);
+        assert_eq!(out, "#0: PageProgram address=0x00020000 (3-byte)\n  \
+                         data (5 bytes):\n    \
+                         0000: 01 00 02 aa bb\n  \
+                         payload: Header { content: Manticore, content_len: 2 }\n  \
+                         manticore message (2 bytes):\n    \
+                         0000: aa bb\n\
+                         #1: ReadJedec\n  \
+                         data (3 bytes):\n    \
+                         0000: ef 40 19\n  \
+                         jedec id: JedecId { bank: 1, manufacturer: 239, device: 64, capacity: 25 } (Winbond)\n");
+    }
+
+    #[test]
+    fn mailbox_status() {
+        let mut miso = vec![0xff; 16];
+        let mut cursor = Cursor::new(&mut miso);
+        cursor.write_bytes(&[0; 4]).unwrap();
+        MailboxStatus {
+            state: MailboxState::ResponseReady,
+            sequence: 1,
+            response_len: 4,
+            rejected_writes: 2,
+            last_rejection: Some(Protection::ReadOnly),
+            boot_status: None,
+        }.to_wire(&mut cursor).unwrap();
+        payload::Header {
+            content: payload::ContentType::Manticore,
+            content_len: 1,
+        }.to_wire(&mut cursor).unwrap();
+        cursor.write_bytes(&[0xaa]).unwrap();
+
+        let transactions = [
+            Transaction { mosi: vec![0x03, 0x02, 0x00, 0x00], miso },
+            Transaction { mosi: vec![0x03, 0x02, 0x00, 0x08], miso: vec![0; 8] },
+        ];
+        let mut decoder = Decoder::new(AddressMode::ThreeByte, 0x020000);
+        let out = decode_all(&mut decoder, &transactions);
+        assert_eq!(out, "#0: NormalRead address=0x00020000 (3-byte)\n  \
+                         data (12 bytes):\n    \
+                         0000: 02 01 00 04 00 02 01 00 01 00 01 aa\n  \
+                         mailbox: MailboxStatus { state: ResponseReady, sequence: 1, response_len: 4, \
+                         rejected_writes: 2, last_rejection: Some(ReadOnly), boot_status: None }\n  \
+                         payload: Header { content: Manticore, content_len: 1 }\n  \
+                         manticore message (1 bytes):\n    \
+                         0000: aa\n\
+                         #1: NormalRead address=0x00020008 (3-byte)\n  \
+                         data (4 bytes):\n    \
+                         0000: 00 00 00 00\n  \
+                         payload: failed to parse header: unknown ContentType 0x0 at offset 0\n");
+    }
+}
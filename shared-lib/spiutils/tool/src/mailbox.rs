@@ -0,0 +1,466 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Client for the SPI mailbox of a PA-RoT.
+//!
+//! A request is split into packets, which are sent by enabling writes and
+//! programming them to the mailbox address one by one. The device reports the
+//! progress of the request in the status word at the start of the mailbox,
+//! which is polled until the first response packet is ready. Each further
+//! response packet is requested by erasing the mailbox. See
+//! `spiutils::protocol::mailbox`.
+
+use core::fmt;
+use core::fmt::Debug;
+
+use crate::transport::Transport;
+
+use spiutils::protocol::flash;
+use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::OpCode;
+use spiutils::protocol::mailbox;
+use spiutils::protocol::mailbox::MailboxState;
+use spiutils::protocol::mailbox::MailboxStatus;
+use spiutils::protocol::payload;
+use spiutils::protocol::payload::ReassemblyError;
+use spiutils::protocol::wire::FieldError;
+use spiutils::protocol::wire::FromWire;
+use spiutils::protocol::wire::FromWireError;
+use spiutils::protocol::wire::ToWire;
+use spiutils::protocol::wire::ToWireError;
+
+/// An error while exchanging a message through the mailbox.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// The transport failed.
+    Transport(E),
+
+    /// The request could not be serialized.
+    ToWire(ToWireError),
+
+    /// The response could not be parsed.
+    FromWire(FromWireError),
+
+    /// The response wasn't ready after the maximum number of status polls.
+    Timeout,
+
+    /// The device failed to process the request.
+    RequestFailed,
+
+    /// The response has a different content type than the request.
+    UnexpectedContentType(payload::ContentType),
+
+    /// The response has a different message id than the request.
+    UnexpectedMessageId(u8),
+
+    /// The response packets could not be reassembled.
+    Reassembly(ReassemblyError),
+}
+
+impl<E: Debug> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(err) => write!(f, "transport failed: {:?}", err),
+            Error::ToWire(err) => write!(f, "failed to serialize request: {}", err),
+            Error::FromWire(err) => write!(f, "failed to parse response: {}", err),
+            Error::Timeout => write!(f, "timed out waiting for the device"),
+            Error::RequestFailed => write!(f, "the device failed to process the request"),
+            Error::UnexpectedContentType(content) => write!(f, "unexpected response content type {:?}", content),
+            Error::UnexpectedMessageId(message_id) => write!(f, "unexpected response message id {}", message_id),
+            Error::Reassembly(err) => write!(f, "failed to reassemble response: {:?}", err),
+        }
+    }
+}
+
+impl<E> From<ToWireError> for Error<E> {
+    fn from(err: ToWireError) -> Self {
+        Error::ToWire(err)
+    }
+}
+
+impl<E> From<FromWireError> for Error<E> {
+    fn from(err: FromWireError) -> Self {
+        Error::FromWire(err)
+    }
+}
+
+/// A client exchanging messages with a device through its mailbox.
+pub struct MailboxClient<T> {
+    transport: T,
+
+    /// The address of the mailbox.
+    pub mailbox_address: u32,
+
+    /// The address mode of the device.
+    pub address_mode: AddressMode,
+
+    /// The maximum number of mailbox status reads while waiting for the
+    /// device to process a request.
+    pub max_polls: usize,
+
+    /// The message id of the next request.
+    message_id: u8,
+}
+
+impl<T: Transport> MailboxClient<T> {
+    /// Creates a new `MailboxClient` sending requests through `transport`.
+    pub fn new(transport: T, mailbox_address: u32, address_mode: AddressMode,
+        max_polls: usize) -> Self {
+        Self {
+            transport,
+            mailbox_address,
+            address_mode,
+            max_polls,
+            message_id: 0,
+        }
+    }
+
+    /// Serializes a flash header for `opcode` at `address` in the current
+    /// address mode.
+    fn header(&self, opcode: OpCode, address: Option<u32>) -> Result<Vec<u8>, Error<T::Error>> {
+        let header = flash::Header {
+            opcode,
+            address_mode: self.address_mode,
+            address,
+        };
+        let mut buf = Vec::new();
+        header.to_wire(spiutils::io::StdWrite(&mut buf))?;
+        Ok(buf)
+    }
+
+    fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, Error<T::Error>> {
+        self.transport.transaction(tx, rx_len).map_err(Error::Transport)
+    }
+
+    /// Reads `len` bytes from the mailbox, starting at `offset`.
+    fn read_mailbox(&mut self, offset: u32, len: usize) -> Result<Vec<u8>, Error<T::Error>> {
+        let address = self.mailbox_address.checked_add(offset)
+            .ok_or_else(|| ToWireError::InvalidData(FieldError::new("address").with_value(offset)))?;
+        let tx = self.header(OpCode::NormalRead, Some(address))?;
+        self.transaction(&tx, len)
+    }
+
+    /// Polls the status register until the device clears its BUSY bit.
+    fn wait_idle(&mut self) -> Result<(), Error<T::Error>> {
+        let tx = self.header(OpCode::ReadStatusRegister, None)?;
+        for _ in 0..self.max_polls {
+            if self.transaction(&tx, 1)?[0] & 0x01 == 0 {
+                return Ok(());
+            }
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Enables writes and executes the "write" type command `opcode` with
+    /// `data` at the mailbox address, then waits until the device is done
+    /// with it.
+    fn write_mailbox(&mut self, opcode: OpCode, data: &[u8]) -> Result<(), Error<T::Error>> {
+        let write_enable = self.header(OpCode::WriteEnable, None)?;
+        self.transaction(&write_enable, 0)?;
+        let mut tx = self.header(opcode, Some(self.mailbox_address))?;
+        tx.extend_from_slice(data);
+        self.transaction(&tx, 0)?;
+        self.wait_idle()
+    }
+
+    /// Reads the status word of the mailbox.
+    fn read_status(&mut self) -> Result<MailboxStatus, Error<T::Error>> {
+        let status = self.read_mailbox(0, mailbox::STATUS_LEN)?;
+        Ok(MailboxStatus::from_wire(status.as_slice())?)
+    }
+
+    /// Polls the status word until the request with `sequence` is complete.
+    ///
+    /// Returns the length of the response.
+    fn wait_response(&mut self, sequence: u8) -> Result<usize, Error<T::Error>> {
+        for _ in 0..self.max_polls {
+            let status = self.read_status()?;
+            if status.sequence != sequence {
+                continue;
+            }
+            match status.state {
+                MailboxState::ResponseReady => return Ok(status.response_len as usize),
+                MailboxState::Error => return Err(Error::RequestFailed),
+                MailboxState::Idle | MailboxState::RequestPending => {}
+            }
+        }
+        Err(Error::Timeout)
+    }
+
+    /// Sends `request` of type `content` and returns the response content.
+    pub fn send(&mut self, content: payload::ContentType, request: &[u8])
+        -> Result<Vec<u8>, Error<T::Error>> {
+        let packet_count = payload::packet_count(request.len(), mailbox::REQUEST_PAYLOAD_LEN)
+            .ok_or_else(|| ToWireError::InvalidData(
+                FieldError::new("message length").with_value(request.len() as u64)))?;
+        let message_id = self.message_id;
+        self.message_id = self.message_id.wrapping_add(1);
+
+        let sequence = self.read_status()?.sequence.wrapping_add(1);
+        for packet_index in 0..packet_count {
+            let mut packet = Vec::new();
+            payload::write_packet(spiutils::io::StdWrite(&mut packet), content, message_id, request,
+                packet_index, mailbox::REQUEST_PAYLOAD_LEN)?;
+            self.write_mailbox(OpCode::PageProgram, &packet)?;
+        }
+
+        let mut buf = vec![0; payload::MAX_PACKET_COUNT * mailbox::RESPONSE_PAYLOAD_LEN];
+        let mut reassembler = payload::Reassembler::new(&mut buf, mailbox::RESPONSE_PAYLOAD_LEN);
+        loop {
+            let response_len = self.wait_response(sequence)?;
+            let response = self.read_mailbox(mailbox::RESPONSE_OFFSET as u32, response_len)?;
+            let (header, packet) = payload::read_packet(response.as_slice())?;
+            if header.content != content {
+                return Err(Error::UnexpectedContentType(header.content));
+            }
+            if header.message_id != message_id {
+                return Err(Error::UnexpectedMessageId(header.message_id));
+            }
+            if reassembler.push(&header, packet).map_err(Error::Reassembly)? {
+                break;
+            }
+            // Erasing the mailbox replaces the packet with the next one.
+            self.write_mailbox(OpCode::SectorErase, &[])?;
+        }
+        // The message is complete.
+        let (_, response) = reassembler.message().ok_or(Error::Reassembly(ReassemblyError::Inconsistent))?;
+        Ok(response.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use spiutils::emulator::FlashEmulator;
+    use spiutils::protocol::mailbox::Mailbox;
+    use spiutils::protocol::payload::Dispatcher;
+    use spiutils::protocol::payload::PayloadHandler;
+    use spiutils::protocol::payload::Reassembler;
+    use spiutils::protocol::wire::WireEnum;
+
+    const MAILBOX_ADDRESS: u32 = 0x02000000;
+
+    /// Reverses the request.
+    struct Reverse;
+
+    impl PayloadHandler for Reverse {
+        type Error = ();
+
+        fn process(&mut self, request: &[u8], response: &mut [u8]) -> Result<usize, ()> {
+            for (dest, src) in response.iter_mut().zip(request.iter().rev()) {
+                *dest = *src;
+            }
+            Ok(request.len())
+        }
+    }
+
+    /// A device that serves its mailbox like otpilot and passes all other
+    /// transactions through to a flash emulator.
+    struct Device<'a> {
+        flash: FlashEmulator,
+        dispatcher: Dispatcher<'a, ()>,
+        mailbox: Mailbox,
+        reassembler: Reassembler<'static>,
+        ram: Vec<u8>,
+        // The content type, message id and content of the response, and the
+        // index of the next packet to send.
+        response: (payload::ContentType, u8, Vec<u8>, u8),
+    }
+
+    impl<'a> Device<'a> {
+        fn new(flash: FlashEmulator, dispatcher: Dispatcher<'a, ()>) -> Self {
+            let buf = Box::leak(vec![0; payload::MAX_PACKET_COUNT * mailbox::REQUEST_PAYLOAD_LEN].into_boxed_slice());
+            let mut device = Self {
+                flash,
+                dispatcher,
+                mailbox: Mailbox::new(),
+                reassembler: Reassembler::new(buf, mailbox::REQUEST_PAYLOAD_LEN),
+                ram: vec![0xff; mailbox::MAILBOX_LEN],
+                response: (payload::ContentType::Unknown, 0, vec![], 0),
+            };
+            device.publish(device.mailbox.status());
+            device
+        }
+
+        fn publish(&mut self, status: MailboxStatus) {
+            status.to_wire(spiutils::io::Cursor::new(&mut self.ram[..mailbox::STATUS_LEN])).unwrap();
+        }
+
+        /// Writes the next response packet into the mailbox and returns its
+        /// length.
+        fn next_response_packet(&mut self) -> Option<u16> {
+            let (content, message_id, response, next_packet) = &mut self.response;
+            let count = payload::packet_count(response.len(), mailbox::RESPONSE_PAYLOAD_LEN).unwrap();
+            if *next_packet >= count {
+                return None;
+            }
+            self.ram = vec![0xff; mailbox::MAILBOX_LEN];
+            let mut cursor = spiutils::io::Cursor::new(&mut self.ram[mailbox::RESPONSE_OFFSET..]);
+            payload::write_packet(&mut cursor, *content, *message_id, response, *next_packet,
+                mailbox::RESPONSE_PAYLOAD_LEN).unwrap();
+            *next_packet += 1;
+            Some(cursor.consumed_len() as u16)
+        }
+
+        fn request_packet(&mut self, mut data: &[u8]) -> MailboxStatus {
+            let complete = payload::read_packet(&mut data).ok()
+                .and_then(|(header, content)| self.reassembler.push(&header, content).ok()
+                    .map(|complete| (header.message_id, complete)));
+            let message_id = match complete {
+                Some((_, false)) => return self.mailbox.status(),
+                Some((message_id, true)) => message_id,
+                None => {
+                    self.mailbox.request_received();
+                    return self.mailbox.failed().unwrap();
+                }
+            };
+
+            let (content, request) = self.reassembler.message().unwrap();
+            self.mailbox.request_received();
+            let mut response = vec![0; 4096];
+            match self.dispatcher.dispatch_content(content, request, &mut response) {
+                Ok(len) => {
+                    response.truncate(len);
+                    self.response = (content, message_id, response, 0);
+                    let packet_len = self.next_response_packet().unwrap();
+                    self.mailbox.response_ready(packet_len).unwrap()
+                }
+                Err(_) => self.mailbox.failed().unwrap(),
+            }
+        }
+    }
+
+    impl<'a> Transport for Device<'a> {
+        type Error = spiutils::emulator::Error;
+
+        fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, Self::Error> {
+            let mut data = tx;
+            let header = flash::Header::from_wire(&mut data, self.flash.address_mode())?;
+            let address = match header.get_address() {
+                Some(address) if address >= MAILBOX_ADDRESS => address - MAILBOX_ADDRESS,
+                _ => return self.flash.transaction(tx, rx_len),
+            };
+            match header.opcode {
+                OpCode::PageProgram if self.flash.is_write_enable_set() => {
+                    let status = self.request_packet(data);
+                    self.publish(status);
+                    // An empty PageProgram4B clears WEL.
+                    self.flash.transaction(&[0x12, 0x00, 0x00, 0x00, 0x00], 0)
+                }
+                OpCode::SectorErase if self.flash.is_write_enable_set() => {
+                    let status = match self.next_response_packet() {
+                        Some(packet_len) => self.mailbox.response_continued(packet_len).unwrap(),
+                        None => {
+                            self.ram = vec![0xff; mailbox::MAILBOX_LEN];
+                            self.mailbox.clear()
+                        }
+                    };
+                    self.publish(status);
+                    self.flash.transaction(&[0x12, 0x00, 0x00, 0x00, 0x00], 0)
+                }
+                OpCode::NormalRead => {
+                    let mut rx = vec![0xff; rx_len];
+                    let src = self.ram.get(address as usize..).unwrap_or(&[]);
+                    for (dest, src) in rx.iter_mut().zip(src) {
+                        *dest = *src;
+                    }
+                    Ok(rx)
+                }
+                _ => Ok(vec![0xff; rx_len]),
+            }
+        }
+    }
+
+    #[test]
+    fn send() {
+        let mut reverse = Reverse;
+        let mut dispatcher = Dispatcher::new();
+        dispatcher.register(payload::ContentType::Manticore, &mut reverse).unwrap();
+        let mut flash = FlashEmulator::new(1024 * 1024);
+        flash.set_address_mode(AddressMode::FourByte);
+        let device = Device::new(flash, dispatcher);
+
+        let mut client = MailboxClient::new(device, MAILBOX_ADDRESS, AddressMode::FourByte, 4);
+        let response = client.send(payload::ContentType::Manticore, &[1, 2, 3]).unwrap();
+        assert_eq!(response, [3, 2, 1]);
+        assert!(!client.transport.flash.is_write_enable_set());
+        assert_eq!(client.transport.mailbox.status().sequence, 1);
+
+        assert!(matches!(client.send(payload::ContentType::Spdm, &[1]), Err(Error::RequestFailed)));
+        let response = client.send(payload::ContentType::Manticore, &[4, 5]).unwrap();
+        assert_eq!(response, [5, 4]);
+    }
+
+    #[test]
+    fn send_multiple_packets() {
+        let mut reverse = Reverse;
+        let mut dispatcher = Dispatcher::new();
+        dispatcher.register(payload::ContentType::Manticore, &mut reverse).unwrap();
+        let mut flash = FlashEmulator::new(1024 * 1024);
+        flash.set_address_mode(AddressMode::FourByte);
+        let device = Device::new(flash, dispatcher);
+
+        // Three request packets and two response packets.
+        let request: Vec<u8> = (0..600).map(|i| i as u8).collect();
+        let mut client = MailboxClient::new(device, MAILBOX_ADDRESS, AddressMode::FourByte, 4);
+        let response = client.send(payload::ContentType::Manticore, &request).unwrap();
+        let expected: Vec<u8> = request.iter().rev().copied().collect();
+        assert_eq!(response, expected);
+        assert_eq!(client.transport.mailbox.status().sequence, 1);
+        assert!(!client.transport.flash.is_write_enable_set());
+    }
+
+    /// A device that never completes a request.
+    struct Stuck {
+        status: MailboxStatus,
+    }
+
+    impl Transport for Stuck {
+        type Error = ();
+
+        fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, ()> {
+            match OpCode::from_wire_value(tx[0]) {
+                Some(OpCode::PageProgram) => {
+                    self.status.state = MailboxState::RequestPending;
+                    self.status.sequence += 1;
+                    Ok(vec![])
+                }
+                Some(OpCode::NormalRead) => {
+                    let mut rx = vec![0xff; rx_len];
+                    self.status.to_wire(spiutils::io::Cursor::new(&mut rx)).unwrap();
+                    Ok(rx)
+                }
+                _ => Ok(vec![0xff; rx_len]),
+            }
+        }
+    }
+
+    #[test]
+    fn timeout() {
+        let status = MailboxStatus {
+            state: MailboxState::Idle,
+            sequence: 3,
+            response_len: 0,
+            rejected_writes: 0,
+            last_rejection: None,
+            boot_status: None,
+        };
+        let mut client = MailboxClient::new(Stuck { status }, 0, AddressMode::ThreeByte, 4);
+        assert!(matches!(client.send(payload::ContentType::Manticore, &[1]), Err(Error::Timeout)));
+        assert_eq!(client.transport.status.sequence, 4);
+    }
+}
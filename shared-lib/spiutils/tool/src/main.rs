@@ -23,6 +23,7 @@ use core::convert::TryFrom;
 
 use spiutils::io::StdWrite;
 use spiutils::io::Write;
+use spiutils::protocol::flash::AddressMode;
 use spiutils::protocol::payload;
 use spiutils::protocol::wire::FromWire;
 use spiutils::protocol::wire::ToWire;
@@ -30,6 +31,22 @@ use spiutils::protocol::wire::ToWire;
 use std::fs::OpenOptions;
 use std::io::Read as _;
 
+mod decode;
+mod mailbox;
+mod transport;
+
+fn read_file(file: &str) -> Vec<u8> {
+    let mut input = OpenOptions::new()
+        .read(true)
+        .open(file)
+        .expect("failed to open input file");
+    let mut read_buf = Vec::new();
+    input
+        .read_to_end(&mut read_buf)
+        .expect("couldn't read from file");
+    read_buf
+}
+
 fn wrap(input_file: &str, output_file: &str) {
     let mut input = OpenOptions::new()
         .read(true)
@@ -95,6 +112,103 @@ fn unwrap(input_file: &str, output_file: &str) {
     }
 }
 
+fn decode(
+    input_files: &[&str],
+    miso_files: &[&str],
+    format: &str,
+    address_mode: AddressMode,
+    mailbox_address: u32,
+) {
+    let transactions = match format {
+        "csv" => input_files
+            .iter()
+            .flat_map(|file| {
+                let text = String::from_utf8(read_file(file)).expect("input file is not UTF-8");
+                decode::parse_csv(&text).expect("failed to parse CSV")
+            })
+            .collect::<Vec<_>>(),
+        _ => {
+            if !miso_files.is_empty() && miso_files.len() != input_files.len() {
+                panic!("expected one MISO file per input file");
+            }
+            input_files
+                .iter()
+                .enumerate()
+                .map(|(i, file)| decode::Transaction {
+                    mosi: read_file(file),
+                    miso: miso_files.get(i).map(|file| read_file(file)).unwrap_or_default(),
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    let mut decoder = decode::Decoder::new(address_mode, mailbox_address);
+    for (index, transaction) in transactions.iter().enumerate() {
+        decoder
+            .decode(&mut out, index, transaction)
+            .expect("failed to write output");
+    }
+}
+
+fn send(
+    device: &str,
+    input_file: &str,
+    output_file: Option<&str>,
+    content: payload::ContentType,
+    address_mode: AddressMode,
+    mailbox_address: u32,
+    max_polls: usize,
+) {
+    let request = read_file(input_file);
+    let port = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(device)
+        .expect("failed to open device");
+
+    let transport = transport::SerialTransport::new(port);
+    let mut client = mailbox::MailboxClient::new(transport, mailbox_address, address_mode, max_polls);
+    let response = client
+        .send(content, &request)
+        .unwrap_or_else(|err| panic!("failed to exchange message: {}", err));
+
+    match output_file {
+        Some(output_file) => std::fs::write(output_file, &response).expect("failed to write output file"),
+        None => {
+            let stdout = std::io::stdout();
+            decode::hex_dump(&mut stdout.lock(), "", &response).expect("failed to write output");
+        }
+    }
+}
+
+fn parse_address_mode(value: &str) -> AddressMode {
+    match value {
+        "4" => AddressMode::FourByte,
+        _ => AddressMode::ThreeByte,
+    }
+}
+
+fn parse_content_type(value: &str) -> payload::ContentType {
+    match value {
+        "firmware-update" => payload::ContentType::FirmwareUpdate,
+        "measurement" => payload::ContentType::Measurement,
+        "spdm" => payload::ContentType::Spdm,
+        "vendor-diagnostics" => payload::ContentType::VendorDiagnostics,
+        _ => payload::ContentType::Manticore,
+    }
+}
+
+fn parse_u32(value: &str) -> u32 {
+    if let Some(hex) = value.strip_prefix("0x") {
+        u32::from_str_radix(hex, 16)
+    } else {
+        value.parse::<u32>()
+    }
+    .expect("invalid number")
+}
+
 fn main() {
     let app = App::new("SPI Transport Tool")
         .version("0.1")
@@ -147,6 +261,111 @@ fn main() {
                         .required(true)
                         .takes_value(true),
                 ),
+        )
+        .subcommand(
+            SubCommand::with_name("decode")
+                .about("Decode a capture of SPI transactions")
+                .arg(
+                    Arg::with_name("input")
+                        .short("i")
+                        .long("input")
+                        .help("input files containing the MOSI bytes of one transaction each, or CSV exports")
+                        .required(true)
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("miso")
+                        .short("m")
+                        .long("miso")
+                        .help("files containing the MISO bytes of each transaction, in the same order as the input files")
+                        .multiple(true)
+                        .number_of_values(1)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("format")
+                        .short("f")
+                        .long("format")
+                        .help("format of the input files")
+                        .possible_values(&["binary", "csv"])
+                        .default_value("binary")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("address-mode")
+                        .long("address-mode")
+                        .help("address mode at the start of the capture")
+                        .possible_values(&["3", "4"])
+                        .default_value("3")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("mailbox-address")
+                        .long("mailbox-address")
+                        .help("address of the mailbox that payloads are exchanged through")
+                        .default_value("0x02000000")
+                        .takes_value(true),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("send")
+                .about("Send a message through the mailbox and print the response")
+                .arg(
+                    Arg::with_name("device")
+                        .short("d")
+                        .long("device")
+                        .help("serial port or pty of the SPI bridge")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("input")
+                        .short("i")
+                        .long("input")
+                        .help("input file containing unwrapped message")
+                        .required(true)
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .short("o")
+                        .long("output")
+                        .help("output file for unwrapped response, printed if omitted")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("content-type")
+                        .short("t")
+                        .long("content-type")
+                        .help("content type of the message")
+                        .possible_values(&["manticore", "firmware-update", "measurement", "spdm", "vendor-diagnostics"])
+                        .default_value("manticore")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("address-mode")
+                        .long("address-mode")
+                        .help("address mode of the device")
+                        .possible_values(&["3", "4"])
+                        .default_value("4")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("mailbox-address")
+                        .long("mailbox-address")
+                        .help("address of the mailbox")
+                        .default_value("0x02000000")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("max-polls")
+                        .long("max-polls")
+                        .help("maximum number of mailbox status reads while waiting for the response")
+                        .default_value("1000")
+                        .takes_value(true),
+                ),
         );
     let matches = app.get_matches();
 
@@ -160,5 +379,23 @@ fn main() {
             matches.value_of("input").unwrap(),
             matches.value_of("output").unwrap(),
         );
+    } else if let Some(matches) = matches.subcommand_matches("decode") {
+        decode(
+            &matches.values_of("input").unwrap().collect::<Vec<_>>(),
+            &matches.values_of("miso").map(|v| v.collect::<Vec<_>>()).unwrap_or_default(),
+            matches.value_of("format").unwrap(),
+            parse_address_mode(matches.value_of("address-mode").unwrap()),
+            parse_u32(matches.value_of("mailbox-address").unwrap()),
+        );
+    } else if let Some(matches) = matches.subcommand_matches("send") {
+        send(
+            matches.value_of("device").unwrap(),
+            matches.value_of("input").unwrap(),
+            matches.value_of("output"),
+            parse_content_type(matches.value_of("content-type").unwrap()),
+            parse_address_mode(matches.value_of("address-mode").unwrap()),
+            parse_u32(matches.value_of("mailbox-address").unwrap()),
+            parse_u32(matches.value_of("max-polls").unwrap()) as usize,
+        );
     }
 }
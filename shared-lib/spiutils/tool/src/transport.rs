@@ -0,0 +1,154 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Transports for SPI transactions.
+
+use core::convert::TryFrom;
+use core::fmt;
+
+use spiutils::emulator;
+use spiutils::emulator::FlashEmulator;
+
+use std::fmt::Debug;
+
+/// A way of executing SPI transactions against a SPI flash device.
+pub trait Transport {
+    /// The error returned by the transport.
+    type Error: Debug;
+
+    /// Executes a single SPI transaction.
+    ///
+    /// `tx` contains all bytes sent by the SPI host, i.e. the op code,
+    /// address, dummy bytes and data. `rx_len` is the number of bytes the SPI
+    /// host clocks in after `tx` has been sent.
+    ///
+    /// Returns the `rx_len` bytes sent by the device.
+    fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, Self::Error>;
+}
+
+impl Transport for FlashEmulator {
+    type Error = emulator::Error;
+
+    fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, Self::Error> {
+        FlashEmulator::transaction(self, tx, rx_len)
+    }
+}
+
+/// An error while talking to a serial SPI bridge.
+#[derive(Debug)]
+pub enum SerialError {
+    /// The underlying stream failed.
+    Io(std::io::Error),
+
+    /// The transaction is too long for the bridge protocol.
+    TooLong,
+}
+
+impl fmt::Display for SerialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SerialError::Io(err) => write!(f, "{}", err),
+            SerialError::TooLong => write!(f, "transaction too long"),
+        }
+    }
+}
+
+impl From<std::io::Error> for SerialError {
+    fn from(err: std::io::Error) -> Self {
+        SerialError::Io(err)
+    }
+}
+
+/// A SPI bridge connected through a serial port or pty.
+///
+/// Each transaction is sent to the bridge as the big-endian 16-bit length of
+/// `tx`, the big-endian 16-bit `rx_len` and the `tx` bytes. The bridge
+/// asserts CS, clocks out `tx`, clocks in `rx_len` bytes, deasserts CS and
+/// replies with exactly the `rx_len` bytes it clocked in.
+///
+/// The serial port needs to be configured for raw mode beforehand, e.g. with
+/// `stty -F <device> raw -echo`.
+pub struct SerialTransport<S> {
+    stream: S,
+}
+
+impl<S: std::io::Read + std::io::Write> SerialTransport<S> {
+    /// Creates a new `SerialTransport` talking to a bridge over `stream`.
+    pub fn new(stream: S) -> Self {
+        Self { stream }
+    }
+}
+
+impl<S: std::io::Read + std::io::Write> Transport for SerialTransport<S> {
+    type Error = SerialError;
+
+    fn transaction(&mut self, tx: &[u8], rx_len: usize) -> Result<Vec<u8>, Self::Error> {
+        let tx_len = u16::try_from(tx.len()).map_err(|_| SerialError::TooLong)?;
+        let rx_len_u16 = u16::try_from(rx_len).map_err(|_| SerialError::TooLong)?;
+
+        let mut request = Vec::with_capacity(4 + tx.len());
+        request.extend_from_slice(&tx_len.to_be_bytes());
+        request.extend_from_slice(&rx_len_u16.to_be_bytes());
+        request.extend_from_slice(tx);
+        self.stream.write_all(&request)?;
+        self.stream.flush()?;
+
+        let mut rx = vec![0; rx_len];
+        self.stream.read_exact(&mut rx)?;
+        Ok(rx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A fake bridge that records requests and replies with canned data.
+    struct FakeBridge {
+        written: Vec<u8>,
+        reply: std::io::Cursor<Vec<u8>>,
+    }
+
+    impl std::io::Read for FakeBridge {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.reply.read(buf)
+        }
+    }
+
+    impl std::io::Write for FakeBridge {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn serial_framing() {
+        let mut transport = SerialTransport::new(FakeBridge {
+            written: Vec::new(),
+            reply: std::io::Cursor::new(vec![0x26, 0x02, 0x17]),
+        });
+        assert_eq!(transport.transaction(&[0x9f], 3).unwrap(), [0x26, 0x02, 0x17]);
+        assert_eq!(transport.stream.written, [0x00, 0x01, 0x00, 0x03, 0x9f]);
+
+        // The bridge didn't reply with enough data.
+        assert!(matches!(transport.transaction(&[0x05], 1), Err(SerialError::Io(_))));
+        assert!(matches!(transport.transaction(&[0; 0x10000], 0), Err(SerialError::TooLong)));
+    }
+}
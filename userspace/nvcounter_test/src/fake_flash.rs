@@ -79,7 +79,9 @@ impl<'c> h1::hil::flash::Flash<'c> for FakeFlash<'c> {
         }
     }
 
-    fn write(&self, target: usize, data: &'c mut [u32]) -> (ReturnCode, Option<&'c mut [u32]>) {
+    fn write_partial(&self, target: usize, data: &'c mut [u32], len: usize)
+        -> (ReturnCode, Option<&'c mut [u32]>)
+    {
         if let Some(error_time) = self.error_time.get() {
             return match error_time {
                 ErrorTime::Fast => (kernel::ReturnCode::FAIL, Some(data)),
@@ -90,12 +92,13 @@ impl<'c> h1::hil::flash::Flash<'c> for FakeFlash<'c> {
             };
         }
         if self.busy.get() { return (ReturnCode::EBUSY, Some(data)); }
+        if len > data.len() { return (ReturnCode::ESIZE, Some(data)); }
         // Note: this will fail if the write crosses pages, which is fine for
         // this use case. That may be true of the real flash anyway.
         match offset_to_page(target) {
             None => return (ReturnCode::ESIZE, Some(data)),
-            Some(Page::High) => self.high_page.write(target - HIGH_PAGE_START, data),
-            Some(Page::Low) => self.low_page.write(target - LOW_PAGE_START, data),
+            Some(Page::High) => self.high_page.write(target - HIGH_PAGE_START, &data[..len]),
+            Some(Page::Low) => self.low_page.write(target - LOW_PAGE_START, &data[..len]),
         }
         self.buffer.set(Some(data));
         (ReturnCode::SUCCESS, None)
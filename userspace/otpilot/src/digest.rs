@@ -0,0 +1,161 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use libtock::result::TockResult;
+use libtock::syscalls;
+
+pub const SHA256_DIGEST_LEN: usize = 32;
+
+pub trait Digest {
+    /// Start computing a SHA-256 digest.
+    fn sha256_init(&self) -> TockResult<()>;
+
+    /// Feed `data` into the digest.
+    fn update(&self, data: &[u8]) -> TockResult<()>;
+
+    /// Write the digest of the data fed since `sha256_init` into `digest`.
+    fn finalize(&self, digest: &mut [u8; SHA256_DIGEST_LEN]) -> TockResult<()>;
+
+    /// Compute the SHA-256 digest of `data`.
+    fn sha256(&self, data: &[u8], digest: &mut [u8; SHA256_DIGEST_LEN]) -> TockResult<()>;
+}
+
+// Get the static Digest object.
+pub fn get() -> &'static dyn Digest {
+    get_impl()
+}
+
+/// A SHA-256 digest being computed.
+///
+/// The kernel locks the engine from `sha256_init` until `finalize`, so
+/// dropping an unfinished `Sha256Session`, e.g. on an error, finalizes it to
+/// release the engine.
+pub struct Sha256Session {
+    finalized: bool,
+}
+
+impl Sha256Session {
+    /// Start computing a SHA-256 digest.
+    pub fn new() -> TockResult<Self> {
+        get().sha256_init()?;
+        Ok(Sha256Session { finalized: false })
+    }
+
+    /// Feed `data` into the digest.
+    pub fn update(&mut self, data: &[u8]) -> TockResult<()> {
+        get().update(data)
+    }
+
+    /// Write the digest of the data fed since `new` into `digest`.
+    pub fn finalize(mut self, digest: &mut [u8; SHA256_DIGEST_LEN]) -> TockResult<()> {
+        self.finalized = true;
+        get().finalize(digest)
+    }
+}
+
+impl Drop for Sha256Session {
+    fn drop(&mut self) {
+        if !self.finalized {
+            let _ = get().finalize(&mut [0u8; SHA256_DIGEST_LEN]);
+        }
+    }
+}
+
+const DRIVER_NUMBER: usize = 0x40003;
+
+// The number of bytes fed into the engine at a time.
+const CHUNK_SIZE: usize = 64;
+
+mod command_nr {
+    pub const CHECK_IF_PRESENT: usize = 0;
+    pub const INITIALIZE: usize = 1;
+    pub const UPDATE: usize = 2;
+    pub const FINALIZE: usize = 3;
+}
+
+mod allow_nr {
+    pub const INPUT_BUFFER: usize = 0;
+    pub const OUTPUT_BUFFER: usize = 1;
+}
+
+mod digest_mode {
+    pub const SHA256: usize = 1;
+}
+
+struct DigestImpl {}
+
+static mut DIGEST: DigestImpl = DigestImpl {};
+
+static mut IS_INITIALIZED: bool = false;
+
+fn get_impl() -> &'static DigestImpl {
+    unsafe {
+        if !IS_INITIALIZED {
+            if DIGEST.initialize().is_err() {
+                panic!("Could not initialize Digest");
+            }
+            IS_INITIALIZED = true;
+        }
+        &DIGEST
+    }
+}
+
+impl DigestImpl {
+    fn initialize(&'static mut self) -> TockResult<()> {
+        syscalls::command(DRIVER_NUMBER, command_nr::CHECK_IF_PRESENT, 0, 0)?;
+
+        Ok(())
+    }
+}
+
+impl Digest for DigestImpl {
+    fn sha256_init(&self) -> TockResult<()> {
+        syscalls::command(DRIVER_NUMBER, command_nr::INITIALIZE, digest_mode::SHA256, 0)?;
+
+        Ok(())
+    }
+
+    fn update(&self, data: &[u8]) -> TockResult<()> {
+        // The kernel needs a mutable buffer, so copy the data in chunks.
+        let mut chunk_buffer = [0u8; CHUNK_SIZE];
+        for chunk in data.chunks(CHUNK_SIZE) {
+            chunk_buffer[..chunk.len()].copy_from_slice(chunk);
+
+            // We want this to go out of scope after executing the command
+            let _input_buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::INPUT_BUFFER,
+                &mut chunk_buffer[..chunk.len()])?;
+
+            syscalls::command(DRIVER_NUMBER, command_nr::UPDATE, chunk.len(), 0)?;
+        }
+
+        Ok(())
+    }
+
+    fn finalize(&self, digest: &mut [u8; SHA256_DIGEST_LEN]) -> TockResult<()> {
+        // We want this to go out of scope after executing the command
+        let _output_buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::OUTPUT_BUFFER, digest)?;
+
+        syscalls::command(DRIVER_NUMBER, command_nr::FINALIZE, 0, 0)?;
+
+        Ok(())
+    }
+
+    fn sha256(&self, data: &[u8], digest: &mut [u8; SHA256_DIGEST_LEN]) -> TockResult<()> {
+        let mut session = Sha256Session::new()?;
+        session.update(data)?;
+        session.finalize(digest)
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use core::cell::Cell;
+
+use libtock::result::TockError;
+use libtock::result::TockResult;
+use libtock::syscalls;
+use libtock::syscalls::raw::yieldk;
+
+/// The size of a flash page, which is the unit of erases.
+pub const PAGE_SIZE: usize = 2048;
+
+/// The maximum number of bytes written at a time.
+pub const MAX_WRITE_LEN: usize = 512;
+
+/// The alignment of offsets and lengths of writes.
+pub const WRITE_ALIGNMENT: usize = 4;
+
+pub trait FwUpdate {
+    /// Get the size of the update region in bytes.
+    fn region_size(&self) -> usize;
+
+    /// Erase the page with index `page` within the update region.
+    fn erase(&self, page: usize) -> TockResult<()>;
+
+    /// Write `data` at `offset` within the update region.
+    /// The offset and the length of `data` must be multiples of
+    /// WRITE_ALIGNMENT, and `data` must not be longer than MAX_WRITE_LEN.
+    fn write(&self, offset: usize, data: &mut [u8]) -> TockResult<()>;
+
+    /// Read `data.len()` bytes at `offset` within the update region.
+    /// The offset must be a multiple of WRITE_ALIGNMENT.
+    fn read(&self, offset: usize, data: &mut [u8]) -> TockResult<()>;
+}
+
+// Get the static FwUpdate object.
+pub fn get() -> &'static dyn FwUpdate {
+    get_impl()
+}
+
+const DRIVER_NUMBER: usize = 0x40007;
+
+mod command_nr {
+    pub const CHECK_IF_PRESENT: usize = 0;
+    pub const REGION_SIZE: usize = 1;
+    pub const ERASE: usize = 2;
+    pub const WRITE: usize = 3;
+    pub const READ: usize = 4;
+}
+
+mod subscribe_nr {
+    pub const DONE: usize = 0;
+}
+
+mod allow_nr {
+    pub const BUFFER: usize = 0;
+}
+
+struct FwUpdateImpl {
+    // The size of the update region in bytes.
+    region_size: Cell<usize>,
+
+    // The result of the last erase or write, once it is complete.
+    result: Cell<Option<isize>>,
+}
+
+static mut FW_UPDATE: FwUpdateImpl = FwUpdateImpl {
+    region_size: Cell::new(0),
+    result: Cell::new(None),
+};
+
+static mut IS_INITIALIZED: bool = false;
+
+fn get_impl() -> &'static FwUpdateImpl {
+    unsafe {
+        if !IS_INITIALIZED {
+            if FW_UPDATE.initialize().is_err() {
+                panic!("Could not initialize FwUpdate");
+            }
+            IS_INITIALIZED = true;
+        }
+        &FW_UPDATE
+    }
+}
+
+impl FwUpdateImpl {
+    fn initialize(&'static mut self) -> TockResult<()> {
+        syscalls::command(DRIVER_NUMBER, command_nr::CHECK_IF_PRESENT, 0, 0)?;
+
+        self.region_size.set(syscalls::command(DRIVER_NUMBER, command_nr::REGION_SIZE, 0, 0)?);
+
+        syscalls::subscribe_fn(
+            DRIVER_NUMBER,
+            subscribe_nr::DONE,
+            FwUpdateImpl::done_trampoline,
+            0)?;
+
+        Ok(())
+    }
+
+    extern "C"
+    fn done_trampoline(arg1: usize, arg2: usize, arg3: usize, _data: usize) {
+        get_impl().done(arg1, arg2, arg3);
+    }
+
+    fn done(&self, arg1: usize, _: usize, _: usize) {
+        // arg1: the ReturnCode of the operation
+        self.result.set(Some(arg1 as isize));
+    }
+
+    // Wait for the running erase or write to complete by yielding.
+    fn wait_done(&self) -> TockResult<()> {
+        loop {
+            match self.result.take() {
+                None => unsafe { yieldk(); },
+                Some(0) => return Ok(()),
+                Some(_) => return Err(TockError::Format),
+            }
+        }
+    }
+}
+
+impl FwUpdate for FwUpdateImpl {
+    fn region_size(&self) -> usize {
+        self.region_size.get()
+    }
+
+    fn erase(&self, page: usize) -> TockResult<()> {
+        self.result.set(None);
+        syscalls::command(DRIVER_NUMBER, command_nr::ERASE, page, 0)?;
+        self.wait_done()
+    }
+
+    fn write(&self, offset: usize, data: &mut [u8]) -> TockResult<()> {
+        let len = data.len();
+
+        // We want this to go out of scope after the write is complete
+        let _buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::BUFFER, data)?;
+
+        self.result.set(None);
+        syscalls::command(DRIVER_NUMBER, command_nr::WRITE, offset, len)?;
+        self.wait_done()
+    }
+
+    fn read(&self, offset: usize, data: &mut [u8]) -> TockResult<()> {
+        let len = data.len();
+
+        // We want this to go out of scope after executing the command
+        let _buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::BUFFER, data)?;
+
+        syscalls::command(DRIVER_NUMBER, command_nr::READ, offset, len)?;
+
+        Ok(())
+    }
+}
@@ -16,19 +16,30 @@
 
 #![no_std]
 
+mod digest;
+mod fw_update;
+mod personality;
+mod reset;
+mod rsa_h1;
 mod sfdp;
 mod spi_host;
 mod spi_host_h1;
 mod spi_device;
 
 use core::cmp::min;
-use core::convert::TryFrom;
 use core::fmt::Write;
+use core::future::Future;
 use core::time::Duration;
 
+use futures::future::select;
+use futures::future::Either;
+use futures::pin_mut;
+
 use libtock::console::Console;
 use libtock::result::TockError;
 use libtock::result::TockResult;
+use libtock::timer;
+use libtock::timer::ParallelSleepDriver;
 
 use manticore::crypto::rsa;
 use manticore::hardware;
@@ -38,12 +49,24 @@ use manticore::protocol::device_id;
 use manticore::server::pa_rot::{PaRot, Options};
 
 use spiutils::io::Cursor as SpiutilsCursor;
-use spiutils::io::Write as _;
 use spiutils::driver::HandlerMode;
+use spiutils::manifest;
+use spiutils::manifest::BootStatus;
+use spiutils::manifest::Decision;
+use spiutils::manifest::ManifestReader;
+use spiutils::policy;
+use spiutils::policy::Protection;
+use spiutils::policy::WritePolicy;
+use spiutils::protocol::firmware;
+use spiutils::protocol::firmware::FirmwareUpdate;
 use spiutils::protocol::flash;
-use spiutils::protocol::flash::Address;
 use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::JedecId;
 use spiutils::protocol::flash::OpCode;
+use spiutils::protocol::mailbox;
+use spiutils::protocol::mailbox::Mailbox;
+use spiutils::protocol::mailbox::MailboxStatus;
+use spiutils::protocol::measurement;
 use spiutils::protocol::payload;
 use spiutils::protocol::wire::FromWire;
 use spiutils::protocol::wire::FromWireError;
@@ -52,19 +75,24 @@ use spiutils::protocol::wire::ToWireError;
 
 //////////////////////////////////////////////////////////////////////////////
 
-struct SpiHostDemo;
+struct SpiHostDemo<'a> {
+    timer: &'a ParallelSleepDriver<'a>,
+}
+
+impl<'a> SpiHostDemo<'a> {
+    async fn read_write_done(&self) -> TockResult<()> {
+        with_timeout(self.timer, TIMEOUTS.regular, spi_host::get().read_write_done()).await
+            .map_err(|_| TockError::Format)
+    }
 
-impl SpiHostDemo {
-    pub fn enter_4b(&self) -> TockResult<()> {
+    pub async fn enter_4b(&self) -> TockResult<()> {
         spi_host::get().read_write_bytes(&mut [0xb7], 1)?;
-        spi_host::get().wait_read_write_done();
-        Ok(())
+        self.read_write_done().await
     }
 
-    pub fn exit_4b(&self) -> TockResult<()> {
+    pub async fn exit_4b(&self) -> TockResult<()> {
         spi_host::get().read_write_bytes(&mut [0xe9], 1)?;
-        spi_host::get().wait_read_write_done();
-        Ok(())
+        self.read_write_done().await
     }
 
     fn create_tx_buf(&self, cmd: u8, addr: u32) -> ([u8; spi_host::MAX_READ_BUFFER_LENGTH], usize) {
@@ -74,17 +102,17 @@ impl SpiHostDemo {
         (tx, 5)
     }
 
-    pub fn read_data<'a>(&self, addr: u32, rx_len: usize) -> TockResult<&'static[u8]> {
+    pub async fn read_data(&self, addr: u32, rx_len: usize) -> TockResult<&'static[u8]> {
         let (mut tx, tx_len) = self.create_tx_buf(0x03, addr);
         spi_host::get().read_write_bytes(&mut tx, tx_len + rx_len)?;
-        spi_host::get().wait_read_write_done();
+        self.read_write_done().await?;
         Ok(&spi_host::get().get_read_buffer()[tx_len..])
     }
 
-    pub fn read_and_print_data(&self, addr: u32) -> TockResult<()> {
+    pub async fn read_and_print_data(&self, addr: u32) -> TockResult<()> {
         let mut console = Console::new();
 
-        let rx_buf = self.read_data(addr, 8)?;
+        let rx_buf = self.read_data(addr, 8).await?;
         writeln!(console, "Host: Result: {:02x?}", rx_buf)?;
         Ok(())
     }
@@ -92,8 +120,12 @@ impl SpiHostDemo {
 
 //////////////////////////////////////////////////////////////////////////////
 
+// The maximum length of a message exchanged through the mailbox. Firmware
+// update requests carry a manifest of up to `manifest::MAX_MANIFEST_LEN` bytes.
+const MAX_MESSAGE_LEN: usize = 1536;
+
 const NETWORKING: Networking = Networking {
-    max_message_size: 1024,
+    max_message_size: MAX_MESSAGE_LEN as u32,
     max_packet_size: 256,
     mode: RotMode::Platform,
     roles: BusRole::HOST,
@@ -104,17 +136,56 @@ const TIMEOUTS: Timeouts = Timeouts {
     crypto: Duration::from_millis(200),
 };
 
-const DEVICE_ID: device_id::DeviceIdentifier =
+// The device identifier can be configured at build time through the
+// OTPILOT_VENDOR_ID, OTPILOT_DEVICE_ID, OTPILOT_SUBSYS_VENDOR_ID and
+// OTPILOT_SUBSYS_ID environment variables, in decimal or 0x-prefixed hex.
+fn device_identifier() -> device_id::DeviceIdentifier {
     device_id::DeviceIdentifier {
-        vendor_id: 1,
-        device_id: 2,
-        subsys_vendor_id: 3,
-        subsys_id: 4,
+        vendor_id: parse_id(option_env!("OTPILOT_VENDOR_ID"), 1),
+        device_id: parse_id(option_env!("OTPILOT_DEVICE_ID"), 2),
+        subsys_vendor_id: parse_id(option_env!("OTPILOT_SUBSYS_VENDOR_ID"), 3),
+        subsys_id: parse_id(option_env!("OTPILOT_SUBSYS_ID"), 4),
+    }
+}
+
+// Parse a build time identifier, falling back to `default` if it is not set
+// or invalid.
+fn parse_id(value: Option<&str>, default: u16) -> u16 {
+    let value = match value {
+        Some(value) => value.trim(),
+        None => return default,
+    };
+    let parsed = if value.starts_with("0x") || value.starts_with("0X") {
+        u16::from_str_radix(&value[2..], 16)
+    } else {
+        u16::from_str_radix(value, 10)
     };
+    parsed.unwrap_or(default)
+}
+
+// The output of `git describe` for the build, e.g. "v1.0-3-g0123456".
+const GIT_LONG_TAG: &'static str = include_str!("../../../build/gitlongtag");
 
 struct Identity {
     version: [u8; 32],
-    device_id: [u8; 64],
+    device_id: [u8; personality::PUBLIC_KEY_SIZE],
+}
+
+impl Identity {
+    // The firmware version is the build's git tag, truncated to 32 bytes.
+    // The device is identified by its attestation public key from the
+    // personality data, which is unique per chip.
+    fn new() -> TockResult<Self> {
+        let mut identity = Identity {
+            version: [0; 32],
+            device_id: [0; personality::PUBLIC_KEY_SIZE],
+        };
+        let tag = GIT_LONG_TAG.trim_end().as_bytes();
+        let len = min(tag.len(), identity.version.len());
+        identity.version[..len].copy_from_slice(&tag[..len]);
+        personality::get().get_public_key(&mut identity.device_id)?;
+        Ok(identity)
+    }
 }
 impl hardware::Identity for Identity {
     fn firmware_version(&self) -> &[u8; 32] {
@@ -125,47 +196,98 @@ impl hardware::Identity for Identity {
     }
 }
 
+// Manticore can't handle errors here, so report zero if the kernel fails.
 struct Reset;
 impl hardware::Reset for Reset {
     fn resets_since_power_on(&self) -> u32 {
-        0
+        reset::get().resets_since_power_on().unwrap_or(0)
     }
     fn uptime(&self) -> Duration {
-        Duration::from_millis(1)
+        reset::get().uptime().unwrap_or(Duration::from_millis(0))
     }
 }
 
-struct NoRsaPubKey;
-impl rsa::PublicKey for NoRsaPubKey {
+// The maximum supported RSA modulus length, in bytes. This matches the
+// kernel's RSA scratch space.
+const MAX_RSA_MODULUS_LEN: usize = 3072 / 8;
+
+struct H1RsaPubKey {
+    modulus: [u8; MAX_RSA_MODULUS_LEN],
+    len: rsa::ModulusLength,
+    exponent: u32,
+}
+
+impl H1RsaPubKey {
+    // Create a public key from the big-endian `modulus` and `exponent`.
+    fn new(modulus: &[u8], exponent: u32) -> Option<Self> {
+        let len = match modulus.len() {
+            256 => rsa::ModulusLength::Bits2048,
+            384 => rsa::ModulusLength::Bits3072,
+            _ => return None,
+        };
+        let mut key = H1RsaPubKey {
+            modulus: [0; MAX_RSA_MODULUS_LEN],
+            len,
+            exponent,
+        };
+        key.modulus[..modulus.len()].copy_from_slice(modulus);
+        Some(key)
+    }
+
+    fn modulus_len(&self) -> usize {
+        match self.len {
+            rsa::ModulusLength::Bits2048 => 256,
+            rsa::ModulusLength::Bits3072 => 384,
+            rsa::ModulusLength::Bits4096 => 512,
+        }
+    }
+}
+
+impl rsa::PublicKey for H1RsaPubKey {
     fn len(&self) -> rsa::ModulusLength {
-        unreachable!()
+        self.len
     }
 }
 
-struct NoRsaEngine;
-impl rsa::Engine for NoRsaEngine {
+struct H1RsaEngine {
+    key: H1RsaPubKey,
+}
+
+impl rsa::Engine for H1RsaEngine {
     type Error = ();
-    type Key = NoRsaPubKey;
+    type Key = H1RsaPubKey;
 
     fn verify_signature(
         &mut self,
-        _signature: &[u8],
-        _message: &[u8],
+        signature: &[u8],
+        message: &[u8],
     ) -> Result<(), ()> {
-        Err(())
+        let len = self.key.modulus_len();
+        if signature.len() != len {
+            return Err(());
+        }
+
+        let mut digest = [0u8; digest::SHA256_DIGEST_LEN];
+        digest::get().sha256(message, &mut digest).map_err(|_| ())?;
+
+        // The kernel needs mutable buffers.
+        let mut signature_buf = [0u8; MAX_RSA_MODULUS_LEN];
+        signature_buf[..len].copy_from_slice(signature);
+        rsa_h1::get().verify_pkcs1_sha256(&mut self.key.modulus[..len], self.key.exponent,
+            &mut signature_buf[..len], &mut digest).map_err(|_| ())
     }
 }
 
-struct NoRsa;
-impl rsa::Builder for NoRsa {
-    type Engine = NoRsaEngine;
+struct H1Rsa;
+impl rsa::Builder for H1Rsa {
+    type Engine = H1RsaEngine;
 
-    fn supports_modulus(&self, _: rsa::ModulusLength) -> bool {
-        true
+    fn supports_modulus(&self, len: rsa::ModulusLength) -> bool {
+        !matches!(len, rsa::ModulusLength::Bits4096)
     }
 
-    fn new_engine(&self, _key: NoRsaPubKey) -> Result<NoRsaEngine, ()> {
-        Err(())
+    fn new_engine(&self, key: H1RsaPubKey) -> Result<H1RsaEngine, ()> {
+        Ok(H1RsaEngine { key })
     }
 }
 
@@ -178,9 +300,14 @@ enum SpiProcessorError {
     Tock,
     Manticore(manticore::server::Error),
     UnsupportedContentType(payload::ContentType),
+    TooManyPayloadHandlers,
     UnsupportedOpCode(OpCode),
     InvalidAddress(Option<u32>),
+    Mailbox(mailbox::InvalidTransition),
+    Reassembly(payload::ReassemblyError),
+    ResponseTooLong(usize),
     Format(core::fmt::Error),
+    Timeout,
 }
 
 impl From<FromWireError> for SpiProcessorError {
@@ -207,6 +334,31 @@ impl From<manticore::server::Error> for SpiProcessorError {
     }
 }
 
+impl From<payload::DispatchError<SpiProcessorError>> for SpiProcessorError {
+    fn from(err: payload::DispatchError<SpiProcessorError>) -> Self {
+        match err {
+            payload::DispatchError::FromWire(err) => SpiProcessorError::FromWire(err),
+            payload::DispatchError::ToWire(err) => SpiProcessorError::ToWire(err),
+            payload::DispatchError::UnsupportedContentType(content) =>
+                SpiProcessorError::UnsupportedContentType(content),
+            payload::DispatchError::TooManyHandlers => SpiProcessorError::TooManyPayloadHandlers,
+            payload::DispatchError::Handler(err) => err,
+        }
+    }
+}
+
+impl From<mailbox::InvalidTransition> for SpiProcessorError {
+    fn from(err: mailbox::InvalidTransition) -> Self {
+        SpiProcessorError::Mailbox(err)
+    }
+}
+
+impl From<payload::ReassemblyError> for SpiProcessorError {
+    fn from(err: payload::ReassemblyError) -> Self {
+        SpiProcessorError::Reassembly(err)
+    }
+}
+
 impl From<core::fmt::Error> for SpiProcessorError {
     fn from(err: core::fmt::Error) -> Self {
         SpiProcessorError::Format(err)
@@ -215,148 +367,672 @@ impl From<core::fmt::Error> for SpiProcessorError {
 
 //////////////////////////////////////////////////////////////////////////////
 
-struct SpiProcessor<'a> {
-    server: PaRot<'a, Identity, Reset, NoRsa>,
+// Wait for `future` to complete, but no longer than `timeout`.
+async fn with_timeout<F>(timer: &ParallelSleepDriver<'_>, timeout: Duration, future: F)
+    -> SpiProcessorResult<F::Output>
+where F: Future + Unpin
+{
+    let sleep = timer.sleep(timer::Duration::from_ms(timeout.as_millis() as isize));
+    pin_mut!(sleep);
+    match select(future, sleep).await {
+        Either::Left((output, _)) => Ok(output),
+        Either::Right(_) => Err(SpiProcessorError::Timeout),
+    }
 }
 
-const SPI_TX_BUF_SIZE : usize = 512;
+// Read `data.len()` bytes of the host flash starting at `address` via the
+// SPI host. Outside of boot, this must only be called while the SPI device
+// holds the host in a transaction, so the read doesn't interleave with host
+// traffic passed through to the flash.
+async fn read_host_flash(timer: &ParallelSleepDriver<'_>, address: u32, data: &mut [u8])
+    -> SpiProcessorResult<()> {
+    let header = flash::Header::read(spi_device::get().get_address_mode(), address, data.len() as u32);
+    let transfer = spi_host_h1::get().transfer(&header, false, data)?;
+    with_timeout(timer, TIMEOUTS.regular, transfer).await??;
+    Ok(())
+}
 
-type SpiProcessorResult<T> = Result<T, SpiProcessorError>;
+// The buffer for reading the host flash. It is too large for the stack.
+static mut HOST_FLASH_READ_BUFFER: [u8; 1024] = [0xff; 1024];
+
+// The buffer for data written to the host flash, which must be mutable to
+// share it with the kernel.
+static mut HOST_FLASH_WRITE_BUFFER: [u8; spi_device::MAX_READ_BUFFER_SIZE] = [0xff; spi_device::MAX_READ_BUFFER_SIZE];
+
+// Read the `len` bytes of the host flash starting at `address` and pass them
+// to `f` in chunks of `HOST_FLASH_READ_BUFFER` until `f` returns false.
+async fn scan_host_flash<F>(timer: &ParallelSleepDriver<'_>, mut address: u32, mut len: u32, mut f: F)
+    -> SpiProcessorResult<()>
+where F: FnMut(&[u8]) -> SpiProcessorResult<bool>
+{
+    let buffer = unsafe { &mut HOST_FLASH_READ_BUFFER };
+    while len > 0 {
+        let read_len = min(buffer.len(), len as usize);
+        read_host_flash(timer, address, &mut buffer[..read_len]).await?;
+        if !f(&buffer[..read_len])? {
+            return Ok(());
+        }
 
-impl<'a> SpiProcessor<'a> {
+        // `read_len` is at most `len`, so this cannot overflow.
+        address = address.wrapping_add(read_len as u32);
+        len -= read_len as u32;
+    }
+    Ok(())
+}
 
-    fn send_data(&mut self, tx_header: &payload::Header, tx_buf: &mut[u8]) -> SpiProcessorResult<()> {
-        {
-            // Scope for tx_cursor (which doesn't implement Drop).
-            // We need tx_cursor to go out of scope so that we can use tx_buf further down.
-            let tx_cursor = SpiutilsCursor::new(tx_buf);
-            tx_header.to_wire(tx_cursor)?;
-        }
-        spi_device::get().end_transaction_with_data(tx_buf, true, true)?;
+// The host flash regions measured at boot.
+const HOST_FLASH_MEASURED_REGIONS: &[measurement::Region] = &[
+    // The boot block.
+    measurement::Region { start: 0x0, len: 64 * 1024 },
+];
+
+// Compute the SHA-256 digest of `region` of the host flash.
+async fn measure_host_flash_region(timer: &ParallelSleepDriver<'_>, region: &measurement::Region)
+    -> SpiProcessorResult<[u8; measurement::DIGEST_LEN]> {
+    let mut digest = [0u8; measurement::DIGEST_LEN];
+    let mut session = digest::Sha256Session::new()?;
+    scan_host_flash(timer, region.start, region.len, |data| {
+        session.update(data)?;
+        Ok(true)
+    }).await?;
+    session.finalize(&mut digest)?;
+    Ok(digest)
+}
 
-        Ok(())
+// Measure `HOST_FLASH_MEASURED_REGIONS` with the SHA-256 engine.
+async fn measure_host_flash(timer: &ParallelSleepDriver<'_>) -> SpiProcessorResult<measurement::Log> {
+    let mut console = Console::new();
+    let mut log = measurement::Log::new();
+    for region in HOST_FLASH_MEASURED_REGIONS {
+        let digest = measure_host_flash_region(timer, region).await?;
+        writeln!(console, "Device: Measured {:#x}+{:#x}: {:02x?}", region.start, region.len, digest)?;
+
+        // The new PMR is the digest of the old PMR followed by the new measurement.
+        let entry = measurement::Measurement { region: *region, digest };
+        log.record(entry, |pmr, entry_digest| -> TockResult<[u8; measurement::DIGEST_LEN]> {
+            let mut new_pmr = [0u8; measurement::DIGEST_LEN];
+            let mut session = digest::Sha256Session::new()?;
+            session.update(pmr)?;
+            session.update(entry_digest)?;
+            session.finalize(&mut new_pmr)?;
+            Ok(new_pmr)
+        }).map_err(|_| SpiProcessorError::Tock)?;
     }
+    Ok(log)
+}
+
+// The host flash addresses of the signed manifests of the primary and the
+// recovery image. The recovery image is above the 3-byte address space, so it
+// is read with 4-byte addresses.
+const HOST_MANIFEST_ADDRESS: u32 = 0x10000;
+const HOST_RECOVERY_MANIFEST_ADDRESS: u32 = 0x1000000;
+
+// The buffer for reading manifests. It is too large for the stack.
+static mut MANIFEST_BUFFER: [u8; manifest::MAX_MANIFEST_LEN] = [0xff; manifest::MAX_MANIFEST_LEN];
 
-    fn process_manticore(&mut self, mut data: &[u8]) -> SpiProcessorResult<()> {
+// Compute the SHA-256 digest of `data` with the digest engine.
+fn sha256(data: &[u8]) -> SpiProcessorResult<[u8; measurement::DIGEST_LEN]> {
+    let mut digest = [0u8; measurement::DIGEST_LEN];
+    digest::get().sha256(data, &mut digest)?;
+    Ok(digest)
+}
+
+// Whether the signature of `manifest` is valid, checked with the RSA engine.
+fn verify_manifest_signature(manifest: &ManifestReader) -> bool {
+    match H1RsaPubKey::new(manifest.modulus(), manifest.exponent()) {
+        Some(key) => rsa::Engine::verify_signature(&mut H1RsaEngine { key },
+            manifest.signature(), manifest.signed_data()).is_ok(),
+        None => false,
+    }
+}
+
+// Verify `data` as a manifest signed with the key with `trusted_key_digest`
+// whose regions have the digests returned by `measure`.
+fn verify_manifest<Me>(data: &[u8], trusted_key_digest: &[u8; measurement::DIGEST_LEN], measure: Me)
+    -> Result<(), manifest::Failure>
+where Me: FnMut(&measurement::Region) -> SpiProcessorResult<[u8; measurement::DIGEST_LEN]>
+{
+    manifest::verify(data, trusted_key_digest, sha256, verify_manifest_signature, measure)?;
+    Ok(())
+}
+
+// Verify the manifest at `address` of the host flash against the key with
+// `trusted_key_digest`, and the regions it lists.
+async fn verify_host_image(timer: &ParallelSleepDriver<'_>, address: u32,
+    trusted_key_digest: &[u8; measurement::DIGEST_LEN]) -> Result<(), manifest::Failure> {
+    let buffer = unsafe { &mut MANIFEST_BUFFER };
+    read_host_flash(timer, address, buffer).await
+        .map_err(|_| manifest::Failure::Internal)?;
+
+    // Reading the regions needs the timer, so they are measured here rather
+    // than by `manifest::verify`.
+    let reader = manifest::authenticate(&buffer[..], trusted_key_digest, sha256, verify_manifest_signature)?;
+    for expected in reader.regions() {
+        let digest = measure_host_flash_region(timer, &expected.region).await
+            .map_err(|_| manifest::Failure::Internal)?;
+        if digest != expected.digest {
+            return Err(manifest::Failure::DigestMismatch);
+        }
+    }
+    Ok(())
+}
+
+// Decide which host image may boot. The primary image boots if its manifest
+// verifies. Otherwise the recovery image boots in recovery mode, which allows
+// writes to `Protection::RecoveryOnly` regions so that the host can restore
+// the primary image. If neither verifies, the host flash is not released.
+// The device does not redirect the host to the recovery image; the host boots
+// it by itself when the primary image is broken.
+async fn verified_boot(timer: &ParallelSleepDriver<'_>) -> TockResult<BootStatus> {
+    let mut console = Console::new();
+    let mut trusted_key_digest = [0u8; personality::BOOT_KEY_DIGEST_SIZE];
+    personality::get().get_boot_key_digest(&mut trusted_key_digest)?;
+
+    let failure = match verify_host_image(timer, HOST_MANIFEST_ADDRESS, &trusted_key_digest).await {
+        Ok(()) => return Ok(BootStatus { decision: Decision::Verified, failure: None }),
+        Err(failure) => failure,
+    };
+    writeln!(console, "Device: Primary image rejected: {:?}", failure)?;
+
+    let decision = match verify_host_image(timer, HOST_RECOVERY_MANIFEST_ADDRESS, &trusted_key_digest).await {
+        Ok(()) => Decision::Recovery,
+        Err(recovery_failure) => {
+            writeln!(console, "Device: Recovery image rejected: {:?}", recovery_failure)?;
+            Decision::Halted
+        }
+    };
+    Ok(BootStatus { decision, failure: Some(failure) })
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+struct ManticoreHandler<'a> {
+    server: PaRot<'a, Identity, Reset, H1Rsa>,
+}
+
+impl<'a> payload::PayloadHandler for ManticoreHandler<'a> {
+    type Error = SpiProcessorError;
+
+    fn process(&mut self, mut request: &[u8], response: &mut [u8]) -> SpiProcessorResult<usize> {
         let mut console = Console::new();
         writeln!(console, "Device: Manticore!")?;
 
-        let mut tx_buf : [u8; SPI_TX_BUF_SIZE] = [0xff; SPI_TX_BUF_SIZE];
-        let payload_len : u16;
-        {
-            let mut tx_cursor = ManticoreCursor::new(&mut tx_buf[payload::HEADER_LEN..]);
-            self.server.process_request(&mut data, &mut tx_cursor)?;
-            payload_len = u16::try_from(tx_cursor.consumed_len())
-                .map_err(|_| SpiProcessorError::FromWire(FromWireError::OutOfRange))?;
+        let mut tx_cursor = ManticoreCursor::new(response);
+        self.server.process_request(&mut request, &mut tx_cursor)?;
+        Ok(tx_cursor.consumed_len())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+// Answers measurement requests from the host flash measurements taken at boot.
+struct MeasurementHandler<'a> {
+    measurements: &'a measurement::Log,
+}
+
+impl<'a> payload::PayloadHandler for MeasurementHandler<'a> {
+    type Error = SpiProcessorError;
+
+    fn process(&mut self, request: &[u8], response: &mut [u8]) -> SpiProcessorResult<usize> {
+        let request = measurement::Request::from_wire(request)?;
+        let mut console = Console::new();
+        writeln!(console, "Device: Measurement request: {:?}", request)?;
+
+        let mut tx_cursor = SpiutilsCursor::new(response);
+        self.measurements.respond(&request).to_wire(&mut tx_cursor)?;
+        Ok(tx_cursor.consumed_len())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+// The length of the signed header at the start of a firmware image. The boot
+// ROM only boots an image with a valid header.
+const IMAGE_HEADER_LEN: usize = 1024;
+
+// The header of the firmware image being written. It is too large for the
+// stack.
+static mut IMAGE_HEADER_BUFFER: [u8; IMAGE_HEADER_LEN] = [0xff; IMAGE_HEADER_LEN];
+
+// Writes firmware updates to the RW image of the inactive flash bank, which
+// the kernel exposes as the update region.
+// The image header is kept in `IMAGE_HEADER_BUFFER` and only written on
+// commit, once the image was verified against a manifest signed with the boot
+// key from Personality. Until then, the image in the update region has no
+// header and is not booted.
+struct FirmwareUpdateHandler {
+    update: FirmwareUpdate,
+    trusted_key_digest: [u8; personality::BOOT_KEY_DIGEST_SIZE],
+}
+
+impl FirmwareUpdateHandler {
+    fn new() -> TockResult<Self> {
+        let mut trusted_key_digest = [0u8; personality::BOOT_KEY_DIGEST_SIZE];
+        personality::get().get_boot_key_digest(&mut trusted_key_digest)?;
+        Ok(FirmwareUpdateHandler {
+            update: FirmwareUpdate::new(fw_update::get().region_size() as u32),
+            trusted_key_digest,
+        })
+    }
+
+    fn erase(len: u32) -> TockResult<()> {
+        unsafe { IMAGE_HEADER_BUFFER = [0xff; IMAGE_HEADER_LEN] };
+        let page_count = (len as usize + fw_update::PAGE_SIZE - 1) / fw_update::PAGE_SIZE;
+        for page in 0..page_count {
+            fw_update::get().erase(page)?;
         }
-        let tx_header = payload::Header {
-            content: payload::ContentType::Manticore,
-            content_len: payload_len,
-        };
-        self.send_data(&tx_header, &mut tx_buf)?;
-        writeln!(console, "Device: Data sent")?;
         Ok(())
     }
 
-    fn process_spi_payload(&mut self, mut data: &[u8]) -> SpiProcessorResult<()> {
-        let mut console = Console::new();
-        let header = payload::Header::from_wire(&mut data)?;
-        writeln!(console, "Device: payload header: {:?}", header)?;
-        match header.content {
-            payload::ContentType::Manticore => {
-                self.process_manticore(&data[..header.content_len as usize])
-            }
-            _ => {
-                Err(SpiProcessorError::UnsupportedContentType(header.content))
+    // Write `data` at `offset` of the image, holding back the image header.
+    fn write(offset: u32, data: &[u8]) -> TockResult<()> {
+        let (header, rest_offset, rest) = firmware::split_header(offset, data, IMAGE_HEADER_LEN as u32);
+        if !header.is_empty() {
+            let offset = offset as usize;
+            unsafe { IMAGE_HEADER_BUFFER[offset..offset + header.len()].copy_from_slice(header) };
+        }
+        FirmwareUpdateHandler::write_flash(rest_offset as usize, rest)
+    }
+
+    fn write_flash(offset: usize, data: &[u8]) -> TockResult<()> {
+        let mut chunk_buffer = [0xffu8; fw_update::MAX_WRITE_LEN];
+        for (index, chunk) in data.chunks(fw_update::MAX_WRITE_LEN).enumerate() {
+            // The last chunk is padded with erased bytes to the write alignment.
+            let len = (chunk.len() + fw_update::WRITE_ALIGNMENT - 1) & !(fw_update::WRITE_ALIGNMENT - 1);
+            chunk_buffer[..chunk.len()].copy_from_slice(chunk);
+            for byte in chunk_buffer[chunk.len()..len].iter_mut() {
+                *byte = 0xff;
             }
+            let chunk_offset = offset + index * fw_update::MAX_WRITE_LEN;
+            fw_update::get().write(chunk_offset, &mut chunk_buffer[..len])?;
         }
+        Ok(())
     }
 
-    // Send data via the SPI host.
-    // The transaction is split into smaller transactions that fit into the SPI host's buffer.
-    // The write enable status bit is set before each transaction is executed.
-    // The `pre_transaction_fn` is executed prior to each transaction.
-    fn spi_host_send<AddrType, F>(&self, header: &flash::Header::<AddrType>, mut data: &[u8], pre_transaction_fn: &F) -> SpiProcessorResult<()>
-    where AddrType: Address,
-        F: Fn() -> SpiProcessorResult<()>
-    {
-        // We need to update the header so copy it.
-        let mut header = *header;
-        loop {
-            pre_transaction_fn()?;
-
-            let mut tx_buf = [0xff; spi_host::MAX_READ_BUFFER_LENGTH];
-            let tx_len : usize;
-            let data_len_to_send : usize;
-            {
-                let mut tx_cursor = SpiutilsCursor::new(&mut tx_buf);
-                header.to_wire(&mut tx_cursor)?;
-                if header.opcode.has_dummy_byte() {
-                    // Skip one dummy byte (send 0x0)
-                    tx_cursor.write_bytes(&[0x0; 1])
-                        .map_err(|err| SpiProcessorError::ToWire(ToWireError::Io(err)))?;
-                }
+    // Compute the SHA-256 digest of `region` of the image, taking the part
+    // within the image header from `IMAGE_HEADER_BUFFER`.
+    fn measure(region: &measurement::Region) -> SpiProcessorResult<[u8; measurement::DIGEST_LEN]> {
+        let header = unsafe { &IMAGE_HEADER_BUFFER };
+        let mut chunk_buffer = [0u8; fw_update::MAX_WRITE_LEN];
+        let mut offset = region.start as usize;
+        let end = offset + region.len as usize;
+        let mut session = digest::Sha256Session::new()?;
+        while offset < end {
+            let chunk_len = if offset < IMAGE_HEADER_LEN {
+                let chunk_len = min(end, IMAGE_HEADER_LEN) - offset;
+                session.update(&header[offset..offset + chunk_len])?;
+                chunk_len
+            } else {
+                // Reads must start at a multiple of the write alignment.
+                let skip = offset % fw_update::WRITE_ALIGNMENT;
+                let chunk_len = min(end - offset, chunk_buffer.len() - skip);
+                fw_update::get().read(offset - skip, &mut chunk_buffer[..skip + chunk_len])?;
+                session.update(&chunk_buffer[skip..skip + chunk_len])?;
+                chunk_len
+            };
+            offset += chunk_len;
+        }
+        let mut digest = [0u8; measurement::DIGEST_LEN];
+        session.finalize(&mut digest)?;
+        Ok(digest)
+    }
 
-                data_len_to_send = min(spi_host::MAX_READ_BUFFER_LENGTH - tx_cursor.consumed_len(), data.len());
-                tx_cursor.write_bytes(&data[..data_len_to_send])
-                    .map_err(|err| SpiProcessorError::ToWire(ToWireError::Io(err)))?;
+    // Write the held back header of the verified image of `image_len` bytes.
+    fn commit(image_len: u32) -> TockResult<()> {
+        let header_len = min(image_len as usize, IMAGE_HEADER_LEN);
+        FirmwareUpdateHandler::write_flash(0, unsafe { &IMAGE_HEADER_BUFFER[..header_len] })
+    }
+}
 
-                tx_len = tx_cursor.consumed_len()
-            }
+impl payload::PayloadHandler for FirmwareUpdateHandler {
+    type Error = SpiProcessorError;
 
-            spi_host_h1::get().set_wait_busy_clear_in_transactions(header.opcode.wait_busy_clear())?;
-            spi_host::get().read_write_bytes(&mut tx_buf, tx_len)?;
-            spi_host::get().wait_read_write_done();
-
-            // Move data and address forward
-            data = &data[data_len_to_send..];
-            if let Some(addr) = header.address {
-                let delta : u32 = core::convert::TryFrom::<usize>::try_from(data_len_to_send)
-                    .map_err(|_| SpiProcessorError::FromWire(FromWireError::OutOfRange))?;
-                let next_addr = addr.into() + delta;
-                header.address = Some(AddrType::try_from(next_addr)
-                    .map_err(|_| SpiProcessorError::FromWire(FromWireError::OutOfRange))?);
-            }
+    fn process(&mut self, request: &[u8], response: &mut [u8]) -> SpiProcessorResult<usize> {
+        let mut console = Console::new();
+
+        let request = firmware::Request::from_wire(request)?;
+        let image_len = self.update.status().image_len;
+        let trusted_key_digest = &self.trusted_key_digest;
+        let status = self.update.process(&request,
+            FirmwareUpdateHandler::erase,
+            FirmwareUpdateHandler::write,
+            |manifest| verify_manifest(manifest, trusted_key_digest, FirmwareUpdateHandler::measure),
+            || FirmwareUpdateHandler::commit(image_len));
+        writeln!(console, "Device: Firmware update: {:?}", status)?;
+
+        let mut tx_cursor = SpiutilsCursor::new(response);
+        status.to_wire(&mut tx_cursor)?;
+        Ok(tx_cursor.consumed_len())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+
+// A write to the host flash that passed the write policy. Its data is in
+// `HOST_FLASH_WRITE_BUFFER`.
+#[derive(Copy, Clone, Debug)]
+struct HostFlashWrite {
+    header: flash::Header,
+    len: usize,
+}
+
+// A response that is sent to the SPI host one packet at a time. Its content
+// is in `SpiProcessor::response_buf`.
+#[derive(Copy, Clone, Debug)]
+struct Response {
+    content: payload::ContentType,
+    message_id: u8,
+    len: usize,
+    packet_count: u8,
+    // The index of the packet in the mailbox.
+    packet_index: u8,
+}
+
+// The buffers for reassembling requests and for responses. They are too
+// large for the stack.
+static mut REQUEST_BUFFER: [u8; MAX_MESSAGE_LEN] = [0; MAX_MESSAGE_LEN];
+static mut RESPONSE_BUFFER: [u8; MAX_MESSAGE_LEN] = [0; MAX_MESSAGE_LEN];
+
+struct SpiProcessor<'a> {
+    dispatcher: payload::Dispatcher<'a, SpiProcessorError>,
+    mailbox: Mailbox,
+    // The status word followed by the current response packet, if any.
+    // Sending data to the SPI host replaces the whole mailbox, so the packet
+    // is kept here to be sent again whenever the status word changes.
+    mailbox_buf: [u8; SPI_TX_BUF_SIZE],
+    // Reassembles requests from the packets written to the mailbox.
+    reassembler: payload::Reassembler<'a>,
+    response_buf: &'a mut [u8],
+    response: Option<Response>,
+    // Whether writes to `Protection::RecoveryOnly` regions are allowed.
+    recovery_mode: bool,
+    timer: &'a ParallelSleepDriver<'a>,
+    // The host flash write to execute once the current transaction has been
+    // processed.
+    pending_write: Option<HostFlashWrite>,
+    // Whether a host flash write is in progress. The BUSY bit stays set
+    // until it is done.
+    host_flash_busy: bool,
+    // The maximum times of host flash writes, which bound their timeouts.
+    write_times: sfdp::WriteTimes,
+}
+
+const SPI_TX_BUF_SIZE : usize = mailbox::MAILBOX_LEN;
+
+// The size of the host flash behind the SPI host.
+const HOST_FLASH_SIZE : u32 = 0x02000000;
+
+// The protected regions of the host flash.
+const HOST_FLASH_REGIONS: &[policy::Region] = &[
+    // The boot block.
+    policy::Region { start: 0x0, len: 64 * 1024, protection: Protection::ReadOnly },
+];
+
+const HOST_FLASH_POLICY: WritePolicy<'static> = WritePolicy::new(HOST_FLASH_REGIONS);
+
+type SpiProcessorResult<T> = Result<T, SpiProcessorError>;
+
+impl<'a> SpiProcessor<'a> {
+
+    // Make the mailbox status available to the SPI host, followed by the
+    // response in `mailbox_buf`, if any.
+    fn publish_mailbox_status(&mut self, status: MailboxStatus,
+        clear_busy: bool, clear_write_enable: bool) -> SpiProcessorResult<()> {
+        status.to_wire(&mut self.mailbox_buf[..mailbox::STATUS_LEN])?;
+        spi_device::get().end_transaction_with_data(&mut self.mailbox_buf,
+            clear_busy && !self.host_flash_busy, clear_write_enable)?;
+        Ok(())
+    }
+
+    fn discard_response(&mut self) {
+        for byte in self.mailbox_buf[mailbox::RESPONSE_OFFSET..].iter_mut() {
+            *byte = 0xff;
+        }
+    }
+
+    // Write packet `response.packet_index` of `response` into `mailbox_buf`.
+    // Returns the length of the packet.
+    fn write_response_packet(&mut self, response: &Response) -> SpiProcessorResult<u16> {
+        self.discard_response();
+        let mut tx_cursor = SpiutilsCursor::new(&mut self.mailbox_buf[mailbox::RESPONSE_OFFSET..]);
+        payload::write_packet(&mut tx_cursor, response.content, response.message_id,
+            &self.response_buf[..response.len], response.packet_index, mailbox::RESPONSE_PAYLOAD_LEN)?;
+        // The packet fits into `mailbox_buf`, so its length fits into a u16.
+        Ok(tx_cursor.consumed_len() as u16)
+    }
 
-            if data.len() == 0 { break; }
+    // Write the next packet of the response into `mailbox_buf`.
+    // Returns the length of the packet, or None if all packets were sent.
+    fn next_response_packet(&mut self) -> SpiProcessorResult<Option<u16>> {
+        let response = match self.response {
+            Some(response) if response.packet_index + 1 < response.packet_count =>
+                Response { packet_index: response.packet_index + 1, ..response },
+            _ => return Ok(None),
+        };
+        self.response = Some(response);
+        Ok(Some(self.write_response_packet(&response)?))
+    }
+
+    // The SPI host is done with the response packet in the mailbox.
+    // Replace it with the next one, or clear the mailbox after the last one.
+    fn clear_mailbox(&mut self) -> SpiProcessorResult<()> {
+        if let Some(packet_len) = self.next_response_packet()? {
+            let status = self.mailbox.response_continued(packet_len)?;
+            return self.publish_mailbox_status(status, true, true);
         }
+        self.response = None;
+        let status = self.mailbox.clear();
+        self.discard_response();
+        self.publish_mailbox_status(status, true, true)
+    }
+
+    // Process the reassembled request with `message_id` and write the first
+    // packet of the response into `mailbox_buf`.
+    // Returns the length of the packet.
+    fn dispatch_request(&mut self, message_id: u8) -> SpiProcessorResult<u16> {
+        let mut console = Console::new();
+        let (content, request) = self.reassembler.message()
+            .ok_or(SpiProcessorError::Reassembly(payload::ReassemblyError::Inconsistent))?;
+        writeln!(console, "Device: request: {:?}, {} bytes", content, request.len())?;
+        let len = self.dispatcher.dispatch_content(content, request, &mut self.response_buf[..])?;
+        let packet_count = payload::packet_count(len, mailbox::RESPONSE_PAYLOAD_LEN)
+            .ok_or(SpiProcessorError::ResponseTooLong(len))?;
+        let response = Response { content, message_id, len, packet_count, packet_index: 0 };
+        self.response = Some(response);
+        self.write_response_packet(&response)
+    }
+
+    // Add the packet in `data` to the request. Once the request is complete,
+    // process it and make the first packet of the response available.
+    fn process_spi_payload(&mut self, mut data: &[u8]) -> SpiProcessorResult<()> {
+        let mut console = Console::new();
+
+        let reassembler = &mut self.reassembler;
+        let packet = payload::read_packet(&mut data)
+            .map_err(SpiProcessorError::from)
+            .and_then(|(header, content)| {
+                writeln!(console, "Device: packet header: {:?}", header)?;
+                Ok((header.message_id, reassembler.push(&header, content)?))
+            });
+        if let Ok((_, false)) = packet {
+            // Wait for the remaining packets of the request.
+            return Ok(());
+        }
+
+        // Let the SPI host know that the request is being processed.
+        let status = self.mailbox.request_received();
+        self.response = None;
+        self.discard_response();
+        self.publish_mailbox_status(status, false, false)?;
+
+        let result = packet.and_then(|(message_id, _)| self.dispatch_request(message_id));
+        let status = match result {
+            Ok(packet_len) => self.mailbox.response_ready(packet_len)?,
+            Err(_) => {
+                self.response = None;
+                self.discard_response();
+                self.mailbox.failed()?
+            }
+        };
+        self.publish_mailbox_status(status, true, true)?;
+        writeln!(console, "Device: Mailbox status: {:?}", status)?;
+        result?;
         Ok(())
     }
 
-    // Send a "write enable" command via the SPI host.
-    fn spi_host_write_enable(&self) -> SpiProcessorResult<()> {
-        let header = flash::Header::<u32> {
-            opcode: OpCode::WriteEnable,
-            address: None,
+    // Execute `write` via the SPI host and end the transaction.
+    // The kernel splits the data into smaller transactions as needed and
+    // executes "enable write" for each transaction.
+    async fn execute_host_flash_write(&mut self, write: HostFlashWrite) -> SpiProcessorResult<()> {
+        let data = unsafe { &mut HOST_FLASH_WRITE_BUFFER[..write.len] };
+        let result = match self.host_flash_write_timeout(&write) {
+            Ok(timeout) => match spi_host_h1::get().transfer(&write.header, true, data) {
+                Ok(transfer) => self.wait_host_flash_write(transfer, timeout).await,
+                Err(err) => Err(SpiProcessorError::from(err)),
+            },
+            Err(err) => Err(err),
+        };
+        self.clear_device_status(true, true)?;
+        result
+    }
+
+    // The longest `write` may take. Every transaction of a write waits for the
+    // host flash to clear its BUSY bit, so this is the maximum time of the
+    // operation of each transaction, on top of the regular timeout.
+    fn host_flash_write_timeout(&self, write: &HostFlashWrite) -> SpiProcessorResult<Duration> {
+        let times = &self.write_times;
+        let operation_time = match write.header.opcode {
+            OpCode::PageProgram | OpCode::PageProgram4B => {
+                let transactions = flash::Transfer::new(write.header, write.len, spi_host_h1::MAX_TRANSACTION_LEN)?
+                    .count();
+                times.page_program * transactions as u32
+            }
+            OpCode::SectorErase | OpCode::SectorErase4B => times.sector_erase,
+            // Block erases are not advertised in the SFDP table. They take
+            // no longer than erasing their sectors one by one.
+            OpCode::BlockErase32KB | OpCode::BlockErase32KB4B => times.sector_erase * 8,
+            OpCode::BlockErase64KB | OpCode::BlockErase64KB4B => times.sector_erase * 16,
+            OpCode::ChipErase | OpCode::ChipErase2 => times.chip_erase,
+            _ => return Err(SpiProcessorError::UnsupportedOpCode(write.header.opcode)),
+        };
+        Ok(TIMEOUTS.regular + operation_time)
+    }
+
+    // Wait for a host flash write to complete, but no longer than `timeout`.
+    // Erases can take long, so new device transactions are processed in the
+    // meantime.
+    async fn wait_host_flash_write(&mut self, transfer: spi_host_h1::TransferDone<'_>, timeout: Duration)
+        -> SpiProcessorResult<()> {
+        let timer = self.timer;
+        let timeout = timer.sleep(timer::Duration::from_ms(timeout.as_millis() as isize));
+        pin_mut!(timeout);
+        let mut done = select(transfer, timeout);
+
+        self.host_flash_busy = true;
+        let result = loop {
+            match select(&mut done, spi_device::get().transaction()).await {
+                Either::Left((Either::Left((result, _)), _)) => break result.map_err(SpiProcessorError::from),
+                Either::Left((Either::Right(_), _)) => break Err(SpiProcessorError::Timeout),
+                Either::Right(_) => {
+                    let rx_buf = spi_device::get().get_read_buffer();
+                    if let Err(why) = self.process_spi_packet(rx_buf).await {
+                        self.report_error(why);
+                    }
+                }
+            }
         };
+        self.host_flash_busy = false;
+        result
+    }
 
-        // The command has no data.
-        let data : [u8; 0] = [0; 0];
-        self.spi_host_send(&header, &data, &|| Ok(()))
+    // Whether the host flash ranges modified by `write` are protected by the
+    // block protect bits of the status register.
+    fn is_write_protected(&self, write: &policy::Write) -> SpiProcessorResult<bool> {
+        let status = spi_device::get().get_status()?;
+        Ok(write.ranges().iter().any(|&(address, len)| status.is_protected(HOST_FLASH_SIZE, address, len)))
     }
 
-    // Send a "write" type command (e.g. PageProgram, *Erase) via the SPI host.
-    // This splits the data into smaller transactions as needed and executes
-    // "enable write" for each transaction.
-    fn spi_host_write<AddrType>(&self, header: &flash::Header::<AddrType>, data: &[u8]) -> SpiProcessorResult<()>
-    where AddrType: Address {
-        self.spi_host_send(header, data, &|| self.spi_host_write_enable())
+    // Pass a "write" type command (e.g. PageProgram, *Erase) through to the
+    // SPI host and end the transaction.
+    // Like the host flash itself, commands are ignored if the write enable bit
+    // is not set or the region is protected by the block protect bits.
+    // Commands rejected by `HOST_FLASH_POLICY` are reported through the
+    // mailbox status.
+    async fn host_flash_write(&mut self, header: &flash::Header, data: &[u8]) -> SpiProcessorResult<()> {
+        let mut console = Console::new();
+
+        if self.host_flash_busy {
+            writeln!(console, "Device: Host flash busy, ignoring {:?}", header.opcode)?;
+            return self.clear_device_status(false, false);
+        }
+
+        let write = policy::Write::from_header(header, data.len(), HOST_FLASH_SIZE)
+            .ok_or(SpiProcessorError::UnsupportedOpCode(header.opcode))?;
+        if !spi_device::get().is_write_enable_set() || self.is_write_protected(&write)? {
+            return self.clear_device_status(true, true);
+        }
+
+        // Programs of `Protection::WriteOnce` regions require the programmed
+        // bytes to be erased. A program stays within its page, wrapping
+        // around to the page start, so the whole page is read up front, but
+        // only if the policy needs it.
+        let page = write.ranges()[0].0 & !(flash::PAGE_SIZE - 1);
+        let current = unsafe { &mut HOST_FLASH_READ_BUFFER[..] };
+        let current = if HOST_FLASH_POLICY.needs_erased_check(&write) {
+            let current = &mut current[..flash::PAGE_SIZE as usize];
+            read_host_flash(self.timer, page, current).await?;
+            current
+        } else {
+            &mut current[..0]
+        };
+
+        let result = HOST_FLASH_POLICY.check(&write, self.recovery_mode,
+            |start, len| -> SpiProcessorResult<bool> {
+                let offset = (start - page) as usize;
+                Ok(current[offset..offset + len as usize].iter().all(|&b| b == 0xff))
+            });
+        match result {
+            Ok(()) => {
+                // Pass through to SPI host once the transaction has been
+                // processed. The BUSY bit stays set until the write is done.
+                unsafe { HOST_FLASH_WRITE_BUFFER[..data.len()].copy_from_slice(data) };
+                self.pending_write = Some(HostFlashWrite { header: *header, len: data.len() });
+                self.clear_device_status(false, false)
+            }
+            Err(policy::Error::Rejected(protection)) => {
+                writeln!(console, "Device: Rejected {:?} of {:?} region", write, protection)?;
+                let status = self.mailbox.write_rejected(protection);
+                self.publish_mailbox_status(status, true, true)
+            }
+            Err(policy::Error::Flash(err)) => Err(err),
+        }
     }
 
     fn clear_device_status(&self, clear_busy: bool, clear_write_enable: bool) -> SpiProcessorResult<()> {
-        spi_device::get().end_transaction_with_status(clear_busy, clear_write_enable)?;
+        spi_device::get().end_transaction_with_status(clear_busy && !self.host_flash_busy, clear_write_enable)?;
         Ok(())
     }
 
-    fn process_spi_header<AddrType>(&mut self, header: &flash::Header::<AddrType>, rx_buf: &[u8]) -> SpiProcessorResult<()>
-    where AddrType: Address {
+    fn report_error(&self, why: SpiProcessorError) {
+        let mut console = Console::new();
+        // Ignore error from writeln. There's nothing we can do here anyway.
+        let _ = writeln!(console, "Device: Error processing SPI packet: {:?}", why);
+        if spi_device::get().is_busy_set() {
+            if let Err(_) = self.clear_device_status(true, false) {
+                // Ignore error from writeln. There's nothing we can do here anyway.
+                let _ = writeln!(console, "Device: Error ending transaction.");
+            }
+        }
+    }
+
+    async fn process_spi_header(&mut self, header: &flash::Header, rx_buf: &[u8]) -> SpiProcessorResult<()> {
         let mut data: &[u8] = rx_buf;
         if header.opcode.has_dummy_byte() {
             // Skip dummy byte
             data = &rx_buf[1..];
         }
         match header.opcode {
-            OpCode::PageProgram => {
+            OpCode::PageProgram | OpCode::PageProgram4B => {
                 match header.get_address() {
                     Some(0x02000000) => {
                         if spi_device::get().is_write_enable_set() {
@@ -364,139 +1040,148 @@ impl<'a> SpiProcessor<'a> {
                         }
                         self.clear_device_status(true, true)
                     }
-                    Some(x) if x < 0x02000000 => {
-                        if spi_device::get().is_write_enable_set() {
-                            // Pass through to SPI host
-                            self.spi_host_write(header, data)?;
-                        }
-                        self.clear_device_status(true, true)
-                    }
+                    Some(x) if x < 0x02000000 => self.host_flash_write(header, data).await,
                     _ => return Err(SpiProcessorError::InvalidAddress(header.get_address())),
                 }
             }
-            OpCode::SectorErase | OpCode::BlockErase32KB | OpCode::BlockErase64KB => {
+            OpCode::SectorErase | OpCode::SectorErase4B
+                | OpCode::BlockErase32KB | OpCode::BlockErase32KB4B
+                | OpCode::BlockErase64KB | OpCode::BlockErase64KB4B => {
                 match header.get_address() {
                     Some(0x02000000) => {
-                        // Nothing to do.
-                        self.clear_device_status(true, true)
-                    }
-                    Some(x) if x < 0x02000000 => {
-                        if spi_device::get().is_write_enable_set() {
-                            // Pass through to SPI host
-                            self.spi_host_write(header, data)?;
-                        }
-                        self.clear_device_status(true, true)
+                        // The SPI host is done with the response.
+                        self.clear_mailbox()
                     }
+                    Some(x) if x < 0x02000000 => self.host_flash_write(header, data).await,
                     _ => return Err(SpiProcessorError::InvalidAddress(header.get_address())),
                 }
             }
-            OpCode::ChipErase | OpCode::ChipErase2 => {
-                if spi_device::get().is_write_enable_set() {
-                    // Pass through to SPI host
-                    self.spi_host_write(header, data)?;
-                }
-                self.clear_device_status(true, true)
-            }
+            OpCode::ChipErase | OpCode::ChipErase2 => self.host_flash_write(header, data).await,
             _ => return Err(SpiProcessorError::UnsupportedOpCode(header.opcode)),
         }
     }
 
-    fn process_spi_packet(&mut self, mut rx_buf: &[u8]) -> SpiProcessorResult<()> {
+    async fn process_spi_packet(&mut self, mut rx_buf: &[u8]) -> SpiProcessorResult<()> {
         let mut console = Console::new();
-        match spi_device::get().get_address_mode() {
-            AddressMode::ThreeByte => {
-                let header = flash::Header::<ux::u24>::from_wire(&mut rx_buf)?;
-                writeln!(console, "Device: flash header (3B): {:?}", header)?;
-                self.process_spi_header(&header, rx_buf)
-            }
-            AddressMode::FourByte => {
-                let header = flash::Header::<u32>::from_wire(&mut rx_buf)?;
-                writeln!(console, "Device: flash header (4B): {:?}", header)?;
-                self.process_spi_header(&header, rx_buf)
-            }
-        }
+        let header = flash::Header::from_wire(&mut rx_buf, spi_device::get().get_address_mode())?;
+        writeln!(console, "Device: flash header: {:?}", header)?;
+        self.process_spi_header(&header, rx_buf).await
     }
 }
 
 //////////////////////////////////////////////////////////////////////////////
 
-fn run() -> TockResult<()> {
+async fn run() -> TockResult<()> {
     let mut console = Console::new();
 
+    let timer_context = timer::DriverContext::create()?;
+    let mut timer_driver = timer_context.create_timer_driver()?;
+    let timer = timer_driver.activate()?;
+
     //////////////////////////////////////////////////////////////////////////////
 
+    // Writes are checked region by region, which only covers whole erases
+    // and pages if the regions are aligned.
+    if !HOST_FLASH_POLICY.is_aligned() {
+        writeln!(console, "Device: Host flash regions are not sector aligned")?;
+        return Err(TockError::Format);
+    }
+
     // We cannot use the SPI host if passthrough is enabled.
     spi_host_h1::get().set_passthrough(false)?;
 
-    let host_demo = SpiHostDemo {};
+    let host_demo = SpiHostDemo { timer: &timer };
 
     writeln!(console, "Host: Entering 4B mode")?;
-    host_demo.enter_4b()?;
+    host_demo.enter_4b().await?;
 
     writeln!(console, "Host: Reading data")?;
-    host_demo.read_and_print_data(0x0)?;
-    host_demo.read_and_print_data(0x1)?;
+    host_demo.read_and_print_data(0x0).await?;
+    host_demo.read_and_print_data(0x1).await?;
 
     if spi_device::get().get_address_mode() == AddressMode::ThreeByte {
         writeln!(console, "Host: Exiting 4B mode")?;
-        host_demo.exit_4b()?;
+        host_demo.exit_4b().await?;
     }
 
-    let mut identity = Identity {
-        version: [0; 32],
-        device_id: [0; 64],
+    // Measure the host flash while passthrough is still disabled. If that
+    // fails, the host gets an empty log rather than no device at all.
+    let measurements = match measure_host_flash(&timer).await {
+        Ok(measurements) => measurements,
+        Err(err) => {
+            writeln!(console, "Device: Measuring host flash failed: {:?}", err)?;
+            measurement::Log::new()
+        }
     };
 
-    //////////////////////////////////////////////////////////////////////////////
+    let boot_status = verified_boot(&timer).await?;
+    writeln!(console, "Device: Verified boot: {:?}", boot_status)?;
 
-    {
-        let mut idx : usize = 0;
-        for val in "v1.00".as_bytes() {
-            if idx > identity.version.len() { break; }
-            identity.version[idx] = *val;
-            idx = idx + 1;
-        }
-    }
+    let identity = Identity::new()?;
+    let device_id = device_identifier();
+    writeln!(console, "Device: Firmware version: {}", GIT_LONG_TAG.trim_end())?;
+    writeln!(console, "Device: Vendor {:#06x} device {:#06x} subsystem vendor {:#06x} subsystem {:#06x}",
+        device_id.vendor_id, device_id.device_id, device_id.subsys_vendor_id, device_id.subsys_id)?;
 
-    {
-        let mut idx : usize = 0;
-        for val in "1234567890".as_bytes() {
-            if idx > identity.device_id.len() { break; }
-            identity.device_id[idx] = *val;
-            idx = idx + 1;
-        }
-    }
+    //////////////////////////////////////////////////////////////////////////////
 
-    let mut processor = SpiProcessor {
+    let mut manticore_handler = ManticoreHandler {
         server: PaRot::new(Options {
             identity: &identity,
             reset: &Reset,
-            rsa: &NoRsa,
-            device_id: DEVICE_ID,
+            rsa: &H1Rsa,
+            device_id: device_id,
             networking: NETWORKING,
             timeouts: TIMEOUTS,
         }),
     };
 
+    let mut measurement_handler = MeasurementHandler {
+        measurements: &measurements,
+    };
+
+    let mut fw_update_handler = FirmwareUpdateHandler::new()?;
+
+    let mut processor = SpiProcessor {
+        dispatcher: payload::Dispatcher::new(),
+        mailbox: Mailbox::new(),
+        mailbox_buf: [0xff; SPI_TX_BUF_SIZE],
+        reassembler: payload::Reassembler::new(unsafe { &mut REQUEST_BUFFER }, mailbox::REQUEST_PAYLOAD_LEN),
+        response_buf: unsafe { &mut RESPONSE_BUFFER },
+        response: None,
+        recovery_mode: boot_status.decision == Decision::Recovery,
+        timer: &timer,
+        pending_write: None,
+        host_flash_busy: false,
+        write_times: sfdp::write_times().ok_or(TockError::Format)?,
+    };
+    processor.dispatcher.register(payload::ContentType::Manticore, &mut manticore_handler)
+        .map_err(|_| TockError::Format)?;
+    processor.dispatcher.register(payload::ContentType::FirmwareUpdate, &mut fw_update_handler)
+        .map_err(|_| TockError::Format)?;
+    processor.dispatcher.register(payload::ContentType::Measurement, &mut measurement_handler)
+        .map_err(|_| TockError::Format)?;
+
     writeln!(console, "Device: Configuring address_mode handling to KernelSpace")?;
     spi_device::get().set_address_mode_handling(HandlerMode::KernelSpace)?;
 
     // OpenTitan JEDEC ID
     /*
-    spi_device::get().set_jedec_id(&mut [
-        0x26, // Manufacturer (Visic, should actually be
-              // 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x7F, 0x26)
-        0x31, // Device (OpenTitan)
-        0x19, // Size (2^25 = 256 Mb)
-        ])?;
+    spi_device::get().set_jedec_id(&JedecId {
+        bank: 9,            // Manufacturer bank (Google)
+        manufacturer: 0x26, // Manufacturer (Google)
+        device: 0x31,       // Device (OpenTitan)
+        capacity: 0x19,     // Size (2^25 = 256 Mb)
+    })?;
     */
 
     // Legacy JEDEC ID
-    spi_device::get().set_jedec_id(&mut [
-        0x26, // Manufacturer
-        0x02, // Device
-        0x17, // Size
-        ])?;
+    spi_device::get().set_jedec_id(&JedecId {
+        bank: 1,
+        manufacturer: 0x26, // Manufacturer
+        device: 0x02,       // Device
+        capacity: 0x17,     // Size
+    })?;
 
     {
         let mut sfdp = [0xff; 128];
@@ -506,7 +1191,7 @@ fn run() -> TockResult<()> {
             spi_device::get().get_address_mode(), // startup_address_mode
             spi_device::get().get_address_mode() == AddressMode::ThreeByte, // support_address_mode_switch
             0x2000000, // mailbox_offset
-            spi_device::MAX_READ_BUFFER_SIZE as u32, // mailbox_size
+            mailbox::MAILBOX_LEN as u32, // mailbox_size
             0 // google_capabilities
             ).map_err(|_| TockError::Format)?;
         spi_device::get().set_sfdp(&mut sfdp)?;
@@ -514,24 +1199,30 @@ fn run() -> TockResult<()> {
 
     //////////////////////////////////////////////////////////////////////////////
 
-    // We need SPI passthrough to be fully operational.
-    spi_host_h1::get().set_passthrough(true)?;
+    // The SPI host reads the mailbox status before sending its first request.
+    let status = processor.mailbox.boot_decided(boot_status);
+    processor.publish_mailbox_status(status, false, false)
+        .map_err(|_| TockError::Format)?;
+
+    if boot_status.decision == Decision::Halted {
+        writeln!(console, "Device: Keeping SPI passthrough disabled")?;
+    } else {
+        // We need SPI passthrough to be fully operational.
+        spi_host_h1::get().set_passthrough(true)?;
+    }
 
     loop {
-        spi_device::get().wait_for_transaction();
+        spi_device::get().transaction().await;
 
         let rx_buf = spi_device::get().get_read_buffer();
-        match processor.process_spi_packet(rx_buf) {
-            Ok(()) => {}
-            Err(why) => {
+        if let Err(why) = processor.process_spi_packet(rx_buf).await {
+            processor.report_error(why);
+        }
+
+        if let Some(write) = processor.pending_write.take() {
+            if let Err(why) = processor.execute_host_flash_write(write).await {
                 // Ignore error from writeln. There's nothing we can do here anyway.
-                let _ = writeln!(console, "Device: Error processing SPI packet: {:?}", why);
-                if spi_device::get().is_busy_set() {
-                    if let Err(_) = spi_device::get().end_transaction_with_status(true, false) {
-                        // Ignore error from writeln. There's nothing we can do here anyway.
-                        let _ = writeln!(console, "Device: Error ending transaction.");
-                    }
-                }
+                let _ = writeln!(console, "Device: Error writing host flash: {:?}", why);
             }
         }
     }
@@ -547,7 +1238,7 @@ const BANNER: &'static str = concat!(
 async fn main() -> TockResult<()> {
     let mut console = Console::new();
     writeln!(console, "Starting {}", BANNER)?;
-    let result = run();
+    let result = run().await;
     if result.is_ok() {
         writeln!(console, "Returned OK.")?;
     } else {
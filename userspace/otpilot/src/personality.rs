@@ -0,0 +1,115 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use libtock::result::TockResult;
+use libtock::syscalls;
+
+/// The size of the personality (device attestation) data.
+pub const PERSONALITY_SIZE: usize = 2048;
+
+/// The size of the device attestation public key (X and Y of a P-256 point).
+pub const PUBLIC_KEY_SIZE: usize = 64;
+
+// The offset of the public key within the personality data. It follows the
+// checksum and the salt, which are 32 bytes each.
+const PUBLIC_KEY_OFFSET: usize = 64;
+
+/// The size of the digest of the host firmware signing key.
+pub const BOOT_KEY_DIGEST_SIZE: usize = 32;
+
+// The SHA-256 digest of the RSA key that signs host firmware manifests is
+// provisioned in the last bytes of the personality data, after the space
+// reserved for the certificate.
+const BOOT_KEY_DIGEST_OFFSET: usize = PERSONALITY_SIZE - BOOT_KEY_DIGEST_SIZE;
+
+pub trait Personality {
+    /// Get the device attestation public key from the personality data.
+    fn get_public_key(&self, public_key: &mut [u8; PUBLIC_KEY_SIZE]) -> TockResult<()>;
+
+    /// Get the digest of the host firmware signing key from the personality data.
+    fn get_boot_key_digest(&self, digest: &mut [u8; BOOT_KEY_DIGEST_SIZE]) -> TockResult<()>;
+}
+
+// Get the static Personality object.
+pub fn get() -> &'static dyn Personality {
+    get_impl()
+}
+
+const DRIVER_NUMBER: usize = 0x5000b;
+
+mod command_nr {
+    pub const CHECK_IF_PRESENT: usize = 0;
+    pub const READ: usize = 1;
+}
+
+mod allow_nr {
+    pub const BUFFER: usize = 0;
+}
+
+struct PersonalityImpl {}
+
+static mut PERSONALITY: PersonalityImpl = PersonalityImpl {};
+
+// The buffer for reading the personality data. It is too large for the stack.
+static mut PERSONALITY_BUFFER: [u8; PERSONALITY_SIZE] = [0; PERSONALITY_SIZE];
+
+static mut IS_INITIALIZED: bool = false;
+
+fn get_impl() -> &'static PersonalityImpl {
+    unsafe {
+        if !IS_INITIALIZED {
+            if PERSONALITY.initialize().is_err() {
+                panic!("Could not initialize Personality");
+            }
+            IS_INITIALIZED = true;
+        }
+        &PERSONALITY
+    }
+}
+
+impl PersonalityImpl {
+    fn initialize(&'static mut self) -> TockResult<()> {
+        syscalls::command(DRIVER_NUMBER, command_nr::CHECK_IF_PRESENT, 0, 0)?;
+
+        Ok(())
+    }
+
+    fn read(&self) -> TockResult<&'static [u8; PERSONALITY_SIZE]> {
+        let buffer = unsafe { &mut PERSONALITY_BUFFER };
+        {
+            // We want this to go out of scope after executing the command
+            let _buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::BUFFER, &mut buffer[..])?;
+
+            syscalls::command(DRIVER_NUMBER, command_nr::READ, 0, 0)?;
+        }
+
+        Ok(buffer)
+    }
+}
+
+impl Personality for PersonalityImpl {
+    fn get_public_key(&self, public_key: &mut [u8; PUBLIC_KEY_SIZE]) -> TockResult<()> {
+        let buffer = self.read()?;
+        public_key.copy_from_slice(&buffer[PUBLIC_KEY_OFFSET..PUBLIC_KEY_OFFSET + PUBLIC_KEY_SIZE]);
+        Ok(())
+    }
+
+    fn get_boot_key_digest(&self, digest: &mut [u8; BOOT_KEY_DIGEST_SIZE]) -> TockResult<()> {
+        let buffer = self.read()?;
+        digest.copy_from_slice(&buffer[BOOT_KEY_DIGEST_OFFSET..BOOT_KEY_DIGEST_OFFSET + BOOT_KEY_DIGEST_SIZE]);
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+use libtock::result::TockResult;
+use libtock::syscalls;
+
+pub trait Reset {
+    /// Get the number of resets since the last power-on reset.
+    fn resets_since_power_on(&self) -> TockResult<u32>;
+
+    /// Get the time since the last reset.
+    fn uptime(&self) -> TockResult<Duration>;
+}
+
+// Get the static Reset object.
+pub fn get() -> &'static dyn Reset {
+    get_impl()
+}
+
+const DRIVER_NUMBER: usize = 0x40006;
+
+mod command_nr {
+    pub const CHECK_IF_PRESENT: usize = 0;
+    pub const RESETS_SINCE_POWER_ON: usize = 1;
+    pub const UPTIME: usize = 3;
+}
+
+mod allow_nr {
+    pub const BUFFER: usize = 0;
+}
+
+struct ResetImpl {}
+
+static mut RESET: ResetImpl = ResetImpl {};
+
+static mut IS_INITIALIZED: bool = false;
+
+fn get_impl() -> &'static ResetImpl {
+    unsafe {
+        if !IS_INITIALIZED {
+            if RESET.initialize().is_err() {
+                panic!("Could not initialize Reset");
+            }
+            IS_INITIALIZED = true;
+        }
+        &RESET
+    }
+}
+
+impl ResetImpl {
+    fn initialize(&'static mut self) -> TockResult<()> {
+        syscalls::command(DRIVER_NUMBER, command_nr::CHECK_IF_PRESENT, 0, 0)?;
+
+        Ok(())
+    }
+}
+
+impl Reset for ResetImpl {
+    fn resets_since_power_on(&self) -> TockResult<u32> {
+        let resets = syscalls::command(DRIVER_NUMBER, command_nr::RESETS_SINCE_POWER_ON, 0, 0)?;
+        Ok(resets as u32)
+    }
+
+    fn uptime(&self) -> TockResult<Duration> {
+        let mut buffer = [0u8; 8];
+        {
+            // We want this to go out of scope after executing the command
+            let _buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::BUFFER, &mut buffer)?;
+
+            syscalls::command(DRIVER_NUMBER, command_nr::UPTIME, 0, 0)?;
+        }
+        Ok(Duration::from_millis(u64::from_le_bytes(buffer)))
+    }
+}
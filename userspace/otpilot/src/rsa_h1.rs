@@ -0,0 +1,85 @@
+// Copyright 2020 lowRISC contributors.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use libtock::result::TockResult;
+use libtock::syscalls;
+
+pub trait RsaH1 {
+    /// Verify the RSASSA-PKCS1-v1_5 `signature` of a message with the SHA-256 `digest` against
+    /// the public key given by the big-endian `modulus` and `exponent`.
+    ///
+    /// Fails if the signature is invalid.
+    fn verify_pkcs1_sha256(&self, modulus: &mut [u8], exponent: u32, signature: &mut [u8],
+        digest: &mut [u8]) -> TockResult<()>;
+}
+
+// Get the static RsaH1 object.
+pub fn get() -> &'static dyn RsaH1 {
+    get_impl()
+}
+
+const DRIVER_NUMBER: usize = 0x40005;
+
+mod command_nr {
+    pub const CHECK_IF_PRESENT: usize = 0;
+    pub const VERIFY: usize = 1;
+}
+
+mod allow_nr {
+    pub const MODULUS: usize = 0;
+    pub const SIGNATURE: usize = 1;
+    pub const DIGEST: usize = 2;
+}
+
+struct RsaH1Impl {}
+
+static mut RSA_H1: RsaH1Impl = RsaH1Impl {};
+
+static mut IS_INITIALIZED: bool = false;
+
+fn get_impl() -> &'static RsaH1Impl {
+    unsafe {
+        if !IS_INITIALIZED {
+            if RSA_H1.initialize().is_err() {
+                panic!("Could not initialize RSA H1");
+            }
+            IS_INITIALIZED = true;
+        }
+        &RSA_H1
+    }
+}
+
+impl RsaH1Impl {
+    fn initialize(&'static mut self) -> TockResult<()> {
+        syscalls::command(DRIVER_NUMBER, command_nr::CHECK_IF_PRESENT, 0, 0)?;
+
+        Ok(())
+    }
+}
+
+impl RsaH1 for RsaH1Impl {
+    fn verify_pkcs1_sha256(&self, modulus: &mut [u8], exponent: u32, signature: &mut [u8],
+        digest: &mut [u8]) -> TockResult<()> {
+        // We want these to go out of scope after executing the command
+        let _modulus_share = syscalls::allow(DRIVER_NUMBER, allow_nr::MODULUS, modulus)?;
+        let _signature_share = syscalls::allow(DRIVER_NUMBER, allow_nr::SIGNATURE, signature)?;
+        let _digest_share = syscalls::allow(DRIVER_NUMBER, allow_nr::DIGEST, digest)?;
+
+        syscalls::command(DRIVER_NUMBER, command_nr::VERIFY, exponent as usize, 0)?;
+
+        Ok(())
+    }
+}
@@ -16,15 +16,23 @@
 
 use core::cell::Cell;
 use core::convert::TryFrom;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
 
 use libtock::result::TockError;
 use libtock::result::TockResult;
 use libtock::shared_memory::SharedMemory;
 use libtock::syscalls;
-use libtock::syscalls::raw::yieldk;
 
 use spiutils::driver::HandlerMode;
+use spiutils::io::Cursor;
 use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::JedecId;
+use spiutils::protocol::flash::MAX_JEDEC_ID_LEN;
+use spiutils::protocol::flash::StatusRegister;
+use spiutils::protocol::wire::ToWire;
 
 pub const MAX_READ_BUFFER_SIZE: usize = 512;
 
@@ -32,8 +40,8 @@ pub const MAX_READ_BUFFER_SIZE: usize = 512;
 pub const MAX_WRITE_BUFFER_SIZE: usize = 2048;
 
 pub trait SpiDevice {
-    /// Wait for a transaction by yielding.
-    fn wait_for_transaction(&self);
+    /// Get a future that completes when a transaction was received.
+    fn transaction(&self) -> TransactionReceived;
 
     /// Get the buffer slice of received data.
     fn get_read_buffer(&self) -> &[u8];
@@ -61,11 +69,14 @@ pub trait SpiDevice {
     /// Set handling mode for address mode changes.
     fn set_address_mode_handling(&self, address_mode_handling: HandlerMode) -> TockResult<()>;
 
-    /// Set the JEDEC ID data.
-    fn set_jedec_id(&self, data: &mut[u8]) -> TockResult<()>;
+    /// Set the JEDEC ID.
+    fn set_jedec_id(&self, jedec_id: &JedecId) -> TockResult<()>;
 
     /// Set the SFDP data.
     fn set_sfdp(&self, data: &mut[u8]) -> TockResult<()>;
+
+    /// Get the contents of the status register.
+    fn get_status(&self) -> TockResult<StatusRegister>;
 }
 
 // Get the static SpiDevice object.
@@ -73,6 +84,25 @@ pub fn get() -> &'static dyn SpiDevice {
     get_impl()
 }
 
+/// A future that completes when a transaction was received.
+///
+/// The transaction is available until it is ended with
+/// `end_transaction_with_status` or `end_transaction_with_data`.
+pub struct TransactionReceived {}
+
+impl Future for TransactionReceived {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<()> {
+        // The executor yields between polls, which runs the callbacks.
+        if get_impl().have_transaction() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 const DRIVER_NUMBER: usize = 0x40030;
 
 mod command_nr {
@@ -84,6 +114,7 @@ mod command_nr {
     pub const SET_ADDRESS_MODE_HANDLING: usize = 5;
     pub const SET_JEDEC_ID: usize = 6;
     pub const SET_SFDP: usize = 7;
+    pub const GET_STATUS: usize = 8;
 }
 
 mod subscribe_nr {
@@ -208,8 +239,8 @@ impl SpiDeviceImpl {
 }
 
 impl SpiDevice for SpiDeviceImpl {
-    fn wait_for_transaction(&self) {
-        while !self.have_transaction() { unsafe { yieldk(); } }
+    fn transaction(&self) -> TransactionReceived {
+        TransactionReceived {}
     }
 
     fn get_read_buffer(&self) -> &[u8] {
@@ -262,9 +293,16 @@ impl SpiDevice for SpiDeviceImpl {
         Ok(())
     }
 
-    fn set_jedec_id(&self, data: &mut[u8]) -> TockResult<()> {
+    fn set_jedec_id(&self, jedec_id: &JedecId) -> TockResult<()> {
+        let mut buf = [0xff; MAX_JEDEC_ID_LEN];
+        let len = {
+            let mut cursor = Cursor::new(&mut buf);
+            jedec_id.to_wire(&mut cursor).map_err(|_| TockError::Format)?;
+            cursor.consumed_len()
+        };
+
         // We want this to go out of scope after executing the command
-        let _write_buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::WRITE_BUFFER, data)?;
+        let _write_buffer_share = syscalls::allow(DRIVER_NUMBER, allow_nr::WRITE_BUFFER, &mut buf[..len])?;
 
         syscalls::command(DRIVER_NUMBER, command_nr::SET_JEDEC_ID, 0, 0)?;
 
@@ -279,4 +317,9 @@ impl SpiDevice for SpiDeviceImpl {
 
         Ok(())
     }
+
+    fn get_status(&self) -> TockResult<StatusRegister> {
+        let status = syscalls::command(DRIVER_NUMBER, command_nr::GET_STATUS, 0, 0)?;
+        Ok(StatusRegister::from_bits(status as u16))
+    }
 }
@@ -15,11 +15,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use core::cell::Cell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
 
 use libtock::result::TockResult;
 use libtock::shared_memory::SharedMemory;
 use libtock::syscalls;
-use libtock::syscalls::raw::yieldk;
 
 pub const MAX_READ_BUFFER_LENGTH: usize = 128;
 
@@ -32,8 +35,8 @@ pub trait SpiHost {
     // Check if the last read_write is done.
     fn is_read_write_done(&self) -> bool;
 
-    // Wait for the last read_write to complete by yielding.
-    fn wait_read_write_done(&self);
+    // Get a future that completes when the last read_write is done.
+    fn read_write_done(&self) -> ReadWriteDone;
 
     // Get the read buffer slice.
     fn get_read_buffer(&self) -> &[u8];
@@ -44,6 +47,22 @@ pub fn get() -> &'static dyn SpiHost {
     get_impl()
 }
 
+// A future that completes when the last read_write is done.
+pub struct ReadWriteDone {}
+
+impl Future for ReadWriteDone {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<()> {
+        // The executor yields between polls, which runs the callbacks.
+        if get_impl().is_read_write_done() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
 const DRIVER_NUMBER: usize = 0x20001;
 
 mod command_nr {
@@ -148,9 +167,9 @@ impl SpiHost for SpiHostImpl {
         self.read_write_done.get()
     }
 
-    // Wait for the last transaction to complete. Runs yieldk().
-    fn wait_read_write_done(&self) {
-        while !self.is_read_write_done() { unsafe { yieldk(); } }
+    // Get a future that completes when the last transaction is done.
+    fn read_write_done(&self) -> ReadWriteDone {
+        ReadWriteDone {}
     }
 
     // Get the receive buffer slice for the last transaction.
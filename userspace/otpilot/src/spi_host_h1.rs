@@ -14,15 +14,62 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use core::cell::Cell;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::Context;
+use core::task::Poll;
+
+use libtock::result::TockError;
 use libtock::result::TockResult;
+use libtock::shared_memory::SharedMemory;
 use libtock::syscalls;
 
+use spiutils::protocol::flash;
+use spiutils::protocol::flash::AddressMode;
+use spiutils::protocol::flash::DataDirection;
+use spiutils::protocol::wire::WireEnum;
+
+/// The maximum length of a transaction the kernel splits transfers into,
+/// including the header.
+pub const MAX_TRANSACTION_LEN: usize = 128;
+
 pub trait SpiHostH1 {
     /// Enable/disable SPI passthrough.
     fn set_passthrough(&self, enabled: bool) -> TockResult<()>;
 
     /// Enable/disable wait for BUSY bit to clear before completing transactions.
     fn set_wait_busy_clear_in_transactions(&self, enabled: bool) -> TockResult<()>;
+
+    /// Start executing the SPI flash command with `header`.
+    /// `data` is sent after the header or receives the data read, depending
+    /// on the op code, and may be of any length. It is ignored by op codes
+    /// without data. The kernel splits the command into transactions that
+    /// fit into the SPI host's buffer. If `write_enable` is set, each
+    /// transaction is preceded by a WriteEnable command.
+    /// Returns a future that completes when the transfer is done.
+    fn transfer<'a>(&self, header: &flash::Header, write_enable: bool, data: &'a mut [u8])
+        -> TockResult<TransferDone<'a>>;
+}
+
+/// A future that completes when a transfer is done.
+///
+/// `data` stays shared with the kernel until the future is dropped.
+pub struct TransferDone<'a> {
+    _buffer_share: Option<SharedMemory<'a>>,
+}
+
+impl<'a> Future for TransferDone<'a> {
+    type Output = TockResult<()>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<TockResult<()>> {
+        // The executor yields between polls, which runs the callbacks.
+        match get_impl().result.take() {
+            None => Poll::Pending,
+            Some(0) => Poll::Ready(Ok(())),
+            Some(_) => Poll::Ready(Err(TockError::Format)),
+        }
+    }
 }
 
 // Get the static SpiHostH1 object.
@@ -36,11 +83,35 @@ mod command_nr {
     pub const CHECK_IF_PRESENT: usize = 0;
     pub const ENABLE_DISABLE_PASSTHROUGH: usize = 1;
     pub const ENABLE_DISABLE_WAIT_BUSY_CLEAR_IN_TRANSACTIONS: usize = 2;
+    pub const TRANSFER: usize = 3;
+}
+
+mod subscribe_nr {
+    pub const TRANSFER_DONE: usize = 0;
+}
+
+mod allow_nr {
+    pub const WRITE_BUFFER: usize = 0;
+    pub const READ_BUFFER: usize = 1;
+}
+
+mod transfer_flags {
+    pub const FOUR_BYTE_ADDRESS: usize = 1 << 8;
+    pub const WRITE_ENABLE: usize = 1 << 9;
 }
 
-struct SpiHostH1Impl {}
+struct SpiHostH1Impl {
+    // The result of the last transfer, once it is complete.
+    result: Cell<Option<isize>>,
+    // The identifier the kernel returned for the last transfer. Callbacks of
+    // earlier transfers, e.g. ones that timed out, are ignored.
+    transfer_id: Cell<Option<usize>>,
+}
 
-static mut SPI_HOST_H1: SpiHostH1Impl = SpiHostH1Impl {};
+static mut SPI_HOST_H1: SpiHostH1Impl = SpiHostH1Impl {
+    result: Cell::new(None),
+    transfer_id: Cell::new(None),
+};
 
 static mut IS_INITIALIZED: bool = false;
 
@@ -60,8 +131,27 @@ impl SpiHostH1Impl {
     fn initialize(&'static mut self) -> TockResult<()> {
         syscalls::command(DRIVER_NUMBER, command_nr::CHECK_IF_PRESENT, 0, 0)?;
 
+        syscalls::subscribe_fn(
+            DRIVER_NUMBER,
+            subscribe_nr::TRANSFER_DONE,
+            SpiHostH1Impl::transfer_done_trampoline,
+            0)?;
+
         Ok(())
     }
+
+    extern "C"
+    fn transfer_done_trampoline(arg1: usize, arg2: usize, arg3: usize, _data: usize) {
+        get_impl().transfer_done(arg1, arg2, arg3);
+    }
+
+    fn transfer_done(&self, arg1: usize, arg2: usize, _: usize) {
+        // arg1: the ReturnCode of the transfer
+        // arg2: the identifier of the transfer
+        if self.transfer_id.get() == Some(arg2) {
+            self.result.set(Some(arg1 as isize));
+        }
+    }
 }
 
 impl SpiHostH1 for SpiHostH1Impl {
@@ -76,4 +166,34 @@ impl SpiHostH1 for SpiHostH1Impl {
 
         Ok(())
     }
+
+    fn transfer<'a>(&self, header: &flash::Header, write_enable: bool, data: &'a mut [u8])
+        -> TockResult<TransferDone<'a>> {
+        let mut flags = header.opcode.to_wire_value() as usize;
+        if header.address_mode == AddressMode::FourByte {
+            flags |= transfer_flags::FOUR_BYTE_ADDRESS;
+        }
+        if write_enable {
+            flags |= transfer_flags::WRITE_ENABLE;
+        }
+        let address = header.address.unwrap_or(0) as usize;
+
+        let allow_nr = match header.opcode.descriptor().data_direction {
+            DataDirection::None => None,
+            DataDirection::HostToDevice => Some(allow_nr::WRITE_BUFFER),
+            DataDirection::DeviceToHost => Some(allow_nr::READ_BUFFER),
+        };
+
+        // The future keeps the buffer shared until the transfer is complete
+        let buffer_share = match allow_nr {
+            Some(allow_nr) => Some(syscalls::allow(DRIVER_NUMBER, allow_nr, data)?),
+            None => None,
+        };
+
+        self.result.set(None);
+        self.transfer_id.set(None);
+        let transfer_id = syscalls::command(DRIVER_NUMBER, command_nr::TRANSFER, flags, address)?;
+        self.transfer_id.set(Some(transfer_id));
+        Ok(TransferDone { _buffer_share: buffer_share })
+    }
 }